@@ -65,7 +65,7 @@ async fn main() -> anyhow::Result<()> {
         .name("Example NFT")
         .symbol("ENFT")
         .token_type(hedera::TokenType::NonFungibleUnique)
-        .treasury_account_id(client.get_operator_account_id().unwrap())
+        .treasury_account_id(client.operator_account_id().unwrap())
         .admin_key(admin_key.public_key())
         .wipe_key(wipe_key.public_key())
         .supply_key(supply_key.public_key())
@@ -89,8 +89,8 @@ async fn main() -> anyhow::Result<()> {
 
     // recreate the transaction from bytes
     transaction_to_execute.sign_with_operator(&client)?;
-    transaction_to_execute.add_signature(user1_key.public_key(), user1_signature);
-    transaction_to_execute.add_signature(user2_key.public_key(), user2_signature);
+    transaction_to_execute.add_signature(user1_key.public_key(), user1_signature)?;
+    transaction_to_execute.add_signature(user2_key.public_key(), user2_signature)?;
 
     let result = transaction_to_execute.execute(&client).await?;
     let receipt = result.get_receipt(&client).await?;
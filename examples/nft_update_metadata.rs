@@ -59,8 +59,8 @@ async fn main() -> anyhow::Result<()> {
         .name("ffff")
         .symbol("F")
         .token_type(TokenType::NonFungibleUnique)
-        .treasury_account_id(client.get_operator_account_id().unwrap())
-        .supply_key(client.get_operator_public_key().unwrap())
+        .treasury_account_id(client.operator_account_id().unwrap())
+        .supply_key(client.operator_public_key().unwrap())
         .metadata_key(metadata_key.public_key())
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
         .sign(args.operator_key.clone())
@@ -111,7 +111,7 @@ async fn main() -> anyhow::Result<()> {
     println!("Set token NFT metadata: {:?}", token_nfts_info.metadata);
 
     let account_id = AccountCreateTransaction::new()
-        .key(client.get_operator_public_key().unwrap())
+        .key(client.operator_public_key().unwrap())
         .max_automatic_token_associations(10)
         .initial_balance(Hbar::new(100))
         .freeze_with(&client)?
@@ -126,11 +126,7 @@ async fn main() -> anyhow::Result<()> {
     println!("New Account id: {account_id:?}");
 
     let transfer_nft_tx = TransferTransaction::new()
-        .nft_transfer(
-            nft_id,
-            client.get_operator_account_id().unwrap(),
-            account_id,
-        )
+        .nft_transfer(nft_id, client.operator_account_id().unwrap(), account_id)
         .freeze_with(&client)?
         .sign(args.operator_key.clone())
         .execute(&client)
@@ -72,7 +72,7 @@ async fn main() -> anyhow::Result<()> {
         .hbar_transfer(args.operator_account_id, Hbar::new(1));
 
     let response = tx
-        .schedule()
+        .schedule()?
         .expiration_time(OffsetDateTime::now_utc() + time::Duration::days(1))
         .wait_for_expiry(true)
         .execute(&client)
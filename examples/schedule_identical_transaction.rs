@@ -116,7 +116,7 @@ async fn main() -> anyhow::Result<()> {
 
         let mut scheduled_tx = ScheduleCreateTransaction::new();
 
-        scheduled_tx.scheduled_transaction(tx);
+        scheduled_tx.scheduled_transaction(tx)?;
 
         scheduled_tx.payer_account_id(threshold_account);
 
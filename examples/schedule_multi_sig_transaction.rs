@@ -93,7 +93,7 @@ async fn main() -> anyhow::Result<()> {
 
     // Schedule the transaction
     let receipt = transfer
-        .schedule()
+        .schedule()?
         .payer_account_id(args.operator_account_id)
         .admin_key(args.operator_key.public_key())
         .freeze_with(&client)?
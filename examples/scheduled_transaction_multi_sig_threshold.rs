@@ -92,7 +92,7 @@ async fn main() -> anyhow::Result<()> {
         .hbar_transfer(args.operator_account_id, Hbar::from_tinybars(1));
 
     let tx_schedule_receipt = transfer_transaction
-        .schedule()
+        .schedule()?
         .freeze_with(&client)?
         .sign(private_keys[0].clone())
         .execute(&client)
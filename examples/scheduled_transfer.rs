@@ -121,7 +121,7 @@ async fn main() -> anyhow::Result<()> {
     // If `payer_account_id` is not specified, the account who creates the scheduled transaction
     // will be charged for executing the scheduled transaction.
     let schedule_id = ScheduleCreateTransaction::new()
-        .scheduled_transaction(transfer_to_schedule)
+        .scheduled_transaction(transfer_to_schedule)?
         .payer_account_id(bobs_id)
         .execute(&client)
         .await?
@@ -132,7 +132,7 @@ async fn main() -> anyhow::Result<()> {
 
     println!(
         "The schedule_id is: {}",
-        schedule_id.to_string_with_checksum(&client)
+        schedule_id.to_string_with_checksum(&client)?
     );
 
     // Bob's balance should be unchanged.  The transfer has been scheduled, but it hasn't been executed yet
@@ -56,7 +56,7 @@ async fn main() -> anyhow::Result<()> {
     let new_account_id = AccountCreateTransaction::new()
         .key(new_key.public_key())
         .initial_balance(Hbar::new(10))
-        .staked_account_id("0.0.3".parse()?)
+        .staked_account_id("0.0.3".parse::<AccountId>()?)
         .execute(&client)
         .await?
         .get_receipt(&client)
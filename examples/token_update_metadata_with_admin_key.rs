@@ -59,7 +59,7 @@ async fn main() -> anyhow::Result<()> {
         .token_type(TokenType::FungibleCommon)
         .decimals(3)
         .initial_supply(1000000)
-        .treasury_account_id(client.get_operator_account_id().unwrap())
+        .treasury_account_id(client.operator_account_id().unwrap())
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
         .admin_key(admin_key.public_key())
         .metadata(metadata)
@@ -61,9 +61,9 @@ async fn main() -> anyhow::Result<()> {
         .decimals(3)
         .initial_supply(1000000)
         .metadata(metadata)
-        .treasury_account_id(client.get_operator_account_id().unwrap())
+        .treasury_account_id(client.operator_account_id().unwrap())
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
-        .admin_key(client.get_operator_public_key().unwrap())
+        .admin_key(client.operator_public_key().unwrap())
         .metadata_key(metadata_key.public_key())
         .execute(&client)
         .await?
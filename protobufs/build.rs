@@ -136,6 +136,12 @@ fn main() -> anyhow::Result<()> {
     // NOTE: prost generates rust doc comments and fails to remove the leading * line
     remove_useless_comments(&Path::new(&env::var("OUT_DIR")?).join("proto.rs"))?;
 
+    // the sdk facing `Status` mirrors the ResponseCodeEnum but keeps codes it doesn't know about
+    generate_status(
+        &Path::new(&env::var("OUT_DIR")?).join("proto.rs"),
+        &Path::new(&env::var("OUT_DIR")?).join("status.rs"),
+    )?;
+
     // mirror
     // NOTE: must be compiled in a separate folder otherwise it will overwrite the previous build
 
@@ -275,6 +281,121 @@ fn remove_useless_comments(path: &Path) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn generate_status(proto_path: &Path, status_path: &Path) -> anyhow::Result<()> {
+    let contents = fs::read_to_string(proto_path)?;
+
+    let Some((_, body)) = contents.split_once("pub enum ResponseCodeEnum {\n") else {
+        anyhow::bail!("`ResponseCodeEnum` not found in {}", proto_path.display());
+    };
+
+    let Some((body, _)) = body.split_once("\n}\n") else {
+        anyhow::bail!("`ResponseCodeEnum` is never closed in {}", proto_path.display());
+    };
+
+    let re_variant = RegexBuilder::new(r"^\s*(\w+) = (-?\d+),$").build()?;
+
+    let mut variants = String::new();
+    let mut from_code = String::new();
+    let mut to_code = String::new();
+    let mut to_name = String::new();
+
+    for line in body.lines() {
+        let Some(captures) = re_variant.captures(line) else {
+            // doc comments and attributes carry over as is.
+            variants.push_str(line);
+            variants.push('\n');
+            continue;
+        };
+
+        let (name, code) = (&captures[1], &captures[2]);
+
+        if code == "0" {
+            variants.push_str("    #[default]\n");
+        }
+
+        variants.push_str(&format!("    {name},\n"));
+        from_code.push_str(&format!("            {code} => Self::{name},\n"));
+        to_code.push_str(&format!("            Status::{name} => {code},\n"));
+        to_name.push_str(&format!(
+            "            Self::{name} => crate::services::ResponseCodeEnum::{name}.as_str_name(),\n"
+        ));
+    }
+
+    let status = format!(
+        r#"/// Returned in `TransactionReceipt`, `Error::PreCheckStatus`, and `Error::ReceiptStatus`.
+///
+/// The success variant is `Success` which is what a `TransactionReceipt` will contain for a
+/// successful transaction.
+///
+/// Response codes that are newer than this version of the SDK are kept as [`Status::Unrecognized`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Status {{
+{variants}    /// A response code that this version of the SDK doesn't know about.
+    ///
+    /// Not to be confused with [`Status::Unknown`], which the network returns for a receipt that isn't known yet.
+    Unrecognized(i32),
+}}
+
+#[allow(deprecated)]
+impl From<i32> for Status {{
+    fn from(code: i32) -> Self {{
+        match code {{
+{from_code}            code => Self::Unrecognized(code),
+        }}
+    }}
+}}
+
+#[allow(deprecated)]
+impl From<Status> for i32 {{
+    fn from(status: Status) -> Self {{
+        match status {{
+{to_code}            Status::Unrecognized(code) => code,
+        }}
+    }}
+}}
+
+impl From<crate::services::ResponseCodeEnum> for Status {{
+    fn from(code: crate::services::ResponseCodeEnum) -> Self {{
+        Self::from(code as i32)
+    }}
+}}
+
+impl Status {{
+    /// Returns the name of the response code as used in the protobuf definition (`"SUCCESS"` for [`Status::Success`]).
+    ///
+    /// Returns `"UNRECOGNIZED"` for [`Status::Unrecognized`].
+    #[allow(deprecated)]
+    #[must_use]
+    pub fn as_str_name(&self) -> &'static str {{
+        match self {{
+{to_name}            Self::Unrecognized(_) => "UNRECOGNIZED",
+        }}
+    }}
+
+    /// Creates a `Status` from the name of a response code as used in the protobuf definition.
+    #[must_use]
+    pub fn from_str_name(value: &str) -> Option<Self> {{
+        crate::services::ResponseCodeEnum::from_str_name(value).map(Self::from)
+    }}
+}}
+
+impl std::fmt::Display for Status {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        match self {{
+            Self::Unrecognized(code) => write!(f, "UNRECOGNIZED({{code}})"),
+            _ => f.write_str(self.as_str_name()),
+        }}
+    }}
+}}
+"#
+    );
+
+    fs::write(status_path, status)?;
+
+    Ok(())
+}
+
 trait BuilderExtensions {
     fn services_path<T: AsRef<str>, U: AsRef<str>>(self, proto_name: T, rust_name: U) -> Self
     where
@@ -33,6 +33,12 @@ pub mod services {
     tonic::include_proto!("proto");
 }
 
+mod status {
+    include!(concat!(env!("OUT_DIR"), "/status.rs"));
+}
+
+pub use status::Status;
+
 // fixme: Do this, just, don't warn 70 times in generated code.
 #[allow(clippy::derive_partial_eq_without_eq)]
 pub mod mirror {
@@ -27,6 +27,7 @@ use crate::protobuf::FromProtobuf;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -230,6 +231,10 @@ impl TransactionExecute for AccountAllowanceApproveTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { CryptoServiceClient::new(channel).approve_allowances(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Crypto
+    }
 }
 
 impl ValidateChecksums for AccountAllowanceApproveTransactionData {
@@ -263,7 +268,7 @@ impl ToTransactionDataProtobuf for AccountAllowanceApproveTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::CryptoApproveAllowance(self.to_protobuf())
     }
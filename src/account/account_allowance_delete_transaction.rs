@@ -30,6 +30,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -110,6 +111,10 @@ impl TransactionExecute for AccountAllowanceDeleteTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { CryptoServiceClient::new(channel).delete_allowances(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Crypto
+    }
 }
 
 impl ValidateChecksums for AccountAllowanceDeleteTransactionData {
@@ -127,7 +132,7 @@ impl ToTransactionDataProtobuf for AccountAllowanceDeleteTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::CryptoDeleteAllowance(self.to_protobuf())
     }
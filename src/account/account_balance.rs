@@ -19,10 +19,18 @@
  */
 
 use std::collections::HashMap;
+use std::fmt;
 
 use hedera_proto::services;
 use prost::Message;
 
+#[cfg(feature = "mirror-rest")]
+use crate::mirror_node::{
+    field,
+    parse_field,
+    tinybar_field,
+    u64_field,
+};
 use crate::protobuf::ToProtobuf;
 use crate::{
     AccountId,
@@ -42,10 +50,15 @@ pub struct AccountBalance {
     pub hbars: Hbar,
 
     /// Token balances for the referenced account.
+    ///
+    /// Consensus nodes are phasing out reporting token balances,
+    /// once they stop populating it this will be empty; use a mirror node query instead.
     // #[deprecated = "use a mirror query"]
     pub tokens: HashMap<TokenId, u64>,
 
     /// Token decimals for the referenced account.
+    ///
+    /// Like [`tokens`](Self::tokens), this will be empty once consensus nodes stop reporting it.
     #[deprecated = "use a mirror query"]
     #[allow(deprecated)]
     pub token_decimals: HashMap<TokenId, u32>,
@@ -63,16 +76,87 @@ impl AccountBalance {
 
     /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
     #[must_use]
+    #[allow(deprecated)]
     pub fn to_bytes(&self) -> Vec<u8> {
-        #[allow(deprecated)]
+        let mut token_balances: Vec<_> = self
+            .tokens
+            .iter()
+            .map(|(id, balance)| services::TokenBalance {
+                token_id: Some(id.to_protobuf()),
+                balance: *balance,
+                decimals: self.token_decimals.get(id).copied().unwrap_or_default(),
+            })
+            .collect();
+
+        // `HashMap` iteration order is unspecified, keep the output deterministic.
+        token_balances.sort_unstable_by_key(|it| {
+            it.token_id.as_ref().map(|id| (id.shard_num, id.realm_num, id.token_num))
+        });
+
         services::CryptoGetAccountBalanceResponse {
             header: None,
             account_id: Some(self.account_id.to_protobuf()),
             balance: self.hbars.to_tinybars() as u64,
-            token_balances: Vec::default(),
+            token_balances,
         }
         .encode_to_vec()
     }
+
+    /// Create a new `AccountBalance` from a mirror node REST `/accounts/{id}` response.
+    ///
+    /// The mirror node doesn't report token decimals, so `token_decimals` is always empty.
+    #[cfg(feature = "mirror-rest")]
+    #[allow(deprecated)]
+    pub(crate) fn from_mirror_json(json: &serde_json::Value) -> crate::Result<Self> {
+        let account_id: AccountId = parse_field(json, "account")?;
+
+        let balance = field(json, "balance")?;
+
+        let hbars = Hbar::from_tinybars(tinybar_field(balance, "balance")?);
+
+        let mut tokens = HashMap::new();
+
+        if let Some(token_balances) = balance.get("tokens").and_then(serde_json::Value::as_array) {
+            for token in token_balances {
+                let token_id: TokenId = parse_field(token, "token_id")?;
+
+                tokens.insert(token_id, u64_field(token, "balance")?);
+            }
+        }
+
+        Ok(Self { account_id, hbars, tokens, token_decimals: HashMap::new() })
+    }
+}
+
+impl fmt::Display for AccountBalance {
+    /// Formats the hbar balance followed by a summary of the token balances (ordered by token ID),
+    /// for example `10 ℏ, 2 tokens, 0.0.1001: 100, 0.0.1002: 5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // any more than this and the rest are summarized as "and N more".
+        const MAX_TOKENS: usize = 5;
+
+        write!(f, "{}", self.hbars)?;
+
+        if self.tokens.is_empty() {
+            return Ok(());
+        }
+
+        let mut tokens: Vec<_> = self.tokens.iter().collect();
+        tokens.sort_unstable_by_key(|(id, _)| (id.shard, id.realm, id.num));
+
+        let plural = if tokens.len() == 1 { "" } else { "s" };
+        write!(f, ", {} token{plural}", tokens.len())?;
+
+        for (id, balance) in tokens.iter().take(MAX_TOKENS) {
+            write!(f, ", {id}: {balance}")?;
+        }
+
+        if tokens.len() > MAX_TOKENS {
+            write!(f, ", and {} more", tokens.len() - MAX_TOKENS)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl FromProtobuf<services::CryptoGetAccountBalanceResponse> for AccountBalance {
@@ -107,3 +191,125 @@ impl FromProtobuf<services::response::Response> for AccountBalance {
         Self::from_protobuf(response)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use hedera_proto::services;
+
+    use crate::protobuf::ToProtobuf;
+    use crate::{
+        AccountBalance,
+        AccountId,
+        FromProtobuf,
+        Hbar,
+        TokenId,
+    };
+
+    #[allow(deprecated)]
+    fn make_balance(tokens: HashMap<TokenId, u64>) -> AccountBalance {
+        AccountBalance {
+            account_id: AccountId::new(0, 0, 5005),
+            hbars: Hbar::new(10),
+            tokens,
+            token_decimals: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn display() {
+        let balance = make_balance(HashMap::from([
+            (TokenId::new(1, 2, 3), 100),
+            (TokenId::new(0, 0, 1001), 5),
+        ]));
+
+        assert_eq!(balance.to_string(), "10 ℏ, 2 tokens, 0.0.1001: 5, 1.2.3: 100");
+    }
+
+    #[test]
+    fn display_no_tokens() {
+        assert_eq!(make_balance(HashMap::new()).to_string(), "10 ℏ");
+    }
+
+    #[test]
+    fn display_truncated() {
+        let balance = make_balance((1..=7).map(|num| (TokenId::new(0, 0, num), num)).collect());
+
+        assert_eq!(
+            balance.to_string(),
+            "10 ℏ, 7 tokens, 0.0.1: 1, 0.0.2: 2, 0.0.3: 3, 0.0.4: 4, 0.0.5: 5, and 2 more"
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn from_protobuf_with_token_balances() {
+        let pb = services::CryptoGetAccountBalanceResponse {
+            header: None,
+            account_id: Some(AccountId::new(0, 0, 5005).to_protobuf()),
+            balance: 1500,
+            token_balances: vec![
+                services::TokenBalance {
+                    token_id: Some(TokenId::new(0, 0, 1001).to_protobuf()),
+                    balance: 100,
+                    decimals: 2,
+                },
+                services::TokenBalance {
+                    token_id: Some(TokenId::new(0, 0, 1002).to_protobuf()),
+                    balance: 5,
+                    decimals: 8,
+                },
+            ],
+        };
+
+        let balance = AccountBalance::from_protobuf(pb).unwrap();
+
+        assert_eq!(balance.account_id, AccountId::new(0, 0, 5005));
+        assert_eq!(balance.hbars, Hbar::from_tinybars(1500));
+        assert_eq!(
+            balance.tokens,
+            HashMap::from([(TokenId::new(0, 0, 1001), 100), (TokenId::new(0, 0, 1002), 5)])
+        );
+        assert_eq!(
+            balance.token_decimals,
+            HashMap::from([(TokenId::new(0, 0, 1001), 2), (TokenId::new(0, 0, 1002), 8)])
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn from_protobuf_without_token_balances() {
+        let pb = services::CryptoGetAccountBalanceResponse {
+            header: None,
+            account_id: Some(AccountId::new(0, 0, 5005).to_protobuf()),
+            balance: 1500,
+            token_balances: Vec::new(),
+        };
+
+        let balance = AccountBalance::from_protobuf(pb).unwrap();
+
+        assert!(balance.tokens.is_empty());
+        assert!(balance.token_decimals.is_empty());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn to_from_bytes() {
+        let mut balance = make_balance(HashMap::from([
+            (TokenId::new(0, 0, 1001), 100),
+            (TokenId::new(0, 0, 1002), 5),
+        ]));
+        balance.token_decimals = HashMap::from([(TokenId::new(0, 0, 1001), 2)]);
+
+        let decoded = AccountBalance::from_bytes(&balance.to_bytes()).unwrap();
+
+        assert_eq!(decoded.account_id, balance.account_id);
+        assert_eq!(decoded.hbars, balance.hbars);
+        assert_eq!(decoded.tokens, balance.tokens);
+        assert_eq!(
+            decoded.token_decimals,
+            HashMap::from([(TokenId::new(0, 0, 1001), 2), (TokenId::new(0, 0, 1002), 0)])
+        );
+    }
+}
@@ -18,6 +18,11 @@
  * ‍
  */
 
+#[cfg(feature = "mirror-rest")]
+use std::time::Duration;
+
+#[cfg(feature = "mirror-rest")]
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
 use services::crypto_get_account_balance_query::BalanceSource;
@@ -84,8 +89,8 @@ impl AccountBalanceQuery {
     /// Sets the account ID for which information is requested.
     ///
     /// This is mutually exclusive with [`contract_id`](Self::contract_id).
-    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data.source = AccountBalanceSource::AccountId(id);
+    pub fn account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data.source = AccountBalanceSource::AccountId(id.into());
         self
     }
 
@@ -101,12 +106,31 @@ impl AccountBalanceQuery {
     /// Sets the contract ID for which information is requested.
     ///
     /// This is mutually exclusive with [`account_id`](Self::account_id).
-    pub fn contract_id(&mut self, id: ContractId) -> &mut Self {
-        self.data.source = AccountBalanceSource::ContractId(id);
+    pub fn contract_id(&mut self, id: impl Into<ContractId>) -> &mut Self {
+        self.data.source = AccountBalanceSource::ContractId(id.into());
         self
     }
 }
 
+#[cfg(feature = "mirror-rest")]
+impl AccountBalanceQueryData {
+    async fn execute_mirror_rest(
+        &self,
+        client: &crate::Client,
+        timeout: Option<Duration>,
+    ) -> crate::Result<AccountBalance> {
+        let id = match self.source {
+            AccountBalanceSource::AccountId(id) => id.to_string(),
+            AccountBalanceSource::ContractId(id) => id.to_string(),
+        };
+
+        let json =
+            crate::mirror_node::get_json(client, &format!("/accounts/{id}"), timeout).await?;
+
+        AccountBalance::from_mirror_json(&json)
+    }
+}
+
 impl ToQueryProtobuf for AccountBalanceQueryData {
     fn to_query_protobuf(&self, header: services::QueryHeader) -> services::Query {
         let source = Some(&self.source).as_ref().map(|source| match source {
@@ -139,6 +163,19 @@ impl QueryExecute for AccountBalanceQueryData {
     ) -> BoxGrpcFuture<'_, services::Response> {
         Box::pin(async { CryptoServiceClient::new(channel).crypto_get_balance(request).await })
     }
+
+    #[cfg(feature = "mirror-rest")]
+    fn execute_mirror<'a>(
+        &'a self,
+        client: &'a crate::Client,
+        timeout: Option<Duration>,
+    ) -> Option<BoxFuture<'a, crate::Result<Self::Response>>> {
+        if !client.balance_query_prefer_mirror() {
+            return None;
+        }
+
+        Some(Box::pin(self.execute_mirror_rest(client, timeout)))
+    }
 }
 
 impl ValidateChecksums for AccountBalanceQueryData {
@@ -256,4 +293,65 @@ mod tests {
 
         assert_eq!(query.get_contract_id(), Some(ContractId::new(0, 0, 5005)));
     }
+
+    #[cfg(feature = "mirror-rest")]
+    #[tokio::test]
+    async fn prefer_mirror() -> crate::Result<()> {
+        use tokio::io::{
+            AsyncReadExt,
+            AsyncWriteExt,
+        };
+        use tokio::net::TcpListener;
+
+        use crate::{
+            Client,
+            Hbar,
+            TokenId,
+        };
+
+        const BODY: &str = r#"{"account":"0.0.5005","balance":{"balance":1500,"timestamp":"1718822400.000000000","tokens":[{"token_id":"0.0.7","balance":42}]}}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        // a single-shot mock of the mirror node REST API.
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.windows(4).any(|it| it == b"\r\n\r\n") {
+                let read = stream.read(&mut buf).await.unwrap();
+                assert_ne!(read, 0);
+                request.extend_from_slice(&buf[..read]);
+            }
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{BODY}",
+                BODY.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+
+            String::from_utf8(request).unwrap()
+        });
+
+        let client = Client::for_testnet();
+        client.set_mirror_network([address.to_string()]);
+        client.set_balance_query_prefer_mirror(true);
+
+        let balance = AccountBalanceQuery::new()
+            .account_id(AccountId::new(0, 0, 5005))
+            .execute(&client)
+            .await?;
+
+        let request = server.await.unwrap();
+
+        assert!(request.starts_with("GET /api/v1/accounts/0.0.5005 HTTP/1.1\r\n"));
+
+        assert_eq!(balance.account_id, AccountId::new(0, 0, 5005));
+        assert_eq!(balance.hbars, Hbar::from_tinybars(1500));
+        assert_eq!(balance.tokens.get(&TokenId::new(0, 0, 7)), Some(&42));
+
+        Ok(())
+    }
 }
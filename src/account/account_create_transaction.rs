@@ -23,6 +23,10 @@ use hedera_proto::services::crypto_service_client::CryptoServiceClient;
 use time::Duration;
 use tonic::transport::Channel;
 
+use crate::account::{
+    validate_max_automatic_token_associations,
+    validate_transaction_max_automatic_token_associations,
+};
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
     FromProtobuf,
@@ -32,6 +36,7 @@ use crate::staked_id::StakedId;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -181,8 +186,8 @@ impl AccountCreateTransaction {
     ///
     /// # Network Support
     /// Please note that this not supported on any hedera network at this time.
-    pub fn auto_renew_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().auto_renew_account_id = Some(id);
+    pub fn auto_renew_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().auto_renew_account_id = Some(id.into());
         self
     }
 
@@ -207,6 +212,10 @@ impl AccountCreateTransaction {
     }
 
     /// Sets the maximum number of tokens that an Account can be implicitly associated with.
+    ///
+    /// `-1` means unlimited automatic token associations.
+    ///
+    /// Anything less than `-1` is rejected when the transaction is frozen.
     pub fn max_automatic_token_associations(&mut self, amount: i32) -> &mut Self {
         self.data_mut().max_automatic_token_associations = amount;
         self
@@ -241,8 +250,8 @@ impl AccountCreateTransaction {
 
     /// Sets the ID of the account to which this account is staking.
     /// This is mutually exclusive with `staked_node_id`.
-    pub fn staked_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().staked_id = Some(StakedId::AccountId(id));
+    pub fn staked_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().staked_id = Some(StakedId::AccountId(id.into()));
         self
     }
 
@@ -273,7 +282,11 @@ impl AccountCreateTransaction {
     }
 }
 
-impl TransactionData for AccountCreateTransactionData {}
+impl TransactionData for AccountCreateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate_transaction_max_automatic_token_associations(self.max_automatic_token_associations)
+    }
+}
 
 impl TransactionExecute for AccountCreateTransactionData {
     fn execute(
@@ -283,6 +296,10 @@ impl TransactionExecute for AccountCreateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { CryptoServiceClient::new(channel).create_account(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Crypto
+    }
 }
 
 impl ValidateChecksums for AccountCreateTransactionData {
@@ -296,7 +313,7 @@ impl ToTransactionDataProtobuf for AccountCreateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::CryptoCreateAccount(self.to_protobuf())
     }
@@ -327,7 +344,9 @@ impl FromProtobuf<services::CryptoCreateTransactionBody> for AccountCreateTransa
             auto_renew_period: pb.auto_renew_period.map(Into::into),
             auto_renew_account_id: None,
             account_memo: pb.memo,
-            max_automatic_token_associations: pb.max_automatic_token_associations,
+            max_automatic_token_associations: validate_max_automatic_token_associations(
+                pb.max_automatic_token_associations,
+            )?,
             alias,
             staked_id: Option::from_protobuf(pb.staked_id)?,
             decline_staking_reward: pb.decline_reward,
@@ -375,6 +394,7 @@ impl ToProtobuf for AccountCreateTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
     use hex_literal::hex;
@@ -395,6 +415,7 @@ mod tests {
         AccountCreateTransaction,
         AccountId,
         AnyTransaction,
+        Error,
         EvmAddress,
         Hbar,
         PublicKey,
@@ -720,6 +741,36 @@ mod tests {
         assert_eq!(tx.max_automatic_token_associations, MAX_AUTOMATIC_TOKEN_ASSOCIATIONS);
     }
 
+    #[test]
+    fn from_proto_body_unlimited_max_automatic_token_associations() {
+        let tx = services::CryptoCreateTransactionBody {
+            max_automatic_token_associations: -1,
+            ..Default::default()
+        };
+
+        let tx = AccountCreateTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(tx.max_automatic_token_associations, -1);
+    }
+
+    #[test]
+    fn from_proto_body_invalid_max_automatic_token_associations() {
+        let tx = services::CryptoCreateTransactionBody {
+            max_automatic_token_associations: -5,
+            ..Default::default()
+        };
+
+        assert_matches!(AccountCreateTransactionData::from_protobuf(tx), Err(Error::BasicParse(_)));
+    }
+
+    #[test]
+    fn freeze_rejects_invalid_max_automatic_token_associations() {
+        let mut tx = AccountCreateTransaction::new_for_tests();
+        tx.max_automatic_token_associations(-2);
+
+        assert_matches!(tx.freeze(), Err(Error::InvalidTransaction(_)));
+    }
+
     #[test]
     fn properties() {
         let tx = make_transaction();
@@ -30,6 +30,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -67,8 +68,8 @@ impl AccountDeleteTransaction {
     }
 
     /// Sets the account ID which should be deleted.
-    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().account_id = Some(id);
+    pub fn account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().account_id = Some(id.into());
         self
     }
 
@@ -79,8 +80,8 @@ impl AccountDeleteTransaction {
     }
 
     /// Sets the account ID which will receive all remaining hbars.
-    pub fn transfer_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().transfer_account_id = Some(id);
+    pub fn transfer_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().transfer_account_id = Some(id.into());
         self
     }
 }
@@ -95,6 +96,10 @@ impl TransactionExecute for AccountDeleteTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { CryptoServiceClient::new(channel).crypto_delete(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Crypto
+    }
 }
 
 impl ValidateChecksums for AccountDeleteTransactionData {
@@ -109,7 +114,7 @@ impl ToTransactionDataProtobuf for AccountDeleteTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::CryptoDelete(self.to_protobuf())
     }
@@ -106,6 +106,25 @@ impl AccountId {
         }
     }
 
+    /// Create an `AccountId` in `shard.realm` from an evm address.
+    ///
+    /// A "long-zero" address (the solidity address of an account) becomes `shard.realm.num`,
+    /// any other address is kept as the account's evm address.
+    #[must_use]
+    pub fn from_shard_realm_evm_address(shard: u64, realm: u64, address: &EvmAddress) -> Self {
+        match address.long_zero_num() {
+            Some(num) => Self::new(shard, realm, num),
+            None => Self {
+                shard,
+                realm,
+                num: 0,
+                alias: None,
+                evm_address: Some(*address),
+                checksum: None,
+            },
+        }
+    }
+
     /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
     #[must_use]
     pub fn to_bytes(&self) -> Vec<u8> {
@@ -125,29 +144,48 @@ impl AccountId {
     ///
     /// # Errors
     /// - [`Error::CannotCreateChecksum`] if self has an `alias` or `evm_address`.
+    /// - [`Error::CannotCreateChecksumWithoutLedgerId`] if the client has no `ledger_id`.
     pub fn to_string_with_checksum(&self, client: &Client) -> Result<String, Error> {
         if self.alias.is_some() || self.evm_address.is_some() {
             Err(Error::CannotCreateChecksum)
         } else {
-            Ok(EntityId::to_string_with_checksum(self.to_string(), client))
+            EntityId::to_string_with_checksum(self.shard, self.realm, self.num, client)
         }
     }
 
     /// Validates `self.checksum` (if it exists) for `client`.
     ///
     /// # Errors
+    /// - [`Error::BasicParse`] if `self` has both a `num` and an `alias` or `evm_address`.
     /// - [`Error::BadEntityId`] if there is a checksum, and the checksum is not valid for the client's `ledger_id`.
+    /// - [`Error::CannotValidateChecksumWithoutLedgerId`] if there is a checksum, and the client has no `ledger_id`.
     pub fn validate_checksum(&self, client: &Client) -> crate::Result<()> {
+        self.validate_num_or_alias()?;
+
         if self.alias.is_some() || self.evm_address.is_some() {
             Ok(())
         } else {
             EntityId::validate_checksum(self.shard, self.realm, self.num, self.checksum, client)
         }
     }
+
+    /// An account is identified by *either* its `num` or an alias, the protobuf can't hold both.
+    fn validate_num_or_alias(&self) -> crate::Result<()> {
+        if self.num != 0 && (self.alias.is_some() || self.evm_address.is_some()) {
+            return Err(Error::basic_parse(format!(
+                "account ID `{}.{}.{}` has a `num` or an alias, not both",
+                self.shard, self.realm, self.num
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 impl ValidateChecksums for AccountId {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.validate_num_or_alias()?;
+
         if self.alias.is_some() || self.evm_address.is_some() {
             Ok(())
         } else {
@@ -176,7 +214,12 @@ impl Display for AccountId {
         if let Some(alias) = &self.alias {
             write!(f, "{}.{}.{}", self.shard, self.realm, alias)
         } else if let Some(evm_address) = &self.evm_address {
-            write!(f, "{evm_address}")
+            // the short form can't say what shard/realm the account is in.
+            if self.shard == 0 && self.realm == 0 {
+                write!(f, "{evm_address}")
+            } else {
+                write!(f, "{}.{}.{:x}", self.shard, self.realm, evm_address)
+            }
         } else {
             write!(f, "{}.{}.{}", self.shard, self.realm, self.num)
         }
@@ -186,13 +229,19 @@ impl Display for AccountId {
 impl ToProtobuf for AccountId {
     type Protobuf = services::AccountId;
 
+    // an account ID with both a `num` and an alias is rejected by validation, if it gets here the alias wins.
     fn to_protobuf(&self) -> Self::Protobuf {
         services::AccountId {
             realm_num: self.realm as i64,
             shard_num: self.shard as i64,
-            account: Some(match &self.alias {
-                None => services::account_id::Account::AccountNum(self.num as i64),
-                Some(alias) => services::account_id::Account::Alias(ToProtobuf::to_bytes(alias)),
+            account: Some(match (&self.alias, &self.evm_address) {
+                (Some(alias), _) => {
+                    services::account_id::Account::Alias(ToProtobuf::to_bytes(alias))
+                }
+                (None, Some(evm_address)) => {
+                    services::account_id::Account::Alias(evm_address.to_bytes().to_vec())
+                }
+                (None, None) => services::account_id::Account::AccountNum(self.num as i64),
             }),
         }
     }
@@ -229,6 +278,12 @@ impl From<u64> for AccountId {
     }
 }
 
+impl From<EvmAddress> for AccountId {
+    fn from(address: EvmAddress) -> Self {
+        Self::from_evm_address(&address)
+    }
+}
+
 impl From<PublicKey> for AccountId {
     fn from(alias: PublicKey) -> Self {
         Self { num: 0, shard: 0, realm: 0, evm_address: None, alias: Some(alias), checksum: None }
@@ -252,6 +307,25 @@ impl FromStr for AccountId {
                 Ok(Self::from_evm_address(&evm_address.parse()?))
             }
 
+            // <shard>.<realm>.<evm_address>
+            PartialEntityId::LongOther { shard, realm, last }
+                if last.strip_prefix("0x").unwrap_or(last).len() == 40 =>
+            {
+                let evm_address = match last.starts_with("0x") {
+                    true => last.parse()?,
+                    false => format!("0x{last}").parse()?,
+                };
+
+                Ok(Self {
+                    shard,
+                    realm,
+                    num: 0,
+                    alias: None,
+                    evm_address: Some(evm_address),
+                    checksum: None,
+                })
+            }
+
             // <shard>.<realm>.<alias>
             PartialEntityId::LongOther { shard, realm, last } => Ok(Self {
                 shard,
@@ -284,6 +358,7 @@ mod tests {
     use crate::{
         AccountId,
         Client,
+        Error,
         ValidateChecksums,
     };
 
@@ -350,6 +425,76 @@ mod tests {
         )
     }
 
+    #[test]
+    fn long_zero_round_trip() {
+        let account_id = AccountId::from_str("0.0.5005").unwrap();
+        let evm_address: EvmAddress = "0x000000000000000000000000000000000000138d".parse().unwrap();
+
+        assert_eq!(AccountId::from_shard_realm_evm_address(0, 0, &evm_address), account_id);
+        assert_eq!(
+            account_id.to_solidity_address().unwrap(),
+            "000000000000000000000000000000000000138d"
+        );
+        assert_eq!(AccountId::from_bytes(&account_id.to_bytes()).unwrap(), account_id);
+        assert_eq!(account_id.to_string(), "0.0.5005");
+    }
+
+    #[test]
+    fn evm_address_round_trip() {
+        let evm_address = EvmAddress(hex!("302a300506032b6570032100114e6abc371b82da"));
+
+        for s in [
+            "0x302a300506032b6570032100114e6abc371b82da",
+            "1.2.302a300506032b6570032100114e6abc371b82da",
+        ] {
+            let account_id = AccountId::from_str(s).unwrap();
+
+            assert_eq!(account_id.evm_address, Some(evm_address));
+            assert_eq!(account_id.num, 0);
+            assert_eq!(account_id.to_string(), s);
+            assert_eq!(AccountId::from_bytes(&account_id.to_bytes()).unwrap(), account_id);
+        }
+
+        // an explicit `0x` prefix is accepted too.
+        assert_eq!(
+            AccountId::from_str("1.2.0x302a300506032b6570032100114e6abc371b82da").unwrap(),
+            AccountId::from_str("1.2.302a300506032b6570032100114e6abc371b82da").unwrap()
+        );
+    }
+
+    #[test]
+    fn alias_round_trip() {
+        let alias = crate::PrivateKey::generate_ed25519().public_key();
+        let account_id = alias.to_account_id(1, 2);
+
+        let s = account_id.to_string();
+        assert_eq!(s, format!("1.2.{alias}"));
+
+        let parsed = AccountId::from_str(&s).unwrap();
+        assert_eq!(parsed, account_id);
+        assert_eq!(parsed.alias, Some(alias));
+        assert_eq!(parsed.num, 0);
+
+        assert_eq!(AccountId::from_bytes(&account_id.to_bytes()).unwrap(), account_id);
+    }
+
+    #[test]
+    fn num_and_alias_errors() {
+        let account_id = AccountId {
+            alias: Some(crate::PrivateKey::generate_ed25519().public_key()),
+            ..AccountId::new(0, 0, 1001)
+        };
+
+        assert_matches!(
+            ValidateChecksums::validate_checksums(&account_id, RefLedgerId::MAINNET),
+            Err(Error::BasicParse(_))
+        );
+        assert_matches!(
+            account_id.validate_checksum(&Client::for_mainnet()),
+            Err(Error::BasicParse(_))
+        );
+    }
+
     #[test]
     fn good_checksum_on_mainnet() {
         let account_id = AccountId::from_str("0.0.123-vfmkw").unwrap();
@@ -399,6 +544,29 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn good_checksum_on_testnet_client() {
+        let client = Client::for_testnet();
+        let id = AccountId::from_str("0.0.123-esxsf").unwrap();
+
+        id.validate_checksum(&client).unwrap();
+        assert_eq!(id.to_string_with_checksum(&client).unwrap(), "0.0.123-esxsf");
+    }
+
+    #[tokio::test]
+    async fn checksum_without_ledger_id() {
+        let client = Client::for_testnet();
+        client.set_ledger_id(None);
+
+        // no checksum, nothing to validate.
+        AccountId::from_str("0.0.123").unwrap().validate_checksum(&client).unwrap();
+
+        assert_matches!(
+            AccountId::from_str("0.0.123-esxsf").unwrap().validate_checksum(&client),
+            Err(crate::Error::CannotValidateChecksumWithoutLedgerId)
+        );
+    }
+
     #[test]
     fn malformed_id_fails() {
         assert_matches!(AccountId::from_str("0.0."), Err(crate::Error::BasicParse(_)));
@@ -514,4 +682,30 @@ mod tests {
         expect_test::expect!["0x302a300506032b6570032100114e6abc371b82da"]
             .assert_eq(&id.to_string());
     }
+
+    #[test]
+    fn from_shard_realm_evm_address() {
+        let evm_address =
+            EvmAddress::from_str("0x302a300506032b6570032100114e6abc371b82da").unwrap();
+
+        let id = AccountId::from_shard_realm_evm_address(1, 2, &evm_address);
+
+        assert_eq!((id.shard, id.realm, id.num), (1, 2, 0));
+        assert_eq!(id.evm_address, Some(evm_address));
+    }
+
+    #[test]
+    fn from_shard_realm_evm_address_long_zero() {
+        let evm_address =
+            EvmAddress::from_str("0x000000000000000000000000000000000000138d").unwrap();
+
+        let id = AccountId::from_shard_realm_evm_address(1, 2, &evm_address);
+
+        assert_eq!(id, AccountId::new(1, 2, 5005));
+    }
+
+    #[test]
+    fn from_u64() {
+        assert_eq!(AccountId::from(5005), AccountId::new(0, 0, 5005));
+    }
 }
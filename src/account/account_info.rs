@@ -25,6 +25,7 @@ use time::{
     OffsetDateTime,
 };
 
+use crate::account::validate_max_automatic_token_associations;
 use crate::protobuf::ToProtobuf;
 use crate::{
     AccountId,
@@ -99,7 +100,9 @@ pub struct AccountInfo {
     pub owned_nfts: u64,
 
     /// The maximum number of tokens that an Account can be implicitly associated with.
-    pub max_automatic_token_associations: u32,
+    ///
+    /// `-1` means that there's no limit.
+    pub max_automatic_token_associations: i32,
 
     /// The alias of this account.
     pub alias_key: Option<PublicKey>,
@@ -140,7 +143,7 @@ impl AccountInfo {
             auto_renew_period: self.auto_renew_period.to_protobuf(),
             memo: self.account_memo.clone(),
             owned_nfts: self.owned_nfts as i64,
-            max_automatic_token_associations: self.max_automatic_token_associations as i32,
+            max_automatic_token_associations: self.max_automatic_token_associations,
             alias: self.alias_key.as_ref().map(ToProtobuf::to_bytes).unwrap_or_default(),
             ledger_id: self.ledger_id.to_bytes(),
             ethereum_nonce: self.ethereum_nonce as i64,
@@ -195,7 +198,9 @@ impl FromProtobuf<services::crypto_get_info_response::AccountInfo> for AccountIn
             auto_renew_period: pb.auto_renew_period.map(Into::into),
             account_memo: pb.memo,
             owned_nfts: pb.owned_nfts as u64,
-            max_automatic_token_associations: pb.max_automatic_token_associations as u32,
+            max_automatic_token_associations: validate_max_automatic_token_associations(
+                pb.max_automatic_token_associations,
+            )?,
             alias_key,
             ethereum_nonce: pb.ethereum_nonce as u64,
             is_receiver_signature_required: pb.receiver_sig_required,
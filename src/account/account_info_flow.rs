@@ -65,7 +65,7 @@ pub async fn verify_signature(
 pub async fn verify_transaction_signature<D: TransactionExecute>(
     client: &Client,
     account_id: AccountId,
-    transaction: &mut Transaction<D>,
+    transaction: &Transaction<D>,
 ) -> crate::Result<()> {
     let key = query_pk(client, account_id).await?;
 
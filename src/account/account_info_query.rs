@@ -64,8 +64,8 @@ impl AccountInfoQuery {
     }
 
     /// Sets the account ID for which information is requested.
-    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data.account_id = Some(id);
+    pub fn account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data.account_id = Some(id.into());
         self
     }
 }
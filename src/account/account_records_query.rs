@@ -63,8 +63,8 @@ impl AccountRecordsQuery {
     }
 
     /// Sets the account ID for which the records should be retrieved.
-    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data.account_id = Some(id);
+    pub fn account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data.account_id = Some(id.into());
         self
     }
 }
@@ -62,8 +62,8 @@ impl AccountStakersQuery {
     }
 
     /// Sets the account ID for which the records should be retrieved.
-    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data.account_id = Some(id);
+    pub fn account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data.account_id = Some(id.into());
         self
     }
 }
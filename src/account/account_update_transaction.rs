@@ -26,6 +26,10 @@ use time::{
 };
 use tonic::transport::Channel;
 
+use crate::account::{
+    validate_max_automatic_token_associations,
+    validate_transaction_max_automatic_token_associations,
+};
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
     FromProtobuf,
@@ -35,6 +39,7 @@ use crate::staked_id::StakedId;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -117,8 +122,8 @@ impl AccountUpdateTransaction {
     }
 
     /// Sets the ID for the account that is being updated.
-    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().account_id = Some(id);
+    pub fn account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().account_id = Some(id.into());
         self
     }
 
@@ -177,8 +182,8 @@ impl AccountUpdateTransaction {
     /// will behave as if `proxy_account_id` was `None`.
     #[deprecated]
     #[allow(deprecated)]
-    pub fn proxy_account_id(&mut self, proxy_account_id: AccountId) -> &mut Self {
-        self.data_mut().proxy_account_id = Some(proxy_account_id);
+    pub fn proxy_account_id(&mut self, proxy_account_id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().proxy_account_id = Some(proxy_account_id.into());
         self
     }
 
@@ -208,8 +213,8 @@ impl AccountUpdateTransaction {
     ///
     /// # Network Support
     /// Please note that this not supported on any hedera network at this time.
-    pub fn auto_renew_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().auto_renew_account_id = Some(id);
+    pub fn auto_renew_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().auto_renew_account_id = Some(id.into());
         self
     }
 
@@ -233,6 +238,9 @@ impl AccountUpdateTransaction {
 
     /// Sets the maximum number of tokens that an Account can be implicitly associated with.
     ///
+    /// `-1` means unlimited automatic token associations.
+    ///
+    /// Anything less than `-1` is rejected when the transaction is frozen.
     pub fn max_automatic_token_associations(&mut self, amount: i32) -> &mut Self {
         self.data_mut().max_automatic_token_associations = Some(amount);
         self
@@ -247,8 +255,8 @@ impl AccountUpdateTransaction {
 
     /// Sets the ID of the account to which this account is staking.
     /// This is mutually exclusive with `staked_node_id`.
-    pub fn staked_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().staked_id = Some(id.into());
+    pub fn staked_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().staked_id = Some(StakedId::AccountId(id.into()));
         self
     }
 
@@ -291,7 +299,15 @@ impl AccountUpdateTransaction {
     }
 }
 
-impl TransactionData for AccountUpdateTransactionData {}
+impl TransactionData for AccountUpdateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        if let Some(max) = self.max_automatic_token_associations {
+            validate_transaction_max_automatic_token_associations(max)?;
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for AccountUpdateTransactionData {
     fn execute(
@@ -301,6 +317,10 @@ impl TransactionExecute for AccountUpdateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { CryptoServiceClient::new(channel).update_account(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Crypto
+    }
 }
 
 impl ValidateChecksums for AccountUpdateTransactionData {
@@ -315,7 +335,7 @@ impl ToTransactionDataProtobuf for AccountUpdateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::CryptoUpdateAccount(self.to_protobuf())
     }
@@ -354,7 +374,10 @@ impl FromProtobuf<services::CryptoUpdateTransactionBody> for AccountUpdateTransa
             proxy_account_id: Option::from_protobuf(pb.proxy_account_id)?,
             expiration_time: pb.expiration_time.map(Into::into),
             account_memo: pb.memo,
-            max_automatic_token_associations: pb.max_automatic_token_associations,
+            max_automatic_token_associations: pb
+                .max_automatic_token_associations
+                .map(validate_max_automatic_token_associations)
+                .transpose()?,
             staked_id: Option::from_protobuf(pb.staked_id)?,
             decline_staking_reward: pb.decline_reward,
         })
@@ -60,3 +60,35 @@ pub use proxy_staker::{
     AllProxyStakers,
     ProxyStaker,
 };
+
+/// Checks that `value` is a valid `max_automatic_token_associations`.
+///
+/// `-1` means unlimited automatic token associations, anything less than that is invalid.
+pub(crate) fn validate_max_automatic_token_associations(value: i32) -> crate::Result<i32> {
+    if value < -1 {
+        return Err(crate::Error::basic_parse(invalid_max_automatic_token_associations(value)));
+    }
+
+    Ok(value)
+}
+
+/// Checks that a transaction's `max_automatic_token_associations` is valid before it gets frozen.
+///
+/// Same rules as [`validate_max_automatic_token_associations`], but fails with [`Error::InvalidTransaction`](crate::Error::InvalidTransaction).
+pub(crate) fn validate_transaction_max_automatic_token_associations(
+    value: i32,
+) -> crate::Result<()> {
+    if value < -1 {
+        return Err(crate::Error::invalid_transaction(invalid_max_automatic_token_associations(
+            value,
+        )));
+    }
+
+    Ok(())
+}
+
+fn invalid_max_automatic_token_associations(value: i32) -> String {
+    format!(
+        "invalid `max_automatic_token_associations` `{value}`: expected `-1` (unlimited) or a non-negative number"
+    )
+}
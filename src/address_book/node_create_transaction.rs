@@ -18,8 +18,6 @@
  * ‍
  */
 
-use std::net::Ipv4Addr;
-
 use hedera_proto::services;
 use hedera_proto::services::address_book_service_client::AddressBookServiceClient;
 use tonic::transport::Channel;
@@ -30,6 +28,7 @@ use crate::service_endpoint::ServiceEndpoint;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -86,8 +85,8 @@ impl NodeCreateTransaction {
     }
 
     /// Sets the account associated with the new node.
-    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
-        self.data_mut().account_id = Some(account_id);
+    pub fn account_id(&mut self, account_id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().account_id = Some(account_id.into());
         self
     }
 
@@ -188,7 +187,19 @@ impl NodeCreateTransaction {
     }
 }
 
-impl TransactionData for NodeCreateTransactionData {}
+impl TransactionData for NodeCreateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        if self.gossip_endpoints.is_empty() {
+            return Err(Error::invalid_transaction("a node needs at least one gossip endpoint"));
+        }
+
+        if self.service_endpoints.is_empty() {
+            return Err(Error::invalid_transaction("a node needs at least one service endpoint"));
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for NodeCreateTransactionData {
     fn execute(
@@ -198,6 +209,10 @@ impl TransactionExecute for NodeCreateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { AddressBookServiceClient::new(channel).create_node(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::AddressBook
+    }
 }
 
 impl ValidateChecksums for NodeCreateTransactionData {
@@ -212,7 +227,7 @@ impl ToTransactionDataProtobuf for NodeCreateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::NodeCreate(self.to_protobuf())
     }
@@ -236,30 +251,14 @@ impl FromProtobuf<services::NodeCreateTransactionBody> for NodeCreateTransaction
     fn from_protobuf(pb: services::NodeCreateTransactionBody) -> crate::Result<Self> {
         let gossip_endpoints = pb
             .gossip_endpoint
-            .iter()
-            .map(|it| {
-                let ip_addr_v4 = &it.ip_address_v4[..];
-                let ip = Ipv4Addr::new(ip_addr_v4[0], ip_addr_v4[1], ip_addr_v4[2], ip_addr_v4[3]);
-                ServiceEndpoint {
-                    ip_address_v4: Some(ip),
-                    port: it.port,
-                    domain_name: it.domain_name.clone(),
-                }
-            })
-            .collect();
+            .into_iter()
+            .map(ServiceEndpoint::from_endpoint_protobuf)
+            .collect::<crate::Result<_>>()?;
         let service_endpoints = pb
             .service_endpoint
-            .iter()
-            .map(|it| {
-                let ip_addr_v4 = &it.ip_address_v4[..];
-                let ip = Ipv4Addr::new(ip_addr_v4[0], ip_addr_v4[1], ip_addr_v4[2], ip_addr_v4[3]);
-                ServiceEndpoint {
-                    ip_address_v4: Some(ip),
-                    port: it.port,
-                    domain_name: it.domain_name.clone(),
-                }
-            })
-            .collect();
+            .into_iter()
+            .map(ServiceEndpoint::from_endpoint_protobuf)
+            .collect::<crate::Result<_>>()?;
 
         Ok(Self {
             account_id: FromProtobuf::from_protobuf(pb.account_id)?,
@@ -298,6 +297,7 @@ impl ToProtobuf for NodeCreateTransactionData {
 mod tests {
     use std::net::Ipv4Addr;
 
+    use assert_matches::assert_matches;
     use expect_test::expect_file;
     use hedera_proto::services;
 
@@ -316,6 +316,7 @@ mod tests {
     };
     use crate::{
         AnyTransaction,
+        Error,
         Key,
     };
 
@@ -399,6 +400,45 @@ mod tests {
         assert_eq!(data.admin_key, Some(Key::from(unused_private_key().public_key())));
     }
 
+    #[test]
+    fn domain_name_endpoint() {
+        let endpoint = ServiceEndpoint {
+            ip_address_v4: None,
+            port: 50211,
+            domain_name: "node.example.com".to_owned(),
+        };
+
+        let mut tx = NodeCreateTransaction::new_for_tests();
+
+        tx.account_id(TEST_ACCOUNT_ID)
+            .gossip_endpoints(make_ip_address_list())
+            .service_endpoints([endpoint.clone()])
+            .freeze()
+            .unwrap();
+
+        let pb = tx.data().to_protobuf();
+
+        assert!(pb.service_endpoint[0].ip_address_v4.is_empty());
+
+        let data = NodeCreateTransactionData::from_protobuf(pb).unwrap();
+
+        assert_eq!(data.service_endpoints, [endpoint]);
+        assert_eq!(data.gossip_endpoints, make_ip_address_list());
+    }
+
+    #[test]
+    fn freeze_requires_endpoints() {
+        let mut tx = NodeCreateTransaction::new_for_tests();
+        tx.account_id(TEST_ACCOUNT_ID).service_endpoints(make_ip_address_list());
+
+        assert_matches!(tx.freeze(), Err(Error::InvalidTransaction(_)));
+
+        let mut tx = NodeCreateTransaction::new_for_tests();
+        tx.account_id(TEST_ACCOUNT_ID).gossip_endpoints(make_ip_address_list());
+
+        assert_matches!(tx.freeze(), Err(Error::InvalidTransaction(_)));
+    }
+
     #[test]
     fn get_set_account_id() {
         let account_id = TEST_ACCOUNT_ID;
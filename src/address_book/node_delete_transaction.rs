@@ -27,6 +27,7 @@ use crate::protobuf::FromProtobuf;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -88,6 +89,10 @@ impl TransactionExecute for NodeDeleteTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { AddressBookServiceClient::new(channel).delete_node(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::AddressBook
+    }
 }
 
 impl ValidateChecksums for NodeDeleteTransactionData {
@@ -101,7 +106,7 @@ impl ToTransactionDataProtobuf for NodeDeleteTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::NodeDelete(self.to_protobuf())
     }
@@ -18,8 +18,6 @@
  * ‍
  */
 
-use std::net::Ipv4Addr;
-
 use hedera_proto::services;
 use hedera_proto::services::address_book_service_client::AddressBookServiceClient;
 use tonic::transport::Channel;
@@ -30,6 +28,7 @@ use crate::service_endpoint::ServiceEndpoint;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -112,8 +111,8 @@ impl NodeUpdateTransaction {
     }
 
     /// Sets the account associated with the new node.
-    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
-        self.data_mut().account_id = Some(account_id);
+    pub fn account_id(&mut self, account_id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().account_id = Some(account_id.into());
         self
     }
 
@@ -224,6 +223,10 @@ impl TransactionExecute for NodeUpdateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { AddressBookServiceClient::new(channel).update_node(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::AddressBook
+    }
 }
 
 impl ValidateChecksums for NodeUpdateTransactionData {
@@ -238,7 +241,7 @@ impl ToTransactionDataProtobuf for NodeUpdateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::NodeUpdate(self.to_protobuf())
     }
@@ -262,30 +265,14 @@ impl FromProtobuf<services::NodeUpdateTransactionBody> for NodeUpdateTransaction
     fn from_protobuf(pb: services::NodeUpdateTransactionBody) -> crate::Result<Self> {
         let gossip_endpoints = pb
             .gossip_endpoint
-            .iter()
-            .map(|it| {
-                let ip_addr_v4 = &it.ip_address_v4[..];
-                let ip = Ipv4Addr::new(ip_addr_v4[0], ip_addr_v4[1], ip_addr_v4[2], ip_addr_v4[3]);
-                ServiceEndpoint {
-                    ip_address_v4: Some(ip),
-                    port: it.port,
-                    domain_name: it.domain_name.clone(),
-                }
-            })
-            .collect();
+            .into_iter()
+            .map(ServiceEndpoint::from_endpoint_protobuf)
+            .collect::<crate::Result<_>>()?;
         let service_endpoints = pb
             .service_endpoint
-            .iter()
-            .map(|it| {
-                let ip_addr_v4 = &it.ip_address_v4[..];
-                let ip = Ipv4Addr::new(ip_addr_v4[0], ip_addr_v4[1], ip_addr_v4[2], ip_addr_v4[3]);
-                ServiceEndpoint {
-                    ip_address_v4: Some(ip),
-                    port: it.port,
-                    domain_name: it.domain_name.clone(),
-                }
-            })
-            .collect();
+            .into_iter()
+            .map(ServiceEndpoint::from_endpoint_protobuf)
+            .collect::<crate::Result<_>>()?;
 
         Ok(Self {
             node_id: pb.node_id,
@@ -52,7 +52,11 @@ pub(super) struct Operator {
 
 impl From<Operator> for super::Operator {
     fn from(value: Operator) -> Self {
-        Self { account_id: value.account_id.0, signer: AnySigner::PrivateKey(value.private_key.0) }
+        Self {
+            account_id: value.account_id.0,
+            signer: AnySigner::PrivateKey(value.private_key.0),
+            transaction_id_generator: None,
+        }
     }
 }
 
@@ -21,6 +21,7 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
+use std::future::Future;
 use std::num::{
     NonZeroU64,
     NonZeroUsize,
@@ -28,33 +29,54 @@ use std::num::{
 use std::sync::atomic::{
     AtomicBool,
     AtomicU64,
+    AtomicUsize,
     Ordering,
 };
-use std::time::Duration;
+use std::time::{
+    Duration,
+    Instant,
+};
 
+pub(crate) use network::tls::status_certificate_verify_error;
+use network::tls::TransportSecurity;
 pub(crate) use network::{
     Network,
     NetworkData,
 };
 pub(crate) use operator::Operator;
-use parking_lot::RwLock;
+use parking_lot::{
+    Mutex,
+    RwLock,
+};
+use time::OffsetDateTime;
 use tokio::sync::watch;
 use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
 use self::network::managed::ManagedNetwork;
 use self::network::mirror::MirrorNetwork;
 pub(crate) use self::network::mirror::MirrorNetworkData;
+pub use self::network::CustomNetwork;
 use crate::ping_query::PingQuery;
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
+    AccountInfoQuery,
     ArcSwapOption,
     Error,
+    ExchangeRates,
+    FeeSchedules,
+    FileContentsQuery,
+    FileId,
     Hbar,
     LedgerId,
     NodeAddressBook,
     PrivateKey,
     PublicKey,
+    TransactionId,
 };
 
 #[cfg(feature = "serde")]
@@ -62,6 +84,8 @@ mod config;
 
 mod network;
 mod operator;
+#[cfg(test)]
+mod tests;
 
 #[derive(Copy, Clone)]
 pub(crate) struct ClientBackoff {
@@ -85,6 +109,12 @@ impl Default for ClientBackoff {
     }
 }
 
+/// The maximum query payment used when neither the query nor the client has one set.
+const DEFAULT_MAX_QUERY_PAYMENT: Hbar = Hbar::new(1);
+
+/// The number of nodes [`Transaction::execute_parallel`](crate::Transaction::execute_parallel) submits to by default.
+const DEFAULT_MAX_PARALLEL_SUBMISSIONS: usize = 3;
+
 // yes, client is complicated enough for this, even if it's only internal.
 struct ClientBuilder {
     network: ManagedNetwork,
@@ -150,12 +180,24 @@ impl ClientBuilder {
             max_transaction_fee_tinybar: AtomicU64::new(
                 max_transaction_fee.map_or(0, NonZeroU64::get),
             ),
-            max_query_payment_tinybar: AtomicU64::new(max_query_payment.map_or(0, NonZeroU64::get)),
+            max_query_payment_tinybar: AtomicU64::new(
+                max_query_payment
+                    .map_or(DEFAULT_MAX_QUERY_PAYMENT.to_tinybars() as u64, NonZeroU64::get),
+            ),
+            auto_bump_transaction_fee_ceiling_tinybar: AtomicU64::new(0),
             ledger_id: ArcSwapOption::new(ledger_id.map(Arc::new)),
             auto_validate_checksums: AtomicBool::new(auto_validate_checksums),
             regenerate_transaction_ids: AtomicBool::new(regenerate_transaction_ids),
+            #[cfg(feature = "mirror-rest")]
+            balance_query_prefer_mirror: AtomicBool::new(false),
+            #[cfg(feature = "mirror-rest")]
+            mirror_rest_client: once_cell::sync::OnceCell::new(),
+            verify_signatures_before_submit: AtomicBool::new(false),
+            max_parallel_submissions: AtomicUsize::new(DEFAULT_MAX_PARALLEL_SUBMISSIONS),
             network_update_tx,
             backoff: RwLock::new(backoff),
+            node_selection_cache: Mutex::default(),
+            transaction_id_generator: RwLock::new(None),
         }))
     }
 }
@@ -165,11 +207,48 @@ struct ClientInner {
     operator: ArcSwapOption<Operator>,
     max_transaction_fee_tinybar: AtomicU64,
     max_query_payment_tinybar: AtomicU64,
+    auto_bump_transaction_fee_ceiling_tinybar: AtomicU64,
     ledger_id: ArcSwapOption<LedgerId>,
     auto_validate_checksums: AtomicBool,
     regenerate_transaction_ids: AtomicBool,
+    #[cfg(feature = "mirror-rest")]
+    balance_query_prefer_mirror: AtomicBool,
+    // shared by every mirror node REST request, so that connections are reused.
+    #[cfg(feature = "mirror-rest")]
+    mirror_rest_client: once_cell::sync::OnceCell<reqwest::Client>,
+    verify_signatures_before_submit: AtomicBool,
+    max_parallel_submissions: AtomicUsize,
     network_update_tx: watch::Sender<Option<Duration>>,
     backoff: RwLock<ClientBackoff>,
+    node_selection_cache: Mutex<NodeSelectionCache>,
+    transaction_id_generator: RwLock<Option<TransactionIdGenerator>>,
+}
+
+/// A user provided way of generating transaction IDs, in place of the time based default.
+#[derive(Clone)]
+pub(crate) struct TransactionIdGenerator(Arc<dyn Fn(AccountId) -> TransactionId + Send + Sync>);
+
+impl TransactionIdGenerator {
+    fn new<F: Fn(AccountId) -> TransactionId + Send + Sync + 'static>(f: F) -> Self {
+        Self(Arc::new(f).unsize(Coercion!(to dyn Fn(AccountId) -> TransactionId + Send + Sync)))
+    }
+
+    pub(crate) fn generate(&self, account_id: AccountId) -> TransactionId {
+        (self.0)(account_id)
+    }
+}
+
+impl fmt::Debug for TransactionIdGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransactionIdGenerator").finish_non_exhaustive()
+    }
+}
+
+#[derive(Default)]
+struct NodeSelectionCache {
+    duration: Option<Duration>,
+    // the network the selection was made from, so that a network update invalidates it.
+    entry: Option<(Instant, Arc<NetworkData>, Vec<AccountId>)>,
 }
 
 /// Managed client for use on the Hedera network.
@@ -289,6 +368,44 @@ impl Client {
         Ok(ClientBuilder::new(network).disable_network_updating().build())
     }
 
+    /// Construct a client for a fully custom network.
+    ///
+    /// Each node's endpoints are tried in order whenever the client (re)connects to it,
+    /// so later endpoints act as fallbacks for earlier ones.
+    ///
+    /// Note that this disables network auto-updating.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an endpoint isn't a valid `host:port`, or a node has no endpoints.
+    pub fn for_custom_network(network: CustomNetwork) -> crate::Result<Self> {
+        let nodes = Network::from_custom(&network)?;
+
+        let CustomNetwork { mirror_network, ledger_id, .. } = network;
+        let mirror_network =
+            MirrorNetwork::from_addresses(mirror_network.into_iter().map(Cow::Owned).collect());
+
+        Ok(ClientBuilder::new(ManagedNetwork::new(nodes, mirror_network))
+            .ledger_id(ledger_id)
+            .disable_network_updating()
+            .build())
+    }
+
+    /// Construct a client with the given mirror nodes, and no consensus nodes configured.
+    ///
+    /// This is enough for mirror queries and the [`MirrorNodeGateway`](crate::MirrorNodeGateway),
+    /// consensus nodes can be added later with [`set_network`](Self::set_network).
+    ///
+    /// Note that this disables network auto-updating.
+    #[must_use]
+    pub fn for_mirror_network<I: IntoIterator<Item = String>>(addresses: I) -> Self {
+        let network = ManagedNetwork::new(
+            NetworkData::default().into(),
+            MirrorNetwork::from_addresses(addresses.into_iter().map(Cow::Owned).collect()),
+        );
+
+        ClientBuilder::new(network).disable_network_updating().build()
+    }
+
     /// Construct a Hedera client pre-configured for mainnet access.
     #[must_use]
     pub fn for_mainnet() -> Self {
@@ -309,6 +426,25 @@ impl Client {
             .build()
     }
 
+    /// Construct a Hedera client pre-configured for a local node.
+    ///
+    /// The consensus node is `0.0.3` at `127.0.0.1:50211`, and the mirror node is at `127.0.0.1:5600`.
+    ///
+    /// A local node has no well-known ledger ID,
+    /// so none is set (use [`set_ledger_id`](Self::set_ledger_id) to validate checksums).
+    #[must_use]
+    pub fn for_localhost() -> Self {
+        let client = Self::for_network(HashMap::from([(
+            "127.0.0.1:50211".to_owned(),
+            AccountId::new(0, 0, 3),
+        )]))
+        .expect("BUG: localhost network should be valid");
+
+        client.set_mirror_network(["127.0.0.1:5600".to_owned()]);
+
+        client
+    }
+
     /// Updates the network to use the given address book.
     ///
     /// Note: This is only really useful if you used `for_network`, because the network can auto-update.
@@ -354,12 +490,32 @@ impl Client {
         self.net().0.load().set_max_node_attempts(NonZeroUsize::new(attempts))
     }
 
+    /// Returns how many BUSY responses in a row a node can give before requests rotate away from it, if there's a limit.
+    #[must_use]
+    pub fn max_node_busy_responses(&self) -> Option<NonZeroUsize> {
+        self.net().0.load().max_node_busy_responses()
+    }
+
+    /// Set how many BUSY responses in a row a node can give before requests rotate away from it.
+    ///
+    /// Once a node has responded with BUSY this many times in a row it's treated as unhealthy,
+    /// so requests move on to other nodes until its backoff elapses
+    /// (nodes that are unavailable are already avoided after their first failure).
+    ///
+    /// `0` means there's no limit, so a busy node stays in rotation, this is the default.
+    pub fn set_max_node_busy_responses(&self, max_busy_responses: usize) {
+        self.net().0.load().set_max_node_busy_responses(NonZeroUsize::new(max_busy_responses))
+    }
+
     /// Returns the max backoff interval for network nodes if gRPC response fail.    
     pub fn max_node_backoff(&self) -> Duration {
         self.net().0.load().max_backoff()
     }
 
     /// Sets max backoff interval for network nodes
+    ///
+    /// Each consecutive failure of a node exponentially increases how long it's avoided, up to this interval.
+    #[doc(alias = "set_node_max_backoff")]
     pub fn set_max_node_backoff(&self, max_node_backoff: Duration) {
         self.net().0.load().set_max_backoff(max_node_backoff)
     }
@@ -370,13 +526,86 @@ impl Client {
     }
 
     /// Sets initial backoff interval for network nodes
+    ///
+    /// This is how long a node is avoided after it first fails,
+    /// if every node is being avoided, the node whose backoff ends first is used.
+    #[doc(alias = "set_node_min_backoff")]
     pub fn set_min_node_backoff(&self, min_node_backoff: Duration) {
         self.net().0.load().set_min_backoff(min_node_backoff)
     }
 
+    /// Returns how long a node's connection is reused before its addresses are resolved again, if ever.
+    ///
+    /// Regardless of this, a node's addresses are resolved again when reconnecting to it after it was unavailable.
+    #[must_use]
+    pub fn dns_refresh_interval(&self) -> Option<Duration> {
+        self.net().0.load().dns_refresh_interval()
+    }
+
+    /// Sets how long a node's connection is reused before reconnecting, resolving its addresses again.
+    ///
+    /// Useful when nodes are addressed by domain name, so that a changed IP address is eventually picked up.
+    pub fn set_dns_refresh_interval(&self, dns_refresh_interval: Duration) {
+        self.net().0.load().set_dns_refresh_interval(Some(dns_refresh_interval))
+    }
+
+    /// Returns how long a node's connection can go unused before it's closed, if ever.
+    #[must_use]
+    pub fn channel_idle_timeout(&self) -> Option<Duration> {
+        self.net().0.load().channel_idle_timeout()
+    }
+
+    /// Sets how long a node's connection can go unused before it's closed.
+    ///
+    /// Every request to a node shares one connection, the next request after it's closed opens a new one.
+    /// By default, connections are kept for as long as the node is part of the network.
+    pub fn set_channel_idle_timeout(&self, timeout: Duration) {
+        self.net().0.load().set_channel_idle_timeout(Some(timeout));
+    }
+
+    /// Returns true if connections to consensus nodes are secured with TLS.
+    #[must_use]
+    pub fn transport_security(&self) -> bool {
+        self.net().0.load().transport_security().enabled
+    }
+
+    /// Enable or disable securing connections to consensus nodes with TLS.
+    ///
+    /// When enabled, nodes are connected to on their TLS port (`50212`) rather than the plaintext port (`50211`).
+    ///
+    /// Defaults to `false`.
+    pub fn set_transport_security(&self, enabled: bool) {
+        let network = self.net().0.load();
+
+        network
+            .set_transport_security(TransportSecurity { enabled, ..network.transport_security() });
+    }
+
+    /// Returns true if node certificates are checked against the address book when using transport security.
+    #[must_use]
+    pub fn verify_certificates(&self) -> bool {
+        self.net().0.load().transport_security().verify_certificates
+    }
+
+    /// Enable or disable checking node certificates against the address book when using transport security.
+    ///
+    /// When enabled, a node's certificate must match the certificate hash in its address book entry,
+    /// which means the network must have been set from an address book,
+    /// otherwise requests fail with [`Error::TransportSecurity`](crate::Error::TransportSecurity).
+    ///
+    /// Defaults to `true`.
+    pub fn set_verify_certificates(&self, verify: bool) {
+        let network = self.net().0.load();
+
+        network.set_transport_security(TransportSecurity {
+            verify_certificates: verify,
+            ..network.transport_security()
+        });
+    }
+
     /// Construct a hedera client pre-configured for access to the given network.
     ///
-    /// Currently supported network names are `"mainnet"`, `"testnet"`, and `"previewnet"`.
+    /// Currently supported network names are `"mainnet"`, `"testnet"`, `"previewnet"`, and `"localhost"`.
     ///
     /// # Errors
     /// - [`Error::BasicParse`] if the network name is not a supported network name.
@@ -385,14 +614,7 @@ impl Client {
             "mainnet" => Ok(Self::for_mainnet()),
             "testnet" => Ok(Self::for_testnet()),
             "previewnet" => Ok(Self::for_previewnet()),
-            "localhost" => {
-                let mut network: HashMap<String, AccountId> = HashMap::new();
-                network.insert("127.0.0.1:50211".to_string(), AccountId::new(0, 0, 3));
-
-                let client = Client::for_network(network).unwrap();
-                client.set_mirror_network(["127.0.0.1:5600".to_string()]);
-                Ok(client)
-            }
+            "localhost" => Ok(Self::for_localhost()),
             _ => Err(Error::basic_parse(format!("Unknown network name {name}"))),
         }
     }
@@ -403,7 +625,19 @@ impl Client {
         self.0.ledger_id.load()
     }
 
+    /// Returns the ledger ID for the Client's network, if one is set.
+    ///
+    /// Clients created with [`for_mainnet`](Self::for_mainnet), [`for_testnet`](Self::for_testnet),
+    /// or [`for_previewnet`](Self::for_previewnet) have the matching ledger ID.
+    #[must_use]
+    pub fn ledger_id(&self) -> Option<LedgerId> {
+        self.ledger_id_internal().as_deref().cloned()
+    }
+
     /// Sets the ledger ID for the Client's network.
+    ///
+    /// A ledger ID is required to validate entity ID checksums,
+    /// for a custom network this is the ledger ID of that network.
     pub fn set_ledger_id(&self, ledger_id: Option<LedgerId>) {
         self.0.ledger_id.store(ledger_id.map(Arc::new));
     }
@@ -415,10 +649,32 @@ impl Client {
     }
 
     /// Enable or disable automatic entity ID checksum validation.
+    ///
+    /// When enabled, freezing or executing a transaction (or executing a query) fails with
+    /// [`Error::BadEntityId`] if any entity ID has a checksum that isn't valid for this client's ledger ID.
     pub fn set_auto_validate_checksums(&self, value: bool) {
         self.0.auto_validate_checksums.store(value, Ordering::Relaxed);
     }
 
+    /// Returns true if transactions are checked for a payer signature when they're frozen with this client.
+    ///
+    /// This is `false` by default.
+    #[must_use]
+    pub fn verify_signatures_before_submit(&self) -> bool {
+        self.0.verify_signatures_before_submit.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable checking transactions for a payer signature when they're frozen with this client.
+    ///
+    /// The check runs in [`Transaction::freeze_with`](crate::Transaction::freeze_with)
+    /// (and so when executing a transaction that isn't frozen yet), so sign with the payer's key before freezing.
+    /// Transactions that are already frozen, such as ones frozen without a client, aren't checked.
+    ///
+    /// See [`Transaction::validate_signatures`](crate::Transaction::validate_signatures) for what is checked.
+    pub fn set_verify_signatures_before_submit(&self, value: bool) {
+        self.0.verify_signatures_before_submit.store(value, Ordering::Relaxed);
+    }
+
     /// Returns true if transaction IDs should be automatically regenerated.
     ///
     /// This is `true` by default.
@@ -432,6 +688,25 @@ impl Client {
         self.0.regenerate_transaction_ids.store(value, Ordering::Relaxed);
     }
 
+    /// Returns true if [`AccountBalanceQuery`](crate::AccountBalanceQuery) is routed through the mirror node REST API.
+    ///
+    /// This is `false` by default.
+    #[cfg(feature = "mirror-rest")]
+    #[must_use]
+    pub fn balance_query_prefer_mirror(&self) -> bool {
+        self.0.balance_query_prefer_mirror.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable routing [`AccountBalanceQuery`](crate::AccountBalanceQuery) through the mirror node REST API.
+    ///
+    /// When enabled, balances are fetched from the mirror network instead of consensus nodes,
+    /// which keeps balance queries working when consensus nodes are unreachable.
+    /// Note that mirror node balances may lag slightly behind consensus.
+    #[cfg(feature = "mirror-rest")]
+    pub fn set_balance_query_prefer_mirror(&self, value: bool) {
+        self.0.balance_query_prefer_mirror.store(value, Ordering::Relaxed);
+    }
+
     /// Sets the account that will, by default, be paying for transactions and queries built with
     /// this client.
     ///
@@ -439,10 +714,15 @@ impl Client {
     /// executed with this client.
     ///
     /// The operator private key is used to sign all transactions executed by this client.
+    ///
+    /// The operator is replaced atomically, transactions that were already frozen with this client
+    /// keep the operator they were frozen with.
     pub fn set_operator(&self, id: AccountId, key: PrivateKey) {
-        self.0
-            .operator
-            .store(Some(Arc::new(Operator { account_id: id, signer: AnySigner::PrivateKey(key) })));
+        self.0.operator.store(Some(Arc::new(Operator {
+            account_id: id,
+            signer: AnySigner::PrivateKey(key),
+            transaction_id_generator: self.transaction_id_generator(),
+        })));
     }
 
     /// Sets the account that will, by default, be paying for transactions and queries built with
@@ -461,9 +741,122 @@ impl Client {
         self.0.operator.store(Some(Arc::new(Operator {
             account_id: id,
             signer: AnySigner::arbitrary(Box::new(public_key), f),
+            transaction_id_generator: self.transaction_id_generator(),
+        })));
+    }
+
+    /// Sets the account that will, by default, be paying for transactions built with this client,
+    /// signing with an async signer, such as a hardware security module.
+    ///
+    /// Async signers can only sign when a transaction is executed,
+    /// so [`Transaction::to_bytes`](crate::Transaction::to_bytes) and paid queries
+    /// fail with [`Error::AsyncSignerRequiresExecute`](crate::Error::AsyncSignerRequiresExecute).
+    pub fn set_operator_with_async<F, Fut>(&self, id: AccountId, public_key: PublicKey, f: F)
+    where
+        F: Fn(&[u8]) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Vec<u8>> + Send + 'static,
+    {
+        self.0.operator.store(Some(Arc::new(Operator {
+            account_id: id,
+            signer: AnySigner::arbitrary_async(Box::new(public_key), f),
+            transaction_id_generator: self.transaction_id_generator(),
         })));
     }
 
+    /// Sets the clock used to generate transaction valid starts.
+    ///
+    /// By default the system clock is used, with the valid start backdated by a few seconds
+    /// to tolerate clock skew between the client and the network.
+    /// The time returned by a custom clock is used as-is, which makes generated
+    /// transaction IDs deterministic, this is mostly useful for testing.
+    ///
+    /// This replaces any generator set with [`set_transaction_id_generator`](Self::set_transaction_id_generator).
+    pub fn set_clock<F: Fn() -> OffsetDateTime + Send + Sync + 'static>(&self, clock: F) {
+        self.store_transaction_id_generator(TransactionIdGenerator::new(move |account_id| {
+            TransactionId { account_id, valid_start: clock(), nonce: None, scheduled: false }
+        }));
+    }
+
+    /// Sets the function used to generate transaction IDs for the operator,
+    /// both for transactions frozen with this client and when a transaction ID expires during execution.
+    ///
+    /// By default transaction IDs are time based, see [`TransactionId::generate`].
+    /// A generator that returns a fixed ID makes frozen transactions reproducible, this is mostly useful for testing.
+    ///
+    /// Transactions with an explicit [`transaction_id`](crate::Transaction::transaction_id) never use the generator.
+    ///
+    /// This replaces any clock set with [`set_clock`](Self::set_clock).
+    pub fn set_transaction_id_generator<F>(&self, generator: F)
+    where
+        F: Fn(AccountId) -> TransactionId + Send + Sync + 'static,
+    {
+        self.store_transaction_id_generator(TransactionIdGenerator::new(generator));
+    }
+
+    fn store_transaction_id_generator(&self, generator: TransactionIdGenerator) {
+        *self.0.transaction_id_generator.write() = Some(generator.clone());
+
+        // the operator generates transaction IDs for frozen transactions, so it needs the generator as well.
+        self.0.operator.rcu(|operator| {
+            operator.as_ref().map(|operator| {
+                Arc::new(Operator {
+                    account_id: operator.account_id,
+                    signer: operator.signer.clone(),
+                    transaction_id_generator: Some(generator.clone()),
+                })
+            })
+        });
+    }
+
+    pub(crate) fn transaction_id_generator(&self) -> Option<TransactionIdGenerator> {
+        self.0.transaction_id_generator.read().clone()
+    }
+
+    /// Returns how long a random node selection made when freezing a transaction is reused.
+    ///
+    /// This is `None` (no caching) by default.
+    #[must_use]
+    pub fn node_selection_cache_duration(&self) -> Option<Duration> {
+        self.0.node_selection_cache.lock().duration
+    }
+
+    /// Sets how long a random node selection made when freezing a transaction is reused.
+    ///
+    /// Within this window, transactions frozen without explicit node account IDs share the same nodes,
+    /// which avoids re-running node selection in tight loops.
+    /// The cached selection is discarded whenever the network changes.
+    pub fn set_node_selection_cache_duration(&self, duration: Option<Duration>) {
+        let mut cache = self.0.node_selection_cache.lock();
+        cache.duration = duration;
+        cache.entry = None;
+    }
+
+    /// Returns a random selection of healthy nodes, reusing a recent selection if caching is enabled.
+    pub(crate) fn random_node_ids(&self) -> Vec<AccountId> {
+        let network = self.net().0.load_full();
+
+        let mut cache = self.0.node_selection_cache.lock();
+
+        let Some(duration) = cache.duration else {
+            return network.random_node_ids();
+        };
+
+        let now = Instant::now();
+
+        if let Some((selected_at, cached_network, node_ids)) = &cache.entry {
+            if Arc::ptr_eq(cached_network, &network) && now.duration_since(*selected_at) < duration
+            {
+                return node_ids.clone();
+            }
+        }
+
+        let node_ids = network.random_node_ids();
+
+        cache.entry = Some((now, network, node_ids.clone()));
+
+        node_ids
+    }
+
     /// Gets a reference to the configured network.
     pub(crate) fn net(&self) -> &Network {
         &self.0.network.primary
@@ -474,6 +867,12 @@ impl Client {
         &self.0.network.mirror
     }
 
+    /// Gets the HTTP client used for requests to the mirror node REST API.
+    #[cfg(feature = "mirror-rest")]
+    pub(crate) fn mirror_rest_client(&self) -> &reqwest::Client {
+        self.0.mirror_rest_client.get_or_init(reqwest::Client::new)
+    }
+
     /// Sets the maximum transaction fee to be used when no explicit max transaction fee is set.
     ///
     /// Note: Setting `amount` to zero is "unlimited"
@@ -492,7 +891,34 @@ impl Client {
         (val > 0).then(|| Hbar::from_tinybars(val as i64))
     }
 
+    /// Returns the ceiling transaction fees are automatically bumped up to, if enabled.
+    #[must_use]
+    pub fn auto_bump_transaction_fee(&self) -> Option<Hbar> {
+        let val = self.0.auto_bump_transaction_fee_ceiling_tinybar.load(Ordering::Relaxed);
+
+        (val > 0).then(|| Hbar::from_tinybars(val as i64))
+    }
+
+    /// Enable or disable automatically bumping the max transaction fee when a transaction fails
+    /// pre-check with [`Status::InsufficientTxFee`](crate::Status::InsufficientTxFee).
+    ///
+    /// Only transactions relying on the default max transaction fee are bumped, that is,
+    /// transactions with no explicit max transaction fee on a client with no default max transaction fee.
+    /// The fee is never bumped past `ceiling`.
+    ///
+    /// # Panics
+    /// - if `ceiling` is negative
+    pub fn set_auto_bump_transaction_fee(&self, enabled: bool, ceiling: Hbar) {
+        assert!(ceiling >= Hbar::ZERO);
+
+        let ceiling = if enabled { ceiling.to_tinybars() as u64 } else { 0 };
+
+        self.0.auto_bump_transaction_fee_ceiling_tinybar.store(ceiling, Ordering::Relaxed);
+    }
+
     /// Gets the maximum query fee the paying account is willing to pay.
+    ///
+    /// Defaults to `1 ℏ`, `None` means there's no limit.
     #[must_use]
     pub fn default_max_query_payment(&self) -> Option<Hbar> {
         let val = self.0.max_query_payment_tinybar.load(Ordering::Relaxed);
@@ -532,6 +958,25 @@ impl Client {
         self.0.backoff.write().max_attempts = max_attempts;
     }
 
+    /// Returns the maximum number of nodes a transaction is submitted to at once by
+    /// [`Transaction::execute_parallel`](crate::Transaction::execute_parallel).
+    #[must_use]
+    pub fn max_parallel_submissions(&self) -> usize {
+        self.0.max_parallel_submissions.load(Ordering::Relaxed)
+    }
+
+    /// Sets the maximum number of nodes a transaction is submitted to at once by
+    /// [`Transaction::execute_parallel`](crate::Transaction::execute_parallel).
+    ///
+    /// Defaults to `3`.
+    ///
+    /// # Panics
+    /// - if `max` is zero.
+    pub fn set_max_parallel_submissions(&self, max: usize) {
+        assert!(max > 0, "at least one node must be submitted to");
+        self.0.max_parallel_submissions.store(max, Ordering::Relaxed);
+    }
+
     /// The initial backoff for a request being executed.
     #[doc(alias = "initial_backoff")]
     #[must_use]
@@ -541,8 +986,8 @@ impl Client {
 
     /// Sets the initial backoff for a request being executed.
     #[doc(alias = "set_initial_backoff")]
-    pub fn set_min_backoff(&self, max_backoff: Duration) {
-        self.0.backoff.write().max_backoff = max_backoff;
+    pub fn set_min_backoff(&self, min_backoff: Duration) {
+        self.0.backoff.write().initial_backoff = min_backoff;
     }
 
     /// Returns the maximum amount of time a request will wait between attempts.
@@ -571,6 +1016,41 @@ impl Client {
         self.0.operator.load_full()
     }
 
+    /// Returns the staking reward `account_id` will receive at the end of the current staking period.
+    ///
+    /// This is zero for accounts that aren't staked, or that decline rewards.
+    ///
+    /// # Errors
+    /// - If the [`AccountInfoQuery`] fails.
+    pub async fn pending_rewards(&self, account_id: AccountId) -> crate::Result<Hbar> {
+        let info = AccountInfoQuery::new().account_id(account_id).execute(self).await?;
+
+        Ok(info.staking.map_or(Hbar::ZERO, |it| it.pending_reward))
+    }
+
+    /// Fetches the network's current and next fee schedules from [`FileId::FEE_SCHEDULE`].
+    ///
+    /// # Errors
+    /// - If the [`FileContentsQuery`] fails.
+    /// - [`Error::FromProtobuf`] if the file's contents aren't valid fee schedules.
+    pub async fn fetch_fee_schedule(&self) -> crate::Result<FeeSchedules> {
+        let response = FileContentsQuery::new().file_id(FileId::FEE_SCHEDULE).execute(self).await?;
+
+        FeeSchedules::from_bytes(&response.contents)
+    }
+
+    /// Fetches the network's current and next exchange rates from [`FileId::EXCHANGE_RATES`].
+    ///
+    /// # Errors
+    /// - If the [`FileContentsQuery`] fails.
+    /// - [`Error::FromProtobuf`] if the file's contents aren't valid exchange rates.
+    pub async fn fetch_exchange_rates(&self) -> crate::Result<ExchangeRates> {
+        let response =
+            FileContentsQuery::new().file_id(FileId::EXCHANGE_RATES).execute(self).await?;
+
+        ExchangeRates::from_bytes(&response.contents)
+    }
+
     /// Send a ping to the given node.
     pub async fn ping(&self, node_account_id: AccountId) -> crate::Result<()> {
         PingQuery::new(node_account_id).execute(self, None).await
@@ -585,22 +1065,39 @@ impl Client {
         PingQuery::new(node_account_id).execute(self, Some(timeout)).await
     }
 
-    /// Send a ping to all nodes.
+    /// Send a ping to all nodes, concurrently.
+    ///
+    /// # Errors
+    /// - [`Error::PingAllFailed`] with the account ID of every node that failed its ping.
     pub async fn ping_all(&self) -> crate::Result<()> {
-        futures_util::future::try_join_all(
-            self.net().0.load().node_ids().iter().map(|it| self.ping(*it)),
-        )
-        .await?;
-
-        Ok(())
+        self.ping_all_with_optional_timeout(None).await
     }
 
-    /// Send a ping to all nodes, canceling the ping after `timeout` has elapsed.
+    /// Send a ping to all nodes, concurrently, canceling each ping after `timeout` has elapsed.
+    ///
+    /// # Errors
+    /// - [`Error::PingAllFailed`] with the account ID of every node that failed its ping.
     pub async fn ping_all_with_timeout(&self, timeout: Duration) -> crate::Result<()> {
-        futures_util::future::try_join_all(
-            self.net().0.load().node_ids().iter().map(|it| self.ping_with_timeout(*it, timeout)),
+        self.ping_all_with_optional_timeout(Some(timeout)).await
+    }
+
+    async fn ping_all_with_optional_timeout(&self, timeout: Option<Duration>) -> crate::Result<()> {
+        let node_ids = self.net().0.load().node_ids().to_vec();
+
+        let results = futures_util::future::join_all(
+            node_ids.iter().map(|it| PingQuery::new(*it).execute(self, timeout)),
         )
-        .await?;
+        .await;
+
+        let failures: Vec<_> = node_ids
+            .into_iter()
+            .zip(results)
+            .filter_map(|(node_account_id, result)| Some((node_account_id, result.err()?)))
+            .collect();
+
+        if !failures.is_empty() {
+            return Err(Error::PingAllFailed { failures });
+        }
 
         Ok(())
     }
@@ -627,13 +1124,45 @@ impl Client {
 
     /// Returns the Account ID for the operator.
     #[must_use]
-    pub fn get_operator_account_id(&self) -> Option<AccountId> {
+    pub fn operator_account_id(&self) -> Option<AccountId> {
         self.load_operator().as_deref().map(|it| it.account_id)
     }
 
     /// Returns the `PublicKey` for the current operator.
     #[must_use]
-    pub fn get_operator_public_key(&self) -> Option<PublicKey> {
+    pub fn operator_public_key(&self) -> Option<PublicKey> {
         self.load_operator().as_deref().map(|it| it.signer.public_key())
     }
+
+    /// Returns the Account ID for the operator.
+    ///
+    /// Same as [`operator_account_id`](Self::operator_account_id).
+    #[deprecated = "use `operator_account_id` instead"]
+    #[must_use]
+    pub fn get_operator_account_id(&self) -> Option<AccountId> {
+        self.operator_account_id()
+    }
+
+    /// Returns the `PublicKey` for the current operator.
+    ///
+    /// Same as [`operator_public_key`](Self::operator_public_key).
+    #[deprecated = "use `operator_public_key` instead"]
+    #[must_use]
+    pub fn get_operator_public_key(&self) -> Option<PublicKey> {
+        self.operator_public_key()
+    }
+
+    /// Generates a new transaction ID for the operator, the same way transactions frozen with this client do.
+    ///
+    /// By default the valid start is backdated by a random 5 to 8 seconds, see [`TransactionId::generate`],
+    /// this can be changed with [`set_transaction_id_generator`](Self::set_transaction_id_generator).
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if the client has no operator.
+    pub fn generate_transaction_id(&self) -> crate::Result<TransactionId> {
+        self.load_operator()
+            .as_deref()
+            .map(Operator::generate_transaction_id)
+            .ok_or(Error::NoPayerAccountOrTransactionId)
+    }
 }
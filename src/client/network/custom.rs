@@ -0,0 +1,112 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::{
+    AccountId,
+    LedgerId,
+};
+
+/// A fully custom network of consensus nodes, for use with [`Client::for_custom_network`](crate::Client::for_custom_network).
+///
+/// Unlike [`Client::for_network`](crate::Client::for_network), each node's endpoints are kept in the order given,
+/// and connecting to a node tries them in that order until one accepts the connection.
+///
+/// # Examples
+/// ```
+/// # fn main() -> hedera::Result<()> {
+/// use hedera::{AccountId, Client, CustomNetwork};
+///
+/// let mut network = CustomNetwork::new();
+/// network
+///     .node(AccountId::new(0, 0, 3), ["10.0.0.1:50211", "10.0.1.1:50211"])
+///     .node(AccountId::new(0, 0, 4), ["10.0.0.2:50211", "10.0.1.2:50211"])
+///     .mirror_network(["10.0.0.10:5600"]);
+///
+/// let client = Client::for_custom_network(network)?;
+/// # drop(client);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CustomNetwork {
+    pub(crate) nodes: Vec<(AccountId, Vec<String>)>,
+    pub(crate) mirror_network: Vec<String>,
+    pub(crate) ledger_id: Option<LedgerId>,
+}
+
+impl CustomNetwork {
+    /// Create a new, empty, `CustomNetwork`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `endpoints` (as `host:port`) to the node with the given account ID.
+    ///
+    /// Endpoints are tried in the order they're added, adding the same node again appends to its endpoints.
+    pub fn node<I>(&mut self, account_id: AccountId, endpoints: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let endpoints = endpoints.into_iter().map(Into::into);
+
+        match self.nodes.iter_mut().find(|(id, _)| *id == account_id) {
+            Some((_, existing)) => existing.extend(endpoints),
+            None => self.nodes.push((account_id, endpoints.collect())),
+        }
+
+        self
+    }
+
+    /// Returns the nodes of this network, and their endpoints in the order they're tried.
+    #[must_use]
+    pub fn get_nodes(&self) -> &[(AccountId, Vec<String>)] {
+        &self.nodes
+    }
+
+    /// Sets the mirror node addresses (as `host:port`) of this network.
+    pub fn mirror_network<I>(&mut self, addresses: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.mirror_network = addresses.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the mirror node addresses of this network.
+    #[must_use]
+    pub fn get_mirror_network(&self) -> &[String] {
+        &self.mirror_network
+    }
+
+    /// Sets the ID of the ledger this network is part of, used for validating entity ID checksums.
+    pub fn ledger_id(&mut self, ledger_id: LedgerId) -> &mut Self {
+        self.ledger_id = Some(ledger_id);
+        self
+    }
+
+    /// Returns the ID of the ledger this network is part of.
+    #[must_use]
+    pub fn get_ledger_id(&self) -> Option<&LedgerId> {
+        self.ledger_id.as_ref()
+    }
+}
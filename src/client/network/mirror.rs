@@ -30,14 +30,13 @@ use tonic::transport::{
 };
 use triomphe::Arc;
 
+use super::addressbook::{
+    MAINNET_MIRROR,
+    PREVIEWNET_MIRROR,
+    TESTNET_MIRROR,
+};
 use crate::ArcSwap;
 
-pub(crate) const MAINNET: &str = "mainnet-public.mirrornode.hedera.com:443";
-
-pub(crate) const TESTNET: &str = "testnet.mirrornode.hedera.com:443";
-
-pub(crate) const PREVIEWNET: &str = "previewnet.mirrornode.hedera.com:443";
-
 #[derive(Default)]
 pub(crate) struct MirrorNetwork(ArcSwap<MirrorNetworkData>);
 
@@ -51,15 +50,15 @@ impl Deref for MirrorNetwork {
 
 impl MirrorNetwork {
     pub(crate) fn mainnet() -> Self {
-        Self::network(MAINNET)
+        Self::network(MAINNET_MIRROR)
     }
 
     pub(crate) fn testnet() -> Self {
-        Self::network(TESTNET)
+        Self::network(TESTNET_MIRROR)
     }
 
     pub(crate) fn previewnet() -> Self {
-        Self::network(PREVIEWNET)
+        Self::network(PREVIEWNET_MIRROR)
     }
 
     fn network(address: &'static str) -> Self {
@@ -68,7 +67,6 @@ impl MirrorNetwork {
         Self(ArcSwap::new(Arc::new(MirrorNetworkData::from_static(&[address], tls_config))))
     }
 
-    #[cfg(feature = "serde")]
     pub(crate) fn from_addresses(addresses: Vec<Cow<'static, str>>) -> Self {
         Self(ArcSwap::new(Arc::new(MirrorNetworkData::from_addresses(addresses))))
     }
@@ -119,4 +117,24 @@ impl MirrorNetworkData {
     pub(crate) fn addresses(&self) -> impl Iterator<Item = String> + '_ {
         self.addresses.iter().cloned().map(Cow::into_owned)
     }
+
+    /// Returns the base URL of the mirror node REST API, derived from the first mirror address.
+    ///
+    /// Addresses on port 443 are assumed to serve the REST API over https on the same host.
+    /// A local node serves gRPC on port 5600 and the REST API on port 5551 of the same host,
+    /// anything else is assumed to serve it over plain http at the same address.
+    #[cfg(feature = "mirror-rest")]
+    pub(crate) fn rest_base_url(&self) -> Option<String> {
+        let address = self.addresses.first()?;
+
+        if let Some(host) = address.strip_suffix(":443") {
+            return Some(format!("https://{host}/api/v1"));
+        }
+
+        if let Some(host) = address.strip_suffix(":5600") {
+            return Some(format!("http://{host}:5551/api/v1"));
+        }
+
+        Some(format!("http://{address}/api/v1"))
+    }
 }
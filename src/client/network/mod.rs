@@ -18,14 +18,15 @@
  * ‍
  */
 
+pub(super) mod addressbook;
+mod custom;
 pub(super) mod managed;
 pub(super) mod mirror;
+mod tcp;
+pub(crate) mod tls;
 
 use std::borrow::Cow;
-use std::collections::{
-    BTreeSet,
-    HashMap,
-};
+use std::collections::HashMap;
 use std::fmt;
 use std::net::Ipv4Addr;
 use std::num::NonZeroUsize;
@@ -36,8 +37,10 @@ use std::time::{
 };
 
 use backoff::backoff::Backoff;
-use once_cell::sync::OnceCell;
-use parking_lot::RwLock;
+use parking_lot::{
+    Mutex,
+    RwLock,
+};
 use rand::thread_rng;
 use tonic::transport::{
     Channel,
@@ -45,6 +48,17 @@ use tonic::transport::{
 };
 use triomphe::Arc;
 
+use self::addressbook::{
+    MAINNET,
+    PREVIEWNET,
+    TESTNET,
+};
+pub use self::custom::CustomNetwork;
+use self::tcp::TcpConnector;
+use self::tls::{
+    TlsConnector,
+    TransportSecurity,
+};
 use crate::{
     AccountId,
     ArcSwap,
@@ -52,58 +66,6 @@ use crate::{
     NodeAddressBook,
 };
 
-pub(crate) const MAINNET: &[(u64, &[&str])] = &[
-    (3, &["13.124.142.126", "15.164.44.66", "15.165.118.251", "34.239.82.6", "35.237.200.180"]),
-    (4, &["3.130.52.236", "35.186.191.247"]),
-    (5, &["3.18.18.254", "23.111.186.250", "35.192.2.25", "74.50.117.35", "107.155.64.98"]),
-    (6, &["13.52.108.243", "13.71.90.154", "35.199.161.108", "104.211.205.124"]),
-    (7, &["3.114.54.4", "35.203.82.240"]),
-    (8, &["35.183.66.150", "35.236.5.219"]),
-    (9, &["35.181.158.250", "35.197.192.225"]),
-    (10, &["3.248.27.48", "35.242.233.154", "177.154.62.234"]),
-    (11, &["13.53.119.185", "35.240.118.96"]),
-    (12, &["35.177.162.180", "35.204.86.32", "170.187.184.238"]),
-    (13, &["34.215.192.104", "35.234.132.107"]),
-    (14, &["35.236.2.27", "52.8.21.141"]),
-    (15, &["3.121.238.26", "35.228.11.53"]),
-    (16, &["18.157.223.230", "34.91.181.183"]),
-    (17, &["18.232.251.19", "34.86.212.247"]),
-    (18, &["141.94.175.187"]),
-    (19, &["13.244.166.210", "13.246.51.42", "18.168.4.59", "34.89.87.138"]),
-    (20, &["34.82.78.255", "52.39.162.216"]),
-    (21, &["13.36.123.209", "34.76.140.109"]),
-    (22, &["34.64.141.166", "52.78.202.34"]),
-    (23, &["3.18.91.176", "35.232.244.145", "69.167.169.208"]),
-    (24, &["18.135.7.211", "34.89.103.38"]),
-    (25, &["13.232.240.207", "34.93.112.7"]),
-    (26, &["13.228.103.14", "34.87.150.174"]),
-    (27, &["13.56.4.96", "34.125.200.96"]),
-    (28, &["18.139.47.5", "35.198.220.75"]),
-    (29, &["34.142.71.129", "54.74.60.120", "80.85.70.197"]),
-    (30, &["34.201.177.212", "35.234.249.150"]),
-    (31, &["3.77.94.254", "34.107.78.179"]),
-];
-
-pub(crate) const TESTNET: &[(u64, &[&str])] = &[
-    (3, &["0.testnet.hedera.com", "34.94.106.61", "50.18.132.211"]),
-    (4, &["1.testnet.hedera.com", "35.237.119.55", "3.212.6.13"]),
-    (5, &["2.testnet.hedera.com", "35.245.27.193", "52.20.18.86"]),
-    (6, &["3.testnet.hedera.com", "34.83.112.116", "54.70.192.33"]),
-    (7, &["4.testnet.hedera.com", "34.94.160.4", "54.176.199.109"]),
-    (8, &["5.testnet.hedera.com", "34.106.102.218", "35.155.49.147"]),
-    (9, &["6.testnet.hedera.com", "34.133.197.230", "52.14.252.207"]),
-];
-
-pub(crate) const PREVIEWNET: &[(u64, &[&str])] = &[
-    (3, &["0.previewnet.hedera.com", "35.231.208.148", "3.211.248.172", "40.121.64.48"]),
-    (4, &["1.previewnet.hedera.com", "35.199.15.177", "3.133.213.146", "40.70.11.202"]),
-    (5, &["2.previewnet.hedera.com", "35.225.201.195", "52.15.105.130", "104.43.248.63"]),
-    (6, &["3.previewnet.hedera.com", "35.247.109.135", "54.241.38.1", "13.88.22.47"]),
-    (7, &["4.previewnet.hedera.com", "35.235.65.51", "54.177.51.127", "13.64.170.40"]),
-    (8, &["5.previewnet.hedera.com", "34.106.247.65", "35.83.89.171", "13.78.232.192"]),
-    (9, &["6.previewnet.hedera.com", "34.125.23.49", "50.18.17.93", "20.150.136.89"]),
-];
-
 pub(crate) struct Network(pub(crate) ArcSwap<NetworkData>);
 
 impl Network {
@@ -123,6 +85,10 @@ impl Network {
         Ok(NetworkData::from_addresses(addresses)?.into())
     }
 
+    pub(super) fn from_custom(network: &CustomNetwork) -> crate::Result<Self> {
+        Ok(NetworkData::from_custom(network)?.into())
+    }
+
     fn try_rcu<T: Into<Arc<NetworkData>>, E, F: FnMut(&Arc<NetworkData>) -> Result<T, E>>(
         &self,
         mut f: F,
@@ -181,6 +147,13 @@ pub(crate) struct NetworkData {
     // Health stuff has to be in an Arc because it needs to stick around even if the map changes.
     health: Box<[Arc<parking_lot::RwLock<NodeHealth>>]>,
     connections: Box<[NodeConnection]>,
+    // how many BUSY responses in a row a node can give before it's considered unhealthy, if there's a limit.
+    max_node_busy_responses: RwLock<Option<NonZeroUsize>>,
+    // how long a node's channel can be used before it's rebuilt (and its addresses are resolved again).
+    dns_refresh_interval: RwLock<Option<Duration>>,
+    // how long a node's channel can go unused before it's closed (and rebuilt on next use).
+    channel_idle_timeout: RwLock<Option<Duration>>,
+    transport_security: RwLock<TransportSecurity>,
 }
 
 impl NetworkData {
@@ -188,6 +161,42 @@ impl NetworkData {
         Self::default().with_addresses(addresses)
     }
 
+    pub(crate) fn from_custom(network: &CustomNetwork) -> crate::Result<Self> {
+        let mut map = HashMap::with_capacity(network.nodes.len());
+        let mut node_ids = Vec::with_capacity(network.nodes.len());
+        let mut connections = Vec::with_capacity(network.nodes.len());
+        let mut health = Vec::with_capacity(network.nodes.len());
+
+        for (i, (node, endpoints)) in network.nodes.iter().enumerate() {
+            if endpoints.is_empty() {
+                return Err(Error::basic_parse(format!("node `{node}` has no endpoints")));
+            }
+
+            let mut addresses: Vec<HostAndPort> = Vec::with_capacity(endpoints.len());
+
+            for endpoint in endpoints {
+                let address = endpoint.parse()?;
+
+                if !addresses.contains(&address) {
+                    addresses.push(address);
+                }
+            }
+
+            map.insert(*node, i);
+            node_ids.push(*node);
+            health.push(Arc::default());
+            connections.push(NodeConnection::new(addresses).with_failover());
+        }
+
+        Ok(Self {
+            map,
+            node_ids: node_ids.into_boxed_slice(),
+            health: health.into_boxed_slice(),
+            connections: connections.into_boxed_slice(),
+            ..Self::default()
+        })
+    }
+
     pub(crate) fn from_static(network: &'static [(u64, &'static [&'static str])]) -> Self {
         let mut map = HashMap::with_capacity(network.len());
         let mut node_ids = Vec::with_capacity(network.len());
@@ -209,6 +218,10 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            max_node_busy_responses: RwLock::default(),
+            dns_refresh_interval: RwLock::default(),
+            channel_idle_timeout: RwLock::default(),
+            transport_security: RwLock::default(),
         }
     }
 
@@ -221,31 +234,35 @@ impl NetworkData {
         let mut health = Vec::with_capacity(address_book.len());
 
         for (i, address) in address_book.iter().enumerate() {
-            let new: BTreeSet<_> = address
+            let new: Vec<_> = address
                 .service_endpoints
                 .iter()
                 .filter(|it| it.port() == NodeConnection::PLAINTEXT_PORT)
                 .map(|it| (*it.ip()).into())
                 .collect();
 
+            let cert_hash =
+                tls::normalize_certificate_hash(&address.tls_certificate_hash).map(Arc::from);
+
             // if the node is the exact same we want to reuse everything (namely the connections and `healthy`).
             // if the node has different routes then we still want to reuse `healthy` but replace the channel with a new channel.
             // if the node just flat out doesn't exist in `old`, we want to add the new node.
             // and, last but not least, if the node doesn't exist in `new` we want to get rid of it.
             let upsert = match old.map.get(&address.node_account_id) {
                 Some(&account) => {
-                    let connection =
-                        match old.connections[account].addresses.symmetric_difference(&new).count()
-                        {
-                            0 => old.connections[account].clone(),
-                            _ => NodeConnection { addresses: new, channel: OnceCell::new() },
-                        };
+                    let old_connection = &old.connections[account];
+
+                    let unchanged =
+                        old_connection.addresses == new && old_connection.cert_hash == cert_hash;
+
+                    let connection = match unchanged {
+                        true => old_connection.clone(),
+                        false => NodeConnection::new(new).with_cert_hash(cert_hash),
+                    };
 
                     (old.health[account].clone(), connection)
                 }
-                None => {
-                    (Arc::default(), NodeConnection { addresses: new, channel: OnceCell::new() })
-                }
+                None => (Arc::default(), NodeConnection::new(new).with_cert_hash(cert_hash)),
             };
 
             map.insert(address.node_account_id, i);
@@ -260,6 +277,10 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            max_node_busy_responses: RwLock::new(old.max_node_busy_responses()),
+            dns_refresh_interval: RwLock::new(old.dns_refresh_interval()),
+            channel_idle_timeout: RwLock::new(old.channel_idle_timeout()),
+            transport_security: RwLock::new(old.transport_security()),
         }
     }
 
@@ -277,16 +298,17 @@ impl NetworkData {
 
             match map.entry(*node) {
                 Entry::Occupied(entry) => {
-                    connections[*entry.get()].addresses.insert(address);
+                    let addresses = &mut connections[*entry.get()].addresses;
+
+                    if !addresses.contains(&address) {
+                        addresses.push(address);
+                    }
                 }
                 Entry::Vacant(entry) => {
                     entry.insert(next_index);
                     node_ids.push(*node);
                     // fixme: keep the channel around more.
-                    connections.push(NodeConnection {
-                        addresses: BTreeSet::from([address]),
-                        channel: OnceCell::new(),
-                    });
+                    connections.push(NodeConnection::new(vec![address]));
 
                     health.push(match self.map.get(node) {
                         Some(it) => self.health[*it].clone(),
@@ -296,12 +318,21 @@ impl NetworkData {
             };
         }
 
+        // `addresses` has no meaningful order, sort so that the order endpoints are tried in is stable.
+        for connection in &mut connections {
+            connection.addresses.sort();
+        }
+
         Ok(Self {
             map,
             node_ids: node_ids.into_boxed_slice(),
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            max_node_busy_responses: RwLock::new(self.max_node_busy_responses()),
+            dns_refresh_interval: RwLock::new(self.dns_refresh_interval()),
+            channel_idle_timeout: RwLock::new(self.channel_idle_timeout()),
+            transport_security: RwLock::new(self.transport_security()),
         })
     }
 
@@ -355,6 +386,55 @@ impl NetworkData {
         self.backoff.read().min_backoff
     }
 
+    // Sets how many BUSY responses in a row a node can give before it's considered unhealthy, `None` means there's no limit.
+    pub(crate) fn set_max_node_busy_responses(&self, max_busy_responses: Option<NonZeroUsize>) {
+        *self.max_node_busy_responses.write() = max_busy_responses;
+    }
+
+    // Returns how many BUSY responses in a row a node can give before it's considered unhealthy, if there's a limit.
+    #[must_use]
+    pub(crate) fn max_node_busy_responses(&self) -> Option<NonZeroUsize> {
+        *self.max_node_busy_responses.read()
+    }
+
+    // Sets how long a node's channel can be used before its addresses are resolved again.
+    pub(crate) fn set_dns_refresh_interval(&self, dns_refresh_interval: Option<Duration>) {
+        *self.dns_refresh_interval.write() = dns_refresh_interval;
+    }
+
+    // Returns how long a node's channel can be used before its addresses are resolved again.
+    #[must_use]
+    pub(crate) fn dns_refresh_interval(&self) -> Option<Duration> {
+        *self.dns_refresh_interval.read()
+    }
+
+    // Sets how long a node's channel can go unused before it's closed.
+    pub(crate) fn set_channel_idle_timeout(&self, channel_idle_timeout: Option<Duration>) {
+        *self.channel_idle_timeout.write() = channel_idle_timeout;
+    }
+
+    // Returns how long a node's channel can go unused before it's closed.
+    #[must_use]
+    pub(crate) fn channel_idle_timeout(&self) -> Option<Duration> {
+        *self.channel_idle_timeout.read()
+    }
+
+    // Sets whether (and how) connections to nodes are secured with TLS.
+    pub(crate) fn set_transport_security(&self, transport_security: TransportSecurity) {
+        *self.transport_security.write() = transport_security;
+    }
+
+    // Returns whether (and how) connections to nodes are secured with TLS.
+    #[must_use]
+    pub(crate) fn transport_security(&self) -> TransportSecurity {
+        *self.transport_security.read()
+    }
+
+    // Drops the node's channel, so that the next request reconnects (resolving the node's addresses again).
+    pub(crate) fn reset_node_channel(&self, node_index: usize) {
+        self.connections[node_index].reset_channel();
+    }
+
     pub(crate) fn mark_node_unhealthy(&self, node_index: usize) {
         let now = Instant::now();
 
@@ -365,6 +445,21 @@ impl NetworkData {
         self.health[node_index].write().mark_healthy(Instant::now());
     }
 
+    // Records a BUSY response from the node, which makes it unhealthy once it's been busy `max_node_busy_responses` times in a row.
+    pub(crate) fn mark_node_busy(&self, node_index: usize) {
+        let now = Instant::now();
+        let backoff = *self.backoff.read();
+        let max_busy_responses = self.max_node_busy_responses();
+
+        let mut health = self.health[node_index].write();
+
+        let busy_responses = health.mark_busy(now);
+
+        if max_busy_responses.map_or(false, |it| busy_responses >= it.get()) {
+            health.mark_unhealthy(backoff, now);
+        }
+    }
+
     pub(crate) fn is_node_healthy(&self, node_index: usize, now: Instant) -> bool {
         // a healthy node has a healthiness before now.
 
@@ -382,14 +477,28 @@ impl NetworkData {
     pub(crate) fn healthy_node_ids(&self) -> impl Iterator<Item = AccountId> + '_ {
         self.healthy_node_indexes(Instant::now()).map(|it| self.node_ids[it])
     }
+
+    /// Returns the index of the node out of `indexes` whose backoff ends first.
+    ///
+    /// This is the fallback for when every node in `indexes` is unhealthy.
+    pub(crate) fn soonest_healthy_node_index(
+        &self,
+        indexes: impl IntoIterator<Item = usize>,
+    ) -> Option<usize> {
+        indexes.into_iter().min_by_key(|it| self.health[*it].read().healthy_at())
+    }
+
     pub(crate) fn random_node_ids(&self) -> Vec<AccountId> {
-        let mut node_ids: Vec<_> = self.healthy_node_ids().collect();
+        let node_ids: Vec<_> = self.healthy_node_ids().collect();
         // self.remove_dead_nodes();
 
         if node_ids.is_empty() {
-            log::warn!("No healthy nodes, randomly picking some unhealthy ones");
-            // hack, slowpath, don't care perf, fix this better later tho.
-            node_ids = self.node_ids.to_vec();
+            log::warn!("No healthy nodes, picking the one that will be healthy the soonest");
+
+            return self
+                .soonest_healthy_node_index(0..self.node_ids.len())
+                .map(|it| vec![self.node_ids[it]])
+                .unwrap_or_default();
         }
 
         let node_sample_amount = (node_ids.len() + 2) / 3;
@@ -403,7 +512,11 @@ impl NetworkData {
     pub(crate) fn channel(&self, index: usize) -> (AccountId, Channel) {
         let id = self.node_ids[index];
 
-        let channel = self.connections[index].channel();
+        let channel = self.connections[index].channel(
+            self.dns_refresh_interval(),
+            self.channel_idle_timeout(),
+            self.transport_security(),
+        );
 
         (id, channel)
     }
@@ -435,7 +548,9 @@ enum NodeHealth {
     Unhealthy { backoff: NodeBackoff, healthy_at: Instant, attempts: usize },
 
     /// When we last used the node the node acted as normal, so, we get to treat it as a healthy node for 15 minutes.
-    Healthy { used_at: Instant },
+    ///
+    /// `busy_responses` counts how many BUSY responses in a row the node has given.
+    Healthy { used_at: Instant, busy_responses: usize },
 }
 
 #[derive(Copy, Clone)]
@@ -463,7 +578,9 @@ impl NodeHealth {
         // For new Unhealthy nodes, apply config and start attempt count at 0
         let (node_backoff, attempts) = match self {
             Self::Unhealthy { backoff, healthy_at: _, attempts } => (*backoff, attempts),
-            _ => (backoff_config, &0),
+            _ => {
+                (NodeBackoff { current_interval: backoff_config.min_backoff, ..backoff_config }, &0)
+            }
         };
 
         (
@@ -496,7 +613,8 @@ impl NodeHealth {
 
         *self = Self::Unhealthy {
             backoff: NodeBackoff {
-                current_interval: next_backoff,
+                // `next_backoff` already grew the interval, keeping it is what makes repeated failures back off exponentially.
+                current_interval: backoff.current_interval,
                 max_backoff: backoff.max_interval,
                 min_backoff: backoff.initial_interval,
                 max_attempts: backoff_config.max_attempts,
@@ -507,7 +625,19 @@ impl NodeHealth {
     }
 
     pub(crate) fn mark_healthy(&mut self, now: Instant) {
-        *self = Self::Healthy { used_at: now };
+        *self = Self::Healthy { used_at: now, busy_responses: 0 };
+    }
+
+    /// Marks the node as having responded with BUSY, returning how many times in a row it has.
+    pub(crate) fn mark_busy(&mut self, now: Instant) -> usize {
+        let busy_responses = match self {
+            Self::Healthy { used_at: _, busy_responses } => *busy_responses + 1,
+            _ => 1,
+        };
+
+        *self = Self::Healthy { used_at: now, busy_responses };
+
+        busy_responses
     }
 
     pub(crate) fn is_healthy(&self, now: Instant) -> bool {
@@ -518,10 +648,20 @@ impl NodeHealth {
         }
     }
 
+    /// Returns when the node stops being in backoff, if it's unhealthy.
+    pub(crate) fn healthy_at(&self) -> Option<Instant> {
+        match self {
+            Self::Unhealthy { backoff: _, healthy_at, attempts: _ } => Some(*healthy_at),
+            _ => None,
+        }
+    }
+
     pub(crate) fn recently_pinged(&self, now: Instant) -> bool {
         match self {
             // when used at was less than 15 minutes ago we consider ourselves "pinged", otherwise we're basically `.unused`.
-            Self::Healthy { used_at } => now < *used_at + Duration::from_secs(15 * 60),
+            Self::Healthy { used_at, busy_responses: _ } => {
+                now < *used_at + Duration::from_secs(15 * 60)
+            }
             // likewise an unhealthy node (healthyAt > now) has been "pinged" (although we don't want to use it probably we at least *have* gotten *something* from it)
             Self::Unhealthy { backoff: _, healthy_at, attempts: _ } => now < *healthy_at,
 
@@ -541,6 +681,16 @@ impl HostAndPort {
     const fn from_static(host: &'static str) -> Self {
         Self { host: Cow::Borrowed(host), port: NodeConnection::PLAINTEXT_PORT }
     }
+
+    /// Returns the address that serves TLS, nodes serve it on the port after the plaintext port.
+    fn to_tls(&self) -> Self {
+        match self.port {
+            NodeConnection::PLAINTEXT_PORT => {
+                Self { host: self.host.clone(), port: NodeConnection::TLS_PORT }
+            }
+            port => Self { host: self.host.clone(), port },
+        }
+    }
 }
 
 impl FromStr for HostAndPort {
@@ -568,39 +718,520 @@ impl From<Ipv4Addr> for HostAndPort {
     }
 }
 
+struct CachedChannel {
+    channel: Channel,
+    created_at: Instant,
+    last_used_at: Instant,
+    transport_security: TransportSecurity,
+}
+
 #[derive(Clone)]
 struct NodeConnection {
-    addresses: BTreeSet<HostAndPort>,
-    channel: OnceCell<Channel>,
+    // tried in order when connecting if `failover` is set, otherwise load balanced.
+    addresses: Vec<HostAndPort>,
+    // connect to the first address that accepts the connection rather than balancing over all of them.
+    failover: bool,
+    // the expected hash of the node's TLS certificate, as lowercase hex.
+    cert_hash: Option<Arc<str>>,
+    // shared between clones, a clone has the exact same addresses.
+    channel: Arc<Mutex<Option<CachedChannel>>>,
 }
 
 impl NodeConnection {
     const PLAINTEXT_PORT: u16 = 50211;
+    const TLS_PORT: u16 = 50212;
+    const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn new(addresses: Vec<HostAndPort>) -> Self {
+        Self { addresses, failover: false, cert_hash: None, channel: Arc::default() }
+    }
+
+    fn with_failover(self) -> Self {
+        Self { failover: true, ..self }
+    }
+
+    fn with_cert_hash(self, cert_hash: Option<Arc<str>>) -> Self {
+        Self { cert_hash, ..self }
+    }
 
     fn new_static(addresses: &[&'static str]) -> NodeConnection {
-        Self {
-            addresses: addresses.iter().copied().map(HostAndPort::from_static).collect(),
-            channel: OnceCell::default(),
+        Self::new(addresses.iter().copied().map(HostAndPort::from_static).collect())
+    }
+
+    pub(crate) fn channel(
+        &self,
+        dns_refresh_interval: Option<Duration>,
+        idle_timeout: Option<Duration>,
+        transport_security: TransportSecurity,
+    ) -> Channel {
+        let now = Instant::now();
+
+        // host names are kept as-is, the connector looks them up whenever it (re)connects.
+        let channel = self.channel_with_resolver(
+            now,
+            dns_refresh_interval,
+            idle_timeout,
+            transport_security,
+            |it| vec![it.to_string()],
+        );
+
+        if let Some(idle_timeout) = idle_timeout {
+            if self.channel.lock().as_ref().is_some_and(|it| it.created_at == now) {
+                self.spawn_idle_close(now, idle_timeout);
+            }
         }
+
+        channel
     }
 
-    pub(crate) fn channel(&self) -> Channel {
-        let channel = self
-            .channel
-            .get_or_init(|| {
-                let addresses = self.addresses.iter().map(|it| {
-                    Endpoint::from_shared(format!("tcp://{it}"))
-                        .unwrap()
-                        .keep_alive_timeout(Duration::from_secs(10))
-                        .keep_alive_while_idle(true)
-                        .tcp_keepalive(Some(Duration::from_secs(10)))
-                        .connect_timeout(Duration::from_secs(10))
+    /// Closes the channel created at `created_at` once it goes unused for `idle_timeout`,
+    /// rather than leaving it open until the node's next request notices.
+    ///
+    /// Outside of a tokio runtime this does nothing, the channel is still replaced on the node's next request.
+    fn spawn_idle_close(&self, created_at: Instant, idle_timeout: Duration) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        let cached = self.channel.clone();
+
+        runtime.spawn(async move {
+            loop {
+                let deadline = match &*cached.lock() {
+                    Some(it) if it.created_at == created_at => it.last_used_at + idle_timeout,
+                    // the channel was already replaced or reset, a replacement has its own task.
+                    _ => return,
+                };
+
+                tokio::time::sleep_until(deadline.into()).await;
+
+                let mut cached = cached.lock();
+
+                let idle = cached.as_ref().is_some_and(|it| {
+                    it.created_at == created_at && Instant::now() >= it.last_used_at + idle_timeout
                 });
 
-                Channel::balance_list(addresses)
-            })
-            .clone();
+                if idle {
+                    // in-flight requests keep their clone of the channel, it closes once they're done.
+                    *cached = None;
+                    return;
+                }
+            }
+        });
+    }
+
+    /// Returns the cached channel, or builds a new one with the addresses given by `resolve`
+    /// if there isn't one, it's older than `dns_refresh_interval`, it went unused for `idle_timeout`,
+    /// or `transport_security` changed.
+    ///
+    /// The cached channel is shared by every request to the node, replacing it drops (and closes) the old one
+    /// once in-flight requests are done with it.
+    fn channel_with_resolver<F>(
+        &self,
+        now: Instant,
+        dns_refresh_interval: Option<Duration>,
+        idle_timeout: Option<Duration>,
+        transport_security: TransportSecurity,
+        resolve: F,
+    ) -> Channel
+    where
+        F: Fn(&HostAndPort) -> Vec<String>,
+    {
+        let mut cached = self.channel.lock();
+
+        if let Some(cached) = &mut *cached {
+            let fresh = dns_refresh_interval.map_or(true, |it| now < cached.created_at + it);
+            let idle = idle_timeout.map_or(false, |it| now >= cached.last_used_at + it);
+
+            if fresh && !idle && cached.transport_security == transport_security {
+                cached.last_used_at = now;
+                return cached.channel.clone();
+            }
+        }
+
+        let endpoint = |address: &str| {
+            Endpoint::from_shared(format!("tcp://{address}"))
+                .unwrap()
+                .keep_alive_timeout(Duration::from_secs(10))
+                .keep_alive_while_idle(true)
+                .tcp_keepalive(Some(Duration::from_secs(10)))
+        };
+
+        let channel = match transport_security.enabled {
+            true => {
+                let addresses: Vec<_> = self
+                    .addresses
+                    .iter()
+                    .map(HostAndPort::to_tls)
+                    .flat_map(|it| resolve(&it))
+                    .collect();
+
+                // the connector picks the address to use, this is only used as the `:authority` of requests.
+                let authority =
+                    addresses.first().cloned().unwrap_or_else(|| "localhost".to_owned());
+
+                endpoint(&authority)
+                    .connect_timeout(Self::CONNECT_TIMEOUT)
+                    .connect_with_connector_lazy(TlsConnector::new(
+                        addresses,
+                        self.cert_hash.clone(),
+                        transport_security.verify_certificates,
+                    ))
+            }
+
+            false if self.failover => {
+                let addresses: Vec<_> = self.addresses.iter().flat_map(resolve).collect();
+
+                let authority =
+                    addresses.first().cloned().unwrap_or_else(|| "localhost".to_owned());
+
+                // the connector times out each address by itself,
+                // a timeout on the endpoint would cover all of them and never get past a non-responding address.
+                endpoint(&authority).connect_with_connector_lazy(TcpConnector::new(
+                    addresses,
+                    Self::CONNECT_TIMEOUT,
+                ))
+            }
+
+            false => Channel::balance_list(
+                self.addresses
+                    .iter()
+                    .flat_map(resolve)
+                    .map(|it| endpoint(&it).connect_timeout(Self::CONNECT_TIMEOUT)),
+            ),
+        };
+
+        *cached = Some(CachedChannel {
+            channel: channel.clone(),
+            created_at: now,
+            last_used_at: now,
+            transport_security,
+        });
 
         channel
     }
+
+    fn reset_channel(&self) {
+        *self.channel.lock() = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{
+        Ipv4Addr,
+        SocketAddrV4,
+    };
+    use std::num::NonZeroUsize;
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+    use std::time::{
+        Duration,
+        Instant,
+    };
+
+    use hedera_proto::services;
+    use hedera_proto::services::crypto_service_client::CryptoServiceClient;
+    use parking_lot::Mutex;
+    use tonic::transport::Channel;
+
+    use super::{
+        CustomNetwork,
+        HostAndPort,
+        NetworkData,
+        NodeConnection,
+        TransportSecurity,
+    };
+    use crate::mock::MockCryptoService;
+    use crate::{
+        AccountId,
+        NodeAddress,
+        NodeAddressBook,
+    };
+
+    async fn get_balance(channel: Channel) {
+        CryptoServiceClient::new(channel)
+            .crypto_get_balance(services::Query::default())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn reconnect_resolves_again() {
+        let first = MockCryptoService::new(tonic::Code::Ok);
+        let second = MockCryptoService::new(tonic::Code::Ok);
+
+        let resolved = Mutex::new(first.clone().serve().await);
+        let second_address = second.clone().serve().await;
+
+        let connection = NodeConnection::new_static(&["0.testnet.hedera.com"]);
+        let resolutions = AtomicUsize::new(0);
+
+        let resolve = |it: &HostAndPort| {
+            assert_eq!(it.host, "0.testnet.hedera.com");
+            resolutions.fetch_add(1, Ordering::Relaxed);
+            vec![resolved.lock().clone()]
+        };
+
+        let now = Instant::now();
+        let security = TransportSecurity::default();
+
+        get_balance(connection.channel_with_resolver(now, None, None, security, resolve)).await;
+        get_balance(connection.channel_with_resolver(now, None, None, security, resolve)).await;
+
+        assert_eq!(resolutions.load(Ordering::Relaxed), 1);
+        assert_eq!(first.requests.load(Ordering::Relaxed), 2);
+
+        // the node moved, the cached channel keeps using the old address until it's reset.
+        *resolved.lock() = second_address;
+
+        get_balance(connection.channel_with_resolver(now, None, None, security, resolve)).await;
+        assert_eq!(first.requests.load(Ordering::Relaxed), 3);
+
+        connection.reset_channel();
+
+        get_balance(connection.channel_with_resolver(now, None, None, security, resolve)).await;
+
+        assert_eq!(resolutions.load(Ordering::Relaxed), 2);
+        assert_eq!(first.requests.load(Ordering::Relaxed), 3);
+        assert_eq!(second.requests.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn refresh_interval_resolves_again() {
+        let connection = NodeConnection::new_static(&["0.testnet.hedera.com"]);
+        let resolutions = AtomicUsize::new(0);
+
+        let resolve = |it: &HostAndPort| {
+            resolutions.fetch_add(1, Ordering::Relaxed);
+            vec![format!("127.0.0.1:{}", it.port)]
+        };
+
+        let interval = Some(Duration::from_secs(60));
+        let now = Instant::now();
+
+        connection.channel_with_resolver(
+            now,
+            interval,
+            None,
+            TransportSecurity::default(),
+            resolve,
+        );
+        connection.channel_with_resolver(
+            now + Duration::from_secs(30),
+            interval,
+            None,
+            TransportSecurity::default(),
+            resolve,
+        );
+        assert_eq!(resolutions.load(Ordering::Relaxed), 1);
+
+        connection.channel_with_resolver(
+            now + Duration::from_secs(60),
+            interval,
+            None,
+            TransportSecurity::default(),
+            resolve,
+        );
+        assert_eq!(resolutions.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn idle_channel_is_replaced() {
+        let connection = NodeConnection::new_static(&["0.testnet.hedera.com"]);
+        let resolutions = AtomicUsize::new(0);
+
+        let resolve = |it: &HostAndPort| {
+            resolutions.fetch_add(1, Ordering::Relaxed);
+            vec![format!("127.0.0.1:{}", it.port)]
+        };
+
+        let idle_timeout = Some(Duration::from_secs(60));
+        let security = TransportSecurity::default();
+        let now = Instant::now();
+
+        // using the channel keeps it from going idle.
+        for secs in [0, 30, 60, 89] {
+            let now = now + Duration::from_secs(secs);
+            connection.channel_with_resolver(now, None, idle_timeout, security, resolve);
+        }
+        assert_eq!(resolutions.load(Ordering::Relaxed), 1);
+
+        let now = now + Duration::from_secs(89 + 60);
+        connection.channel_with_resolver(now, None, idle_timeout, security, resolve);
+        assert_eq!(resolutions.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn idle_channel_is_closed() {
+        let connection = NodeConnection::new_static(&["127.0.0.1"]);
+
+        connection.channel(None, Some(Duration::from_millis(50)), TransportSecurity::default());
+        assert!(connection.channel.lock().is_some());
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert!(connection.channel.lock().is_none());
+    }
+
+    #[tokio::test]
+    async fn transport_security_uses_tls_port() {
+        let cert_hash = "ab".repeat(48);
+
+        let address_book = NodeAddressBook {
+            node_addresses: vec![NodeAddress {
+                node_id: 0,
+                rsa_public_key: Vec::new(),
+                node_account_id: AccountId::new(0, 0, 3),
+                tls_certificate_hash: format!("0x{cert_hash}").into_bytes(),
+                service_endpoints: vec![
+                    SocketAddrV4::new(Ipv4Addr::LOCALHOST, 50211),
+                    SocketAddrV4::new(Ipv4Addr::LOCALHOST, 50212),
+                ],
+                description: String::new(),
+            }],
+        };
+
+        let network = NetworkData::with_address_book(&NetworkData::default(), &address_book);
+        let connection = &network.connections[0];
+
+        assert_eq!(connection.cert_hash.as_deref(), Some(cert_hash.as_str()));
+
+        let ports = Mutex::new(Vec::new());
+
+        let resolve = |it: &HostAndPort| {
+            ports.lock().push(it.port);
+            vec![it.to_string()]
+        };
+
+        let now = Instant::now();
+        let tls = TransportSecurity { enabled: true, verify_certificates: true };
+
+        // plaintext is the default.
+        connection.channel_with_resolver(now, None, None, TransportSecurity::default(), resolve);
+        assert_eq!(*ports.lock(), [50211]);
+
+        // changing the transport security reconnects to the TLS endpoint.
+        connection.channel_with_resolver(now, None, None, tls, resolve);
+        connection.channel_with_resolver(now, None, None, tls, resolve);
+        assert_eq!(*ports.lock(), [50211, 50212]);
+
+        // and the setting survives network updates.
+        network.set_transport_security(tls);
+        let updated = NetworkData::with_address_book(&network, &address_book);
+        assert_eq!(updated.transport_security(), tls);
+    }
+
+    #[test]
+    fn unhealthy_node_is_avoided_until_backoff_elapses() {
+        let network = NetworkData::from_addresses(
+            &[
+                ("127.0.0.1:50211".to_owned(), AccountId::new(0, 0, 3)),
+                ("127.0.0.1:50212".to_owned(), AccountId::new(0, 0, 4)),
+            ]
+            .into(),
+        )
+        .unwrap();
+
+        network.set_min_backoff(Duration::from_millis(100));
+        network.set_max_backoff(Duration::from_secs(1));
+
+        let node_3 = network.node_indexes_for_ids(&[AccountId::new(0, 0, 3)]).unwrap()[0];
+        let node_4 = network.node_indexes_for_ids(&[AccountId::new(0, 0, 4)]).unwrap()[0];
+
+        for _ in 0..5 {
+            network.mark_node_unhealthy(node_4);
+        }
+
+        // the 5th failure backs off for `100ms * 1.5^4` (+/- 50%).
+        let healthy_at = network.health[node_4].read().healthy_at().unwrap();
+        assert!(healthy_at >= Instant::now() + Duration::from_millis(200));
+
+        for _ in 0..10 {
+            assert_eq!(network.random_node_ids(), [AccountId::new(0, 0, 3)]);
+        }
+
+        assert!(!network.is_node_healthy(node_4, Instant::now()));
+        assert!(network.is_node_healthy(node_4, healthy_at + Duration::from_millis(1)));
+
+        // when every node is backing off, the one that'll stop backing off first is used.
+        network.mark_node_unhealthy(node_3);
+
+        assert_eq!(network.random_node_ids(), [AccountId::new(0, 0, 3)]);
+
+        // and a success resets the backoff.
+        network.mark_node_healthy(node_4);
+
+        assert!(network.is_node_healthy(node_4, Instant::now()));
+        assert_eq!(network.health[node_4].read().healthy_at(), None);
+    }
+
+    #[test]
+    fn busy_node_is_unhealthy_after_max_node_busy_responses() {
+        let network = NetworkData::from_addresses(
+            &[("127.0.0.1:50211".to_owned(), AccountId::new(0, 0, 3))].into(),
+        )
+        .unwrap();
+
+        let node_3 = network.node_indexes_for_ids(&[AccountId::new(0, 0, 3)]).unwrap()[0];
+
+        // by default a node can be busy indefinitely, regardless of `max_node_attempts`.
+        assert_eq!(network.max_node_busy_responses(), None);
+        network.set_max_node_attempts(NonZeroUsize::new(2));
+
+        for _ in 0..20 {
+            network.mark_node_busy(node_3);
+        }
+
+        assert!(network.is_node_healthy(node_3, Instant::now()));
+
+        network.set_max_node_busy_responses(NonZeroUsize::new(2));
+        network.mark_node_healthy(node_3);
+
+        network.mark_node_busy(node_3);
+        assert!(network.is_node_healthy(node_3, Instant::now()));
+
+        // a non-busy response breaks the streak.
+        network.mark_node_healthy(node_3);
+        network.mark_node_busy(node_3);
+        assert!(network.is_node_healthy(node_3, Instant::now()));
+
+        network.mark_node_busy(node_3);
+        assert!(!network.is_node_healthy(node_3, Instant::now()));
+
+        // with no limit a node can be busy indefinitely.
+        network.set_max_node_busy_responses(None);
+        network.mark_node_healthy(node_3);
+
+        for _ in 0..20 {
+            network.mark_node_busy(node_3);
+        }
+
+        assert!(network.is_node_healthy(node_3, Instant::now()));
+    }
+
+    #[test]
+    fn only_custom_networks_fail_over() {
+        let addresses = [
+            ("127.0.0.1:50211".to_owned(), AccountId::new(0, 0, 3)),
+            ("127.0.0.2:50211".to_owned(), AccountId::new(0, 0, 3)),
+        ];
+
+        let network = NetworkData::from_addresses(&addresses.into()).unwrap();
+
+        assert!(!network.connections[0].failover);
+
+        let mut custom = CustomNetwork::new();
+        custom.node(AccountId::new(0, 0, 3), ["127.0.0.1:50211", "127.0.0.2:50211"]);
+
+        let network = NetworkData::from_custom(&custom).unwrap();
+
+        assert!(network.connections[0].failover);
+        assert_eq!(
+            network.connections[0].addresses.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["127.0.0.1:50211", "127.0.0.2:50211"]
+        );
+    }
 }
@@ -0,0 +1,120 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Plaintext connections to consensus nodes.
+
+use std::io;
+use std::task::{
+    Context,
+    Poll,
+};
+use std::time::Duration;
+
+use futures_core::future::BoxFuture;
+use tokio::net::TcpStream;
+use tonic::codegen::http::Uri;
+use tonic::codegen::Service;
+use triomphe::Arc;
+
+/// Connects to the first reachable address of a node, trying them in order.
+///
+/// The `Uri` given by `tonic` is ignored, since a node can have several addresses.
+#[derive(Clone)]
+pub(super) struct TcpConnector {
+    addresses: Arc<[String]>,
+    // per address, so that an address that doesn't respond doesn't use up the time for the others.
+    connect_timeout: Duration,
+}
+
+impl TcpConnector {
+    pub(super) fn new(addresses: Vec<String>, connect_timeout: Duration) -> Self {
+        Self { addresses: addresses.into(), connect_timeout }
+    }
+
+    async fn connect(self) -> io::Result<TcpStream> {
+        let mut last_error = None;
+
+        for address in self.addresses.iter() {
+            let stream = match tokio::time::timeout(
+                self.connect_timeout,
+                TcpStream::connect(address.as_str()),
+            )
+            .await
+            {
+                Ok(it) => it,
+                Err(_) => Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("timed out connecting to `{address}`"),
+                )),
+            };
+
+            match stream {
+                Ok(stream) => {
+                    stream.set_nodelay(true)?;
+                    return Ok(stream);
+                }
+                Err(error) => last_error = Some(error),
+            }
+        }
+
+        Err(last_error
+            .unwrap_or_else(|| io::Error::new(io::ErrorKind::NotFound, "node has no addresses")))
+    }
+}
+
+impl Service<Uri> for TcpConnector {
+    type Response = TcpStream;
+    type Error = io::Error;
+    type Future = BoxFuture<'static, io::Result<Self::Response>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        Box::pin(self.clone().connect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::TcpConnector;
+
+    #[tokio::test]
+    async fn skips_non_responding_address() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        // TEST-NET-1 is reserved and never routed, so connecting to it doesn't get a response.
+        let connector = TcpConnector::new(
+            vec!["192.0.2.1:50211".to_owned(), address.to_string()],
+            Duration::from_millis(100),
+        );
+
+        let stream = tokio::time::timeout(Duration::from_secs(5), connector.connect())
+            .await
+            .expect("connector should give up on the first address")
+            .unwrap();
+
+        assert_eq!(stream.peer_addr().unwrap(), address);
+    }
+}
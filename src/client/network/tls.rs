@@ -0,0 +1,370 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Transport security (TLS) for connections to consensus nodes.
+//!
+//! Nodes present self-signed certificates, so instead of checking them against a set of roots,
+//! they're checked against the certificate hash in the node's address book entry.
+
+use std::io;
+use std::task::{
+    Context,
+    Poll,
+};
+
+use futures_core::future::BoxFuture;
+use sha2::{
+    Digest,
+    Sha384,
+};
+use tokio::net::TcpStream;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::rustls::client::danger::{
+    HandshakeSignatureValid,
+    ServerCertVerified,
+    ServerCertVerifier,
+};
+use tokio_rustls::rustls::crypto::{
+    self,
+    WebPkiSupportedAlgorithms,
+};
+use tokio_rustls::rustls::pki_types::{
+    CertificateDer,
+    ServerName,
+    UnixTime,
+};
+use tokio_rustls::rustls::{
+    self,
+    CertificateError,
+    ClientConfig,
+    DigitallySignedStruct,
+    OtherError,
+    SignatureScheme,
+};
+use tonic::codegen::http::Uri;
+use tonic::codegen::Service;
+use triomphe::Arc;
+
+/// Why a node's certificate was rejected.
+#[derive(Clone, Debug, thiserror::Error)]
+pub(crate) enum CertificateVerifyError {
+    #[error(
+        "node certificate hash `{actual}` doesn't match the address book's certificate hash `{expected}`"
+    )]
+    Mismatch { expected: String, actual: String },
+
+    #[error("certificate verification is enabled, but the node has no certificate hash in its address book entry")]
+    MissingHash,
+}
+
+/// Transport security settings for connections to consensus nodes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) struct TransportSecurity {
+    pub(crate) enabled: bool,
+    pub(crate) verify_certificates: bool,
+}
+
+impl Default for TransportSecurity {
+    fn default() -> Self {
+        Self { enabled: false, verify_certificates: true }
+    }
+}
+
+/// Normalizes an address book certificate hash into lowercase hex.
+///
+/// The hash is usually the hex string itself (as UTF-8), but the raw 48 byte hash is accepted too.
+pub(super) fn normalize_certificate_hash(hash: &[u8]) -> Option<String> {
+    match hash.len() {
+        0 => None,
+        48 => Some(hex::encode(hash)),
+        _ => {
+            let hash = String::from_utf8_lossy(hash);
+            Some(hash.trim().trim_start_matches("0x").to_ascii_lowercase())
+        }
+    }
+}
+
+/// The SHA-384 hash of `certificate` in PEM format, as lowercase hex.
+fn certificate_hash(certificate: &[u8]) -> String {
+    let pem = crate::key::encode_pem("CERTIFICATE", certificate.to_vec());
+
+    hex::encode(Sha384::digest(pem.as_bytes()))
+}
+
+// rustls takes its config, verifier and errors as `std::sync::Arc`s, everything else uses `triomphe::Arc`.
+#[allow(clippy::disallowed_types)]
+type StdArc<T> = std::sync::Arc<T>;
+
+#[derive(Debug)]
+struct CertificateHashVerifier {
+    expected_hash: Option<Arc<str>>,
+    verify_certificates: bool,
+    algorithms: WebPkiSupportedAlgorithms,
+}
+
+impl CertificateHashVerifier {
+    fn verify(&self, certificate: &[u8]) -> Result<(), CertificateVerifyError> {
+        if !self.verify_certificates {
+            return Ok(());
+        }
+
+        let expected = self.expected_hash.as_deref().ok_or(CertificateVerifyError::MissingHash)?;
+
+        let actual = certificate_hash(certificate);
+
+        match actual == expected {
+            true => Ok(()),
+            false => {
+                Err(CertificateVerifyError::Mismatch { expected: expected.to_owned(), actual })
+            }
+        }
+    }
+}
+
+impl ServerCertVerifier for CertificateHashVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        self.verify(end_entity).map(|()| ServerCertVerified::assertion()).map_err(|error| {
+            rustls::Error::InvalidCertificate(CertificateError::Other(OtherError(StdArc::new(
+                error,
+            ))))
+        })
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        crypto::verify_tls12_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        crypto::verify_tls13_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.algorithms.supported_schemes()
+    }
+}
+
+/// Connects to the first reachable address of a node over TLS, checking its certificate hash.
+///
+/// The `Uri` given by `tonic` is ignored, since a node can have several addresses.
+#[derive(Clone)]
+pub(super) struct TlsConnector {
+    addresses: Arc<[String]>,
+    config: StdArc<ClientConfig>,
+}
+
+impl TlsConnector {
+    pub(super) fn new(
+        addresses: Vec<String>,
+        expected_hash: Option<Arc<str>>,
+        verify_certificates: bool,
+    ) -> Self {
+        let verifier = CertificateHashVerifier {
+            expected_hash,
+            verify_certificates,
+            algorithms: crypto::ring::default_provider().signature_verification_algorithms,
+        };
+
+        let mut config = ClientConfig::builder()
+            .dangerous()
+            .with_custom_certificate_verifier(StdArc::new(verifier))
+            .with_no_client_auth();
+
+        config.alpn_protocols = vec![b"h2".to_vec()];
+
+        Self { addresses: addresses.into(), config: StdArc::new(config) }
+    }
+
+    async fn connect(self) -> io::Result<TlsStream<TcpStream>> {
+        let mut last_error = None;
+
+        for address in self.addresses.iter() {
+            match self.connect_to(address).await {
+                Ok(stream) => return Ok(stream),
+                // the node is reachable but isn't who we expected, trying other addresses won't help.
+                Err(error) if certificate_verify_error(&error).is_some() => return Err(error),
+                Err(error) => last_error = Some(error),
+            }
+        }
+
+        Err(last_error
+            .unwrap_or_else(|| io::Error::new(io::ErrorKind::NotFound, "node has no addresses")))
+    }
+
+    async fn connect_to(&self, address: &str) -> io::Result<TlsStream<TcpStream>> {
+        let host = address.rsplit_once(':').map_or(address, |(host, _)| host);
+
+        let server_name = ServerName::try_from(host)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?
+            .to_owned();
+
+        let stream = TcpStream::connect(address).await?;
+        stream.set_nodelay(true)?;
+
+        tokio_rustls::TlsConnector::from(self.config.clone())
+            .connect(server_name, stream)
+            .await
+            .map_err(|error| match certificate_verify_error(&error) {
+                // unwrap the error so that it can be found again in whatever ends up wrapping it.
+                Some(verify_error) => io::Error::new(io::ErrorKind::InvalidData, verify_error),
+                None => error,
+            })
+    }
+}
+
+impl Service<Uri> for TlsConnector {
+    type Response = TlsStream<TcpStream>;
+    type Error = io::Error;
+    type Future = BoxFuture<'static, io::Result<Self::Response>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        Box::pin(self.clone().connect())
+    }
+}
+
+/// Finds the [`CertificateVerifyError`] in `error`, if it's caused by one.
+fn certificate_verify_error(error: &io::Error) -> Option<CertificateVerifyError> {
+    let inner = error.get_ref()?;
+
+    if let Some(error) = inner.downcast_ref::<CertificateVerifyError>() {
+        return Some(error.clone());
+    }
+
+    match inner.downcast_ref::<rustls::Error>()? {
+        rustls::Error::InvalidCertificate(CertificateError::Other(OtherError(error))) => {
+            error.downcast_ref::<CertificateVerifyError>().cloned()
+        }
+        _ => None,
+    }
+}
+
+/// Finds the [`CertificateVerifyError`] that caused `status`, if any.
+pub(crate) fn status_certificate_verify_error(
+    status: &tonic::Status,
+) -> Option<CertificateVerifyError> {
+    let mut source = std::error::Error::source(status);
+
+    while let Some(error) = source {
+        if let Some(error) = error.downcast_ref::<CertificateVerifyError>() {
+            return Some(error.clone());
+        }
+
+        // `io::Error` skips over its inner error in `source`.
+        if let Some(error) = error.downcast_ref::<io::Error>().and_then(certificate_verify_error) {
+            return Some(error);
+        }
+
+        source = error.source();
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use triomphe::Arc;
+
+    use super::{
+        certificate_hash,
+        normalize_certificate_hash,
+        status_certificate_verify_error,
+        CertificateHashVerifier,
+        CertificateVerifyError,
+    };
+
+    fn verifier(expected_hash: Option<&str>, verify_certificates: bool) -> CertificateHashVerifier {
+        CertificateHashVerifier {
+            expected_hash: expected_hash.map(Arc::from),
+            verify_certificates,
+            algorithms: tokio_rustls::rustls::crypto::ring::default_provider()
+                .signature_verification_algorithms,
+        }
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_certificate_hash(b""), None);
+        assert_eq!(normalize_certificate_hash(b"0xABCD"), Some("abcd".to_owned()));
+        assert_eq!(normalize_certificate_hash(&[0xab; 48]), Some("ab".repeat(48)));
+    }
+
+    #[test]
+    fn verify_certificate_hash() {
+        let certificate = b"not really a certificate";
+        let hash = certificate_hash(certificate);
+
+        assert_eq!(hash.len(), 96);
+
+        verifier(Some(&hash), true).verify(certificate).unwrap();
+
+        let err = verifier(Some(&"00".repeat(48)), true).verify(certificate).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "node certificate hash `{hash}` doesn't match the address book's certificate hash `{}`",
+                "00".repeat(48)
+            )
+        );
+
+        assert!(matches!(
+            verifier(None, true).verify(certificate),
+            Err(CertificateVerifyError::MissingHash)
+        ));
+
+        // without verification anything goes.
+        verifier(None, false).verify(certificate).unwrap();
+        verifier(Some(&"00".repeat(48)), false).verify(certificate).unwrap();
+    }
+
+    #[test]
+    fn find_error_in_status() {
+        let error = io::Error::new(io::ErrorKind::InvalidData, CertificateVerifyError::MissingHash);
+        let status = tonic::Status::from_error(Box::new(error));
+
+        assert!(matches!(
+            status_certificate_verify_error(&status),
+            Some(CertificateVerifyError::MissingHash)
+        ));
+
+        assert!(status_certificate_verify_error(&tonic::Status::unavailable("foo")).is_none());
+    }
+}
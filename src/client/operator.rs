@@ -1,7 +1,7 @@
+use super::TransactionIdGenerator;
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
-    PublicKey,
     TransactionId,
 };
 
@@ -9,16 +9,12 @@ use crate::{
 pub(crate) struct Operator {
     pub(crate) account_id: AccountId,
     pub(crate) signer: AnySigner,
+    pub(crate) transaction_id_generator: Option<TransactionIdGenerator>,
 }
 
 impl Operator {
-    #[must_use]
-    pub(crate) fn sign(&self, body_bytes: &[u8]) -> (PublicKey, Vec<u8>) {
-        self.signer.sign(body_bytes)
-    }
-
     #[must_use]
     pub(crate) fn generate_transaction_id(&self) -> TransactionId {
-        TransactionId::generate(self.account_id)
+        TransactionId::generate_with(self.account_id, self.transaction_id_generator.as_ref())
     }
 }
@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use std::time::Duration;
+
+use assert_matches::assert_matches;
+use hedera_proto::{
+    sdk,
+    services,
+};
+use prost::Message;
+use triomphe::Arc;
+
+use crate::mock::MockCryptoService;
+use crate::{
+    AccountBalanceQuery,
+    AccountId,
+    Client,
+    CustomNetwork,
+    Error,
+    FromProtobuf,
+    Hbar,
+    LedgerId,
+    PrivateKey,
+    Status,
+    TransactionId,
+    TransferTransaction,
+};
+
+#[tokio::test]
+async fn ping_all_reports_unhealthy_nodes() {
+    let healthy = MockCryptoService::new(tonic::Code::Ok).serve().await;
+    let unavailable = MockCryptoService::new(tonic::Code::Unavailable).serve().await;
+
+    let client =
+        Client::for_network(HashMap::from([(healthy, 3.into()), (unavailable, 4.into())])).unwrap();
+    client.set_max_attempts(1);
+
+    client.ping(3.into()).await.unwrap();
+
+    let failures = assert_matches!(
+        client.ping_all().await,
+        Err(Error::PingAllFailed { failures }) => failures
+    );
+
+    assert_eq!(failures.len(), 1);
+
+    let (node_account_id, error) = &failures[0];
+
+    assert_eq!(*node_account_id, AccountId::new(0, 0, 4));
+    assert_matches!(
+        error,
+        Error::MaxAttemptsExceeded { attempts: 1, source, .. } => assert_matches!(&**source, Error::GrpcStatus(it) if it.code() == tonic::Code::Unavailable)
+    );
+
+    assert_eq!(Error::PingAllFailed { failures }.to_string(), "failed to ping nodes `0.0.4`");
+}
+
+#[tokio::test]
+async fn max_attempts_exceeded_keeps_the_last_error() {
+    use std::error::Error as _;
+
+    let unavailable = MockCryptoService::new(tonic::Code::Unavailable).serve().await;
+
+    let client = Client::for_network(HashMap::from([(unavailable, 3.into())])).unwrap();
+    client.set_max_attempts(2);
+    client.set_min_backoff(Duration::from_millis(10));
+
+    let error = AccountBalanceQuery::new()
+        .account_id(AccountId::from(1001))
+        .node_account_ids([3.into()])
+        .execute(&client)
+        .await
+        .unwrap_err();
+
+    // the node said it's unavailable, so the request never reached it.
+    assert_matches!(error, Error::MaxAttemptsExceeded { attempts: 2, submitted: false, .. });
+
+    // the chain leads to the status the node responded with.
+    let source = error.source().unwrap();
+    assert_matches!(source.downcast_ref::<Error>(), Some(Error::GrpcStatus(_)));
+
+    let status = source.source().unwrap().downcast_ref::<tonic::Status>().unwrap();
+    assert_eq!(status.code(), tonic::Code::Unavailable);
+}
+
+#[tokio::test]
+async fn max_attempts_exceeded_after_submission() {
+    let exhausted = MockCryptoService::new(tonic::Code::ResourceExhausted).serve().await;
+
+    let client = Client::for_network(HashMap::from([(exhausted, 3.into())])).unwrap();
+    client.set_max_attempts(1);
+
+    let error = AccountBalanceQuery::new()
+        .account_id(AccountId::from(1001))
+        .node_account_ids([3.into()])
+        .execute(&client)
+        .await
+        .unwrap_err();
+
+    // only `UNAVAILABLE` proves the request never reached the node.
+    assert_matches!(error, Error::MaxAttemptsExceeded { attempts: 1, submitted: true, .. });
+}
+
+#[tokio::test]
+async fn requests_to_a_node_share_its_channel() {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let address = MockCryptoService::new(tonic::Code::Ok).serve_counting(connections.clone()).await;
+
+    let client = Client::for_network(HashMap::from([(address, 3.into())])).unwrap();
+    client.set_max_attempts(1);
+
+    client.ping(3.into()).await.unwrap();
+    client.ping(3.into()).await.unwrap();
+
+    assert_eq!(connections.load(Ordering::Relaxed), 1);
+
+    client.set_channel_idle_timeout(Duration::from_millis(50));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    client.ping(3.into()).await.unwrap();
+
+    assert_eq!(connections.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn query_rotates_to_next_node_on_unavailable() {
+    // both nodes share one request count, so whichever node is tried first is the unavailable one.
+    let service = MockCryptoService::new(tonic::Code::Ok).unavailable_for(1);
+    let requests = service.requests.clone();
+
+    let first = service.clone().serve().await;
+    let second = service.serve().await;
+
+    let client =
+        Client::for_network(HashMap::from([(first, 3.into()), (second, 4.into())])).unwrap();
+    client.set_max_attempts(1);
+    client.set_min_node_backoff(Duration::from_secs(60));
+
+    let mut query = AccountBalanceQuery::new();
+    query.account_id(AccountId::from(1001)).node_account_ids([3.into(), 4.into()]);
+
+    // a single attempt succeeds, so the query moved on to the other node.
+    assert_eq!(query.execute(&client).await.unwrap().hbars, Hbar::from_tinybars(10));
+    assert_eq!(requests.load(Ordering::Relaxed), 2);
+
+    // the unavailable node is unhealthy now, so only the other node is asked.
+    assert_eq!(query.execute(&client).await.unwrap().hbars, Hbar::from_tinybars(10));
+    assert_eq!(requests.load(Ordering::Relaxed), 3);
+}
+
+#[tokio::test]
+async fn busy_node_is_rotated_away_from_after_max_node_busy_responses() {
+    let busy = MockCryptoService::busy();
+    let busy_requests = busy.requests.clone();
+
+    let busy = busy.serve().await;
+    let healthy = MockCryptoService::new(tonic::Code::Ok).serve().await;
+
+    let client =
+        Client::for_network(HashMap::from([(busy, 3.into()), (healthy, 4.into())])).unwrap();
+    client.set_max_attempts(1);
+    client.set_max_node_busy_responses(2);
+    client.set_min_node_backoff(Duration::from_secs(60));
+
+    // pinned to the busy node, every request gets `BUSY`.
+    for _ in 0..2 {
+        let error = AccountBalanceQuery::new()
+            .account_id(AccountId::from(1001))
+            .node_account_ids([3.into()])
+            .execute(&client)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.status(), Some(Status::Busy));
+    }
+
+    assert_eq!(busy_requests.load(Ordering::Relaxed), 2);
+
+    // after two `BUSY` responses in a row the busy node is skipped in favor of the healthy one.
+    for _ in 0..16 {
+        let balance = AccountBalanceQuery::new()
+            .account_id(AccountId::from(1001))
+            .node_account_ids([3.into(), 4.into()])
+            .execute(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(balance.hbars, Hbar::from_tinybars(10));
+    }
+
+    assert_eq!(busy_requests.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn custom_network_fails_over_to_next_endpoint() {
+    // nothing listens on a port that was just released, so connecting to it is refused.
+    let refused = || async {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        listener.local_addr().unwrap().to_string()
+    };
+
+    let mut network = CustomNetwork::new();
+    network
+        .node(3.into(), [refused().await, MockCryptoService::new(tonic::Code::Ok).serve().await])
+        .node(4.into(), [refused().await, MockCryptoService::new(tonic::Code::Ok).serve().await])
+        .mirror_network(["127.0.0.1:5600"])
+        .ledger_id(LedgerId::testnet());
+
+    let client = Client::for_custom_network(network).unwrap();
+    client.set_max_attempts(1);
+
+    assert_eq!(client.ledger_id(), Some(LedgerId::testnet()));
+    assert_eq!(client.mirror_network(), ["127.0.0.1:5600"]);
+    assert_eq!(client.network().len(), 4);
+
+    client.ping(3.into()).await.unwrap();
+    client.ping(4.into()).await.unwrap();
+
+    let mut network = CustomNetwork::new();
+    network.node(3.into(), Vec::<String>::new());
+
+    assert_matches!(Client::for_custom_network(network), Err(Error::BasicParse(_)));
+}
+
+#[tokio::test]
+async fn generate_transaction_id() {
+    let client = Client::for_testnet();
+
+    assert_matches!(client.generate_transaction_id(), Err(Error::NoPayerAccountOrTransactionId));
+
+    client.set_operator(AccountId::new(0, 0, 1001), PrivateKey::generate_ed25519());
+
+    let before = time::OffsetDateTime::now_utc();
+    let first = client.generate_transaction_id().unwrap();
+    let second = client.generate_transaction_id().unwrap();
+    let after = time::OffsetDateTime::now_utc();
+
+    assert_ne!(first, second);
+
+    for id in [first, second] {
+        assert_eq!(id.account_id, AccountId::new(0, 0, 1001));
+        assert!(id.valid_start >= before - time::Duration::seconds(8));
+        assert!(id.valid_start <= after - time::Duration::seconds(5));
+    }
+}
+
+#[test]
+fn frozen_transaction_captures_operator() -> crate::Result<()> {
+    let client = Client::for_testnet();
+    assert_eq!(client.operator_account_id(), None);
+
+    let key = PrivateKey::generate_ed25519();
+    client.set_operator(AccountId::new(0, 0, 1001), key.clone());
+
+    assert_eq!(client.operator_account_id(), Some(AccountId::new(0, 0, 1001)));
+    assert_eq!(client.operator_public_key(), Some(key.public_key()));
+
+    let mut tx = TransferTransaction::new();
+    tx.hbar_transfer(1001.into(), Hbar::new(-1))
+        .hbar_transfer(3.into(), Hbar::new(1))
+        .node_account_ids([3.into()])
+        .freeze_with(&client)?;
+
+    // replacing the operator afterwards doesn't affect the already frozen transaction.
+    client.set_operator(AccountId::new(0, 0, 1002), PrivateKey::generate_ed25519());
+    assert_eq!(client.operator_account_id(), Some(AccountId::new(0, 0, 1002)));
+
+    let list = sdk::TransactionList::decode(&*tx.to_bytes()?).unwrap();
+    let signed =
+        services::SignedTransaction::decode(&*list.transaction_list[0].signed_transaction_bytes)
+            .unwrap();
+    let body = services::TransactionBody::decode(&*signed.body_bytes).unwrap();
+
+    let transaction_id = TransactionId::from_protobuf(body.transaction_id.unwrap())?;
+
+    assert_eq!(transaction_id.account_id, AccountId::new(0, 0, 1001));
+
+    Ok(())
+}
+
+#[test]
+fn checksums_on_custom_network_need_ledger_id() -> crate::Result<()> {
+    let client =
+        Client::for_network(HashMap::from([("127.0.0.1:50211".to_owned(), 3.into())])).unwrap();
+    client.set_auto_validate_checksums(true);
+
+    assert_eq!(client.ledger_id(), None);
+
+    let transfer = || {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer("0.0.123-esxsf".parse().unwrap(), Hbar::new(-1))
+            .hbar_transfer(3.into(), Hbar::new(1))
+            .node_account_ids([3.into()])
+            .transaction_id(TransactionId::generate(AccountId::new(0, 0, 1001)));
+        tx
+    };
+
+    assert_matches!(
+        transfer().freeze_with(&client),
+        Err(Error::CannotValidateChecksumWithoutLedgerId)
+    );
+
+    // the checksum above is for testnet, so a private network running with its ledger ID validates.
+    client.set_ledger_id(Some(LedgerId::testnet()));
+    assert_eq!(client.ledger_id(), Some(LedgerId::testnet()));
+
+    transfer().freeze_with(&client)?;
+
+    Ok(())
+}
+
+#[test]
+fn freeze_rejects_bad_checksum() -> crate::Result<()> {
+    let client = Client::for_mainnet();
+
+    let transfer = || {
+        let mut tx = TransferTransaction::new();
+        // a testnet checksum, which is wrong for mainnet.
+        tx.hbar_transfer("0.0.123-esxsf".parse().unwrap(), Hbar::new(-1))
+            .hbar_transfer(3.into(), Hbar::new(1))
+            .node_account_ids([3.into()])
+            .transaction_id(TransactionId::generate(AccountId::new(0, 0, 1001)));
+        tx
+    };
+
+    // checksums are only checked when asked to.
+    assert!(!client.auto_validate_checksums());
+    transfer().freeze_with(&client)?;
+
+    client.set_auto_validate_checksums(true);
+    assert!(client.auto_validate_checksums());
+
+    let (present_checksum, expected_checksum) = assert_matches!(
+        transfer().freeze_with(&client),
+        Err(Error::BadEntityId { shard: 0, realm: 0, num: 123, present_checksum, expected_checksum }) =>
+            (present_checksum, expected_checksum)
+    );
+
+    assert_eq!(present_checksum.to_string(), "esxsf");
+    assert_ne!(expected_checksum, present_checksum);
+
+    Ok(())
+}
+
+#[test]
+fn for_name() {
+    let node_ids = |client: &Client| {
+        let mut ids: Vec<_> = client.network().into_values().map(|it| it.num).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    };
+
+    let client = Client::for_name("mainnet").unwrap();
+    assert_eq!(client.ledger_id(), Some(LedgerId::mainnet()));
+    assert!(node_ids(&client).contains(&3));
+
+    let client = Client::for_name("testnet").unwrap();
+    assert_eq!(client.ledger_id(), Some(LedgerId::testnet()));
+    assert_eq!(node_ids(&client), [3, 4, 5, 6, 7, 8, 9]);
+
+    let client = Client::for_name("previewnet").unwrap();
+    assert_eq!(client.ledger_id(), Some(LedgerId::previewnet()));
+    assert!(node_ids(&client).contains(&3));
+
+    let client = Client::for_name("localhost").unwrap();
+    assert_eq!(client.ledger_id(), None);
+    assert_eq!(
+        client.network(),
+        HashMap::from([("127.0.0.1:50211".to_owned(), AccountId::new(0, 0, 3))])
+    );
+    assert_eq!(client.mirror_network(), ["127.0.0.1:5600"]);
+
+    assert_matches!(Client::for_name("devnet"), Err(Error::BasicParse(_)));
+}
+
+#[cfg(feature = "mirror-rest")]
+#[test]
+fn mirror_rest_base_url() {
+    let rest_base_url = |client: Client| client.mirrornet().load().rest_base_url();
+
+    assert_eq!(
+        rest_base_url(Client::for_mainnet()).as_deref(),
+        Some("https://mainnet-public.mirrornode.hedera.com/api/v1")
+    );
+
+    // a local node serves the REST API on its own port, not on the gRPC port.
+    assert_eq!(
+        rest_base_url(Client::for_name("localhost").unwrap()).as_deref(),
+        Some("http://127.0.0.1:5551/api/v1")
+    );
+
+    assert_eq!(
+        rest_base_url(Client::for_mirror_network(["127.0.0.1:8080".to_owned()])).as_deref(),
+        Some("http://127.0.0.1:8080/api/v1")
+    );
+}
+
+#[test]
+fn network_presets() {
+    for (client, ledger_id, mirror) in [
+        (Client::for_mainnet(), LedgerId::mainnet(), "mainnet-public.mirrornode.hedera.com:443"),
+        (Client::for_testnet(), LedgerId::testnet(), "testnet.mirrornode.hedera.com:443"),
+        (Client::for_previewnet(), LedgerId::previewnet(), "previewnet.mirrornode.hedera.com:443"),
+    ] {
+        assert!(!client.network().is_empty());
+        assert_eq!(client.ledger_id(), Some(ledger_id));
+        assert_eq!(client.mirror_network(), [mirror]);
+    }
+}
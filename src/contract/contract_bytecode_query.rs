@@ -55,8 +55,8 @@ impl ContractBytecodeQuery {
     }
 
     /// Sets the contract for which information is requested.
-    pub fn contract_id(&mut self, contract_id: ContractId) -> &mut Self {
-        self.data.contract_id = Some(contract_id);
+    pub fn contract_id(&mut self, contract_id: impl Into<ContractId>) -> &mut Self {
+        self.data.contract_id = Some(contract_id.into());
         self
     }
 }
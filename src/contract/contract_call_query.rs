@@ -62,6 +62,9 @@ pub struct ContractCallQueryData {
 
     /// The sender for this transaction.
     sender_account_id: Option<AccountId>,
+
+    /// The maximum number of bytes that the result can be.
+    max_result_size: u64,
 }
 
 impl ContractCallQuery {
@@ -72,8 +75,8 @@ impl ContractCallQuery {
     }
 
     /// Sets the contract to make a static call against.
-    pub fn contract_id(&mut self, contract_id: ContractId) -> &mut Self {
-        self.data.contract_id = Some(contract_id);
+    pub fn contract_id(&mut self, contract_id: impl Into<ContractId>) -> &mut Self {
+        self.data.contract_id = Some(contract_id.into());
         self
     }
 
@@ -122,8 +125,22 @@ impl ContractCallQuery {
     }
 
     /// Sets the sender for this transaction.
-    pub fn sender_account_id(&mut self, sender_account_id: AccountId) -> &mut Self {
-        self.data.sender_account_id = Some(sender_account_id);
+    pub fn sender_account_id(&mut self, sender_account_id: impl Into<AccountId>) -> &mut Self {
+        self.data.sender_account_id = Some(sender_account_id.into());
+        self
+    }
+
+    /// Gets the maximum number of bytes that the result can be.
+    #[must_use]
+    pub fn get_max_result_size(&self) -> u64 {
+        self.data.max_result_size
+    }
+
+    /// Sets the maximum number of bytes that the result can be.
+    ///
+    /// Note that current versions of the network ignore this.
+    pub fn max_result_size(&mut self, max_result_size: u64) -> &mut Self {
+        self.data.max_result_size = max_result_size;
         self
     }
 }
@@ -147,7 +164,7 @@ impl ToQueryProtobuf for ContractCallQueryData {
                     contract_id,
                     gas: self.gas as i64,
                     function_parameters: self.function_parameters.clone(),
-                    max_result_size: 0,
+                    max_result_size: self.max_result_size as i64,
                     header: Some(header),
                     sender_id,
                 },
@@ -179,6 +196,7 @@ impl ValidateChecksums for ContractCallQueryData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
 
@@ -197,7 +215,7 @@ mod tests {
         query
             .contract_id(crate::ContractId::new(0, 0, 5005))
             .gas(1541)
-            .sender_account_id("1.2.3".parse().unwrap())
+            .sender_account_id("1.2.3".parse::<AccountId>().unwrap())
             .max_payment_amount(Hbar::from_tinybars(100_000));
 
         query
@@ -735,4 +753,20 @@ mod tests {
 
         assert_eq!(query.get_sender_account_id(), Some(AccountId::new(1, 2, 3)));
     }
+
+    #[test]
+    #[allow(deprecated)]
+    fn get_set_max_result_size() {
+        let mut query = ContractCallQuery::new();
+        query.max_result_size(1024);
+
+        assert_eq!(query.get_max_result_size(), 1024);
+
+        let pb = assert_matches!(
+            query.data.to_query_protobuf(services::QueryHeader::default()).query,
+            Some(services::query::Query::ContractCallLocal(it)) => it
+        );
+
+        assert_eq!(pb.max_result_size, 1024);
+    }
 }
@@ -166,6 +166,10 @@ impl ContractCreateFlow {
     }
 
     /// Sets the maximum number of tokens that the contract can be automatically associated with.
+    ///
+    /// `-1` means unlimited automatic token associations.
+    ///
+    /// Anything less than `-1` is rejected when the contract is created.
     pub fn max_automatic_token_associations(
         &mut self,
         max_automatic_token_associations: i32,
@@ -210,8 +214,11 @@ impl ContractCreateFlow {
     }
 
     /// Sets the account to be used at the contract's expiration time to extend the life of the contract.
-    pub fn auto_renew_account_id(&mut self, auto_renew_account_id: AccountId) -> &mut Self {
-        self.contract_data.auto_renew_account_id = Some(auto_renew_account_id);
+    pub fn auto_renew_account_id(
+        &mut self,
+        auto_renew_account_id: impl Into<AccountId>,
+    ) -> &mut Self {
+        self.contract_data.auto_renew_account_id = Some(auto_renew_account_id.into());
 
         self
     }
@@ -248,8 +255,8 @@ impl ContractCreateFlow {
     }
 
     /// Sets the ID of the account to which the contract is staking.
-    pub fn staked_account_id(&mut self, staked_account_id: AccountId) -> &mut Self {
-        self.contract_data.staked_id = Some(StakedId::AccountId(staked_account_id));
+    pub fn staked_account_id(&mut self, staked_account_id: impl Into<AccountId>) -> &mut Self {
+        self.contract_data.staked_id = Some(StakedId::AccountId(staked_account_id.into()));
 
         self
     }
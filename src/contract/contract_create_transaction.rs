@@ -23,12 +23,17 @@ use hedera_proto::services::smart_contract_service_client::SmartContractServiceC
 use time::Duration;
 use tonic::transport::Channel;
 
+use crate::account::{
+    validate_max_automatic_token_associations,
+    validate_transaction_max_automatic_token_associations,
+};
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::staked_id::StakedId;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -104,8 +109,10 @@ impl ContractCreateTransaction {
     }
 
     /// Sets the file to use as the bytes for the smart contract.
-    pub fn bytecode_file_id(&mut self, file_id: FileId) -> &mut Self {
-        self.data_mut().bytecode_file_id = Some(file_id);
+    ///
+    /// This is mutually exclusive with [`bytecode`](Self::bytecode), setting both fails to freeze.
+    pub fn bytecode_file_id(&mut self, file_id: impl Into<FileId>) -> &mut Self {
+        self.data_mut().bytecode_file_id = Some(file_id.into());
         self
     }
 
@@ -116,6 +123,8 @@ impl ContractCreateTransaction {
     }
 
     /// Sets the bytes of the smart contract.
+    ///
+    /// This is mutually exclusive with [`bytecode_file_id`](Self::bytecode_file_id), setting both fails to freeze.
     pub fn bytecode(&mut self, bytecode: impl AsRef<[u8]>) -> &mut Self {
         self.data_mut().bytecode = Some(bytecode.as_ref().to_vec());
         self
@@ -201,6 +210,10 @@ impl ContractCreateTransaction {
     }
 
     /// Sets the maximum number of tokens that this contract can be automatically associated with.
+    ///
+    /// `-1` means unlimited automatic token associations.
+    ///
+    /// Anything less than `-1` is rejected when the transaction is frozen.
     pub fn max_automatic_token_associations(&mut self, max: i32) -> &mut Self {
         self.data_mut().max_automatic_token_associations = max;
         self
@@ -215,8 +228,8 @@ impl ContractCreateTransaction {
 
     /// Sets the account to be used at the contract's expiration time to extend the
     /// life of the contract.
-    pub fn auto_renew_account_id(&mut self, account_id: AccountId) -> &mut Self {
-        self.data_mut().auto_renew_account_id = Some(account_id);
+    pub fn auto_renew_account_id(&mut self, account_id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().auto_renew_account_id = Some(account_id.into());
         self
     }
 
@@ -229,8 +242,8 @@ impl ContractCreateTransaction {
     /// Sets the ID of the account to which this contract is staking.
     ///
     /// This is mutually exclusive with [`staked_node_id`](Self::staked_node_id).
-    pub fn staked_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().staked_id = Some(id.into());
+    pub fn staked_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().staked_id = Some(StakedId::AccountId(id.into()));
         self
     }
 
@@ -264,6 +277,16 @@ impl TransactionData for ContractCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::new(20)
     }
+
+    fn validate(&self) -> crate::Result<()> {
+        if self.bytecode.is_some() && self.bytecode_file_id.is_some() {
+            return Err(Error::invalid_transaction(
+                "a contract's bytecode can be set from bytes or a file, not both",
+            ));
+        }
+
+        validate_transaction_max_automatic_token_associations(self.max_automatic_token_associations)
+    }
 }
 
 impl TransactionExecute for ContractCreateTransactionData {
@@ -274,6 +297,10 @@ impl TransactionExecute for ContractCreateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { SmartContractServiceClient::new(channel).create_contract(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Contract
+    }
 }
 
 impl ValidateChecksums for ContractCreateTransactionData {
@@ -289,7 +316,7 @@ impl ToTransactionDataProtobuf for ContractCreateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::ContractCreateInstance(self.to_protobuf())
     }
@@ -327,7 +354,9 @@ impl FromProtobuf<services::ContractCreateTransactionBody> for ContractCreateTra
             auto_renew_period: pb_getf!(pb, auto_renew_period)?.into(),
             constructor_parameters: pb.constructor_parameters,
             contract_memo: pb.memo,
-            max_automatic_token_associations: pb.max_automatic_token_associations,
+            max_automatic_token_associations: validate_max_automatic_token_associations(
+                pb.max_automatic_token_associations,
+            )?,
             auto_renew_account_id: Option::from_protobuf(pb.auto_renew_account_id)?,
             staked_id: Option::from_protobuf(pb.staked_id)?,
             decline_staking_reward: pb.decline_reward,
@@ -397,8 +426,10 @@ impl ToProtobuf for ContractCreateTransactionData {
 #[cfg(test)]
 mod tests {
 
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
+    use num_bigint::BigUint;
     use time::Duration;
 
     use crate::contract::ContractCreateTransactionData;
@@ -415,6 +446,8 @@ mod tests {
         AccountId,
         AnyTransaction,
         ContractCreateTransaction,
+        ContractFunctionParameters,
+        Error,
         FileId,
         Hbar,
         PublicKey,
@@ -765,6 +798,43 @@ mod tests {
         assert_eq!(tx.get_bytecode(), Some(BYTECODE.as_slice()));
     }
 
+    #[test]
+    fn freeze_rejects_bytecode_and_bytecode_file_id() {
+        let mut tx = ContractCreateTransaction::new_for_tests();
+        tx.bytecode_file_id(BYTECODE_FILE_ID).bytecode(BYTECODE).gas(GAS);
+
+        assert_matches!(tx.freeze(), Err(Error::InvalidTransaction(_)));
+    }
+
+    #[test]
+    fn serialize_uint256_constructor_parameter() {
+        let mut tx = ContractCreateTransaction::new_for_tests();
+
+        tx.bytecode_file_id(BYTECODE_FILE_ID)
+            .gas(GAS)
+            .constructor_parameters(
+                ContractFunctionParameters::new().add_uint256(BigUint::from(42_u8)).to_bytes(None),
+            )
+            .freeze()
+            .unwrap();
+
+        let tx = check_body(transaction_body(tx));
+
+        let tx =
+            assert_matches!(tx, services::transaction_body::Data::ContractCreateInstance(it) => it);
+
+        let mut expected = [0; 32];
+        expected[31] = 42;
+
+        assert_eq!(tx.constructor_parameters, expected);
+        assert_eq!(
+            tx.initcode_source,
+            Some(services::contract_create_transaction_body::InitcodeSource::FileId(
+                BYTECODE_FILE_ID.to_protobuf()
+            ))
+        );
+    }
+
     #[test]
     #[should_panic]
     fn get_set_bytecode_frozen_panics() {
@@ -30,6 +30,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -66,8 +67,8 @@ impl ContractDeleteTransaction {
     }
 
     /// Sets ID of the contract which should be deleted.
-    pub fn contract_id(&mut self, id: ContractId) -> &mut Self {
-        self.data_mut().contract_id = Some(id);
+    pub fn contract_id(&mut self, id: impl Into<ContractId>) -> &mut Self {
+        self.data_mut().contract_id = Some(id.into());
         self
     }
 
@@ -78,8 +79,8 @@ impl ContractDeleteTransaction {
     }
 
     /// Sets the ID of the account which will receive all remaining hbars.
-    pub fn transfer_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().transfer_account_id = Some(id);
+    pub fn transfer_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().transfer_account_id = Some(id.into());
         self
     }
 
@@ -90,8 +91,8 @@ impl ContractDeleteTransaction {
     }
 
     /// Sets the the ID of the contract which will receive all remaining hbars.
-    pub fn transfer_contract_id(&mut self, id: ContractId) -> &mut Self {
-        self.data_mut().transfer_contract_id = Some(id);
+    pub fn transfer_contract_id(&mut self, id: impl Into<ContractId>) -> &mut Self {
+        self.data_mut().transfer_contract_id = Some(id.into());
         self
     }
 }
@@ -106,6 +107,10 @@ impl TransactionExecute for ContractDeleteTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { SmartContractServiceClient::new(channel).delete_contract(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Contract
+    }
 }
 
 impl ValidateChecksums for ContractDeleteTransactionData {
@@ -121,7 +126,7 @@ impl ToTransactionDataProtobuf for ContractDeleteTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::ContractDeleteInstance(self.to_protobuf())
     }
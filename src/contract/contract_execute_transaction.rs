@@ -27,6 +27,7 @@ use crate::protobuf::FromProtobuf;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -68,6 +69,12 @@ pub struct ContractExecuteTransactionData {
 
     /// The function parameters as their raw bytes.
     function_parameters: Vec<u8>,
+
+    /// Whether the parameters were set as raw bytes.
+    raw_function_parameters_set: bool,
+
+    /// Whether the parameters were set from a function name and its parameters.
+    function_set: bool,
 }
 
 impl ContractExecuteTransaction {
@@ -78,8 +85,8 @@ impl ContractExecuteTransaction {
     }
 
     /// Sets the contract instance to call.
-    pub fn contract_id(&mut self, contract_id: ContractId) -> &mut Self {
-        self.data_mut().contract_id = Some(contract_id);
+    pub fn contract_id(&mut self, contract_id: impl Into<ContractId>) -> &mut Self {
+        self.data_mut().contract_id = Some(contract_id.into());
         self
     }
 
@@ -114,8 +121,13 @@ impl ContractExecuteTransaction {
     }
 
     /// Sets the function parameters as their raw bytes.
+    ///
+    /// This can't be combined with [`function`](Self::function) or
+    /// [`function_with_parameters`](Self::function_with_parameters), freezing fails if both are used.
     pub fn function_parameters(&mut self, data: Vec<u8>) -> &mut Self {
-        self.data_mut().function_parameters = data;
+        let data_mut = self.data_mut();
+        data_mut.function_parameters = data;
+        data_mut.raw_function_parameters_set = true;
         self
     }
 
@@ -130,11 +142,32 @@ impl ContractExecuteTransaction {
         name: &str,
         parameters: &ContractFunctionParameters,
     ) -> &mut Self {
-        self.function_parameters(parameters.to_bytes(Some(name)))
+        let data_mut = self.data_mut();
+        data_mut.function_parameters = parameters.to_bytes(Some(name));
+        data_mut.function_set = true;
+        self
     }
 }
 
-impl TransactionData for ContractExecuteTransactionData {}
+impl TransactionData for ContractExecuteTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        if self.contract_id.is_none() {
+            return Err(Error::FreezeUnsetField { field: "contract_id" });
+        }
+
+        if self.gas == 0 {
+            return Err(Error::invalid_transaction("a contract call needs a non-zero `gas`"));
+        }
+
+        if self.raw_function_parameters_set && self.function_set {
+            return Err(Error::invalid_transaction(
+                "a contract call's parameters can be set from raw bytes or a function, not both",
+            ));
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for ContractExecuteTransactionData {
     fn execute(
@@ -146,12 +179,15 @@ impl TransactionExecute for ContractExecuteTransactionData {
             SmartContractServiceClient::new(channel).contract_call_method(request).await
         })
     }
+
+    fn service(&self) -> Service {
+        Service::Contract
+    }
 }
 
 impl ValidateChecksums for ContractExecuteTransactionData {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
-        self.contract_id.validate_checksums(ledger_id)?;
-        Ok(())
+        self.contract_id.validate_checksums(ledger_id)
     }
 }
 
@@ -160,7 +196,7 @@ impl ToTransactionDataProtobuf for ContractExecuteTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::ContractCall(self.to_protobuf())
     }
@@ -190,6 +226,8 @@ impl FromProtobuf<services::ContractCallTransactionBody> for ContractExecuteTran
             gas: pb.gas as u64,
             payable_amount: Hbar::from_tinybars(pb.amount),
             function_parameters: pb.function_parameters,
+            raw_function_parameters_set: false,
+            function_set: false,
         })
     }
 }
@@ -210,10 +248,12 @@ impl ToProtobuf for ContractExecuteTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
 
     use crate::contract::ContractExecuteTransactionData;
+    use crate::ledger_id::RefLedgerId;
     use crate::protobuf::{
         FromProtobuf,
         ToProtobuf,
@@ -222,11 +262,13 @@ mod tests {
         check_body,
         transaction_body,
     };
+    use crate::transaction::ToSchedulableTransactionDataProtobuf;
     use crate::{
         AnyTransaction,
         ContractExecuteTransaction,
         ContractId,
         Hbar,
+        ValidateChecksums,
     };
 
     const CONTRACT_ID: ContractId = ContractId::new(0, 0, 5007);
@@ -315,6 +357,67 @@ mod tests {
         assert_eq!(tx.function_parameters, function_parameters());
     }
 
+    #[test]
+    fn to_schedulable() {
+        let mut tx = ContractExecuteTransaction::new();
+
+        tx.contract_id(CONTRACT_ID)
+            .gas(GAS)
+            .payable_amount(PAYABLE_AMOUNT)
+            .function_parameters(function_parameters());
+
+        assert_eq!(
+            tx.data().to_schedulable_transaction_data_protobuf(),
+            services::schedulable_transaction_body::Data::ContractCall(tx.data().to_protobuf())
+        );
+    }
+
+    #[test]
+    fn validate_checksums() {
+        let mut tx = ContractExecuteTransaction::new();
+        tx.contract_id("0.0.123-esxsf".parse::<ContractId>().unwrap());
+
+        tx.data().validate_checksums(RefLedgerId::TESTNET).unwrap();
+
+        assert_matches!(
+            tx.data().validate_checksums(RefLedgerId::MAINNET),
+            Err(crate::Error::BadEntityId { shard: 0, realm: 0, num: 123, .. })
+        );
+    }
+
+    #[test]
+    fn freeze_requires_contract_id() {
+        let mut tx = ContractExecuteTransaction::new_for_tests();
+        tx.gas(GAS);
+
+        assert_matches!(tx.freeze(), Err(crate::Error::FreezeUnsetField { field: "contract_id" }));
+    }
+
+    #[test]
+    fn freeze_requires_gas() {
+        let mut tx = ContractExecuteTransaction::new_for_tests();
+        tx.contract_id(CONTRACT_ID);
+
+        assert_matches!(tx.freeze(), Err(crate::Error::InvalidTransaction(_)));
+    }
+
+    #[test]
+    fn freeze_rejects_raw_and_function_parameters() {
+        let mut tx = ContractExecuteTransaction::new_for_tests();
+        tx.contract_id(CONTRACT_ID)
+            .gas(GAS)
+            .function_parameters(function_parameters())
+            .function("setMessage");
+
+        assert_matches!(tx.freeze(), Err(crate::Error::InvalidTransaction(_)));
+
+        // either one on its own is fine.
+        let mut tx = ContractExecuteTransaction::new_for_tests();
+        tx.contract_id(CONTRACT_ID).gas(GAS).function("setMessage");
+
+        tx.freeze().unwrap();
+    }
+
     #[test]
     fn get_set_contract_id() {
         let mut tx = ContractExecuteTransaction::new();
@@ -351,6 +454,14 @@ mod tests {
         assert_eq!(tx.get_payable_amount(), PAYABLE_AMOUNT);
     }
 
+    #[test]
+    fn payable_amount_to_protobuf() {
+        let mut tx = ContractExecuteTransaction::new();
+        tx.payable_amount(Hbar::new(1));
+
+        assert_eq!(tx.data().to_protobuf().amount, 100_000_000);
+    }
+
     #[test]
     #[should_panic]
     fn get_set_payable_amount_frozen_panics() {
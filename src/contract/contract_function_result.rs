@@ -72,6 +72,11 @@ pub struct ContractFunctionResult {
     /// Logs that this call and any called functions produced.
     pub logs: Vec<ContractLogInfo>,
 
+    /// The contracts created by this call.
+    ///
+    /// The network no longer populates this for new calls.
+    pub created_contract_ids: Vec<ContractId>,
+
     /// A list of updated contract account nonces containing the new nonce value for each contract account.
     /// This is always empty in a ContractLocalCallQuery response, since no internal creations can happen in a static EVM call.
     pub contract_nonces: Vec<ContractNonceInfo>,
@@ -99,13 +104,26 @@ impl ContractFunctionResult {
     // fixme(sr): name is weird, but I can't think of a better one.
     #[must_use]
     fn get_u32_at(&self, offset: usize) -> Option<u32> {
-        self.get_fixed_bytes_at(28 + offset).map(|it| u32::from_be_bytes(*it))
+        self.get_fixed_bytes_at(offset.checked_add(28)?).map(|it| u32::from_be_bytes(*it))
     }
 
+    /// Reads the whole word at `offset` as a `usize`, returning `None` if it doesn't fit in a `u32`.
     #[must_use]
-    fn offset_len_pair(&self, offset: usize) -> Option<(usize, usize)> {
-        let offset = self.get_u32(offset)? as usize;
-        let len = self.get_u32_at(offset)? as usize;
+    fn get_usize_at(&self, offset: usize) -> Option<usize> {
+        let word = self.get_fixed_bytes_at::<32>(offset)?;
+        let (high, low) = word.split_at(Self::SLOT_SIZE - 4);
+
+        if high.iter().any(|it| *it != 0) {
+            return None;
+        }
+
+        Some(u32::from_be_bytes(low.try_into().unwrap()) as usize)
+    }
+
+    #[must_use]
+    fn offset_len_pair(&self, index: usize) -> Option<(usize, usize)> {
+        let offset = self.get_usize_at(index.checked_mul(Self::SLOT_SIZE)?)?;
+        let len = self.get_usize_at(offset)?;
         Some((offset, len))
     }
 
@@ -129,16 +147,22 @@ impl ContractFunctionResult {
     #[must_use]
     pub fn get_str_array(&self, index: usize) -> Option<Vec<Cow<str>>> {
         let (offset, len) = self.offset_len_pair(index)?;
+        let offsets_start = offset.checked_add(Self::SLOT_SIZE)?;
+
+        // every string takes at least a slot for its offset, so a `len` beyond that is bogus anyway.
+        let max_len = self.bytes.len().saturating_sub(offsets_start) / Self::SLOT_SIZE;
 
-        let mut v = Vec::with_capacity(len);
+        let mut v = Vec::with_capacity(len.min(max_len));
         for i in 0..len {
-            let str_offset =
-                self.get_u32_at(offset + Self::SLOT_SIZE + (i * Self::SLOT_SIZE))? as usize;
-            let str_offset = offset + str_offset + Self::SLOT_SIZE;
+            let str_offset = offsets_start.checked_add(i.checked_mul(Self::SLOT_SIZE)?)?;
+            let str_offset = self.get_u32_at(str_offset)? as usize;
+            let str_offset = offsets_start.checked_add(str_offset)?;
             let len = self.get_u32_at(str_offset)? as usize;
 
-            let bytes =
-                self.bytes.get((str_offset + Self::SLOT_SIZE)..).and_then(|it| it.get(..len))?;
+            let bytes = self
+                .bytes
+                .get(str_offset.checked_add(Self::SLOT_SIZE)?..)
+                .and_then(|it| it.get(..len))?;
 
             v.push(String::from_utf8_lossy(bytes));
         }
@@ -146,11 +170,21 @@ impl ContractFunctionResult {
         Some(v)
     }
 
+    /// Get the value at `index` as a solidity `string`.
+    ///
+    /// Unlike [`get_str`](Self::get_str), this returns `None` if the string isn't valid utf8.
+    #[must_use]
+    pub fn get_string(&self, index: usize) -> Option<String> {
+        self.get_bytes(index).and_then(|it| str::from_utf8(it).ok()).map(ToOwned::to_owned)
+    }
+
     /// Get the value at `index` as solidity `bytes`.
+    ///
+    /// Returns `None` if the offset or length of the value point outside of the result.
     #[must_use]
     pub fn get_bytes(&self, index: usize) -> Option<&[u8]> {
         let (offset, len) = self.offset_len_pair(index)?;
-        self.bytes.get((offset + Self::SLOT_SIZE)..).and_then(|it| it.get(..len))
+        self.bytes.get(offset.checked_add(Self::SLOT_SIZE)?..).and_then(|it| it.get(..len))
     }
 
     /// Get the value at `index` as solidity `bytes32`.
@@ -258,6 +292,9 @@ impl FromProtobuf<services::ContractFunctionResult> for ContractFunctionResult {
 
         let signer_nonce = pb.signer_nonce.map(|it| it as u64);
 
+        #[allow(deprecated)]
+        let created_contract_ids = Vec::from_protobuf(pb.created_contract_i_ds)?;
+
         Ok(Self {
             contract_id,
             bytes,
@@ -270,6 +307,7 @@ impl FromProtobuf<services::ContractFunctionResult> for ContractFunctionResult {
             sender_account_id,
             evm_address,
             logs: Vec::from_protobuf(pb.log_info)?,
+            created_contract_ids,
             contract_nonces: Vec::from_protobuf(pb.contract_nonces)?,
             signer_nonce,
         })
@@ -300,9 +338,9 @@ impl ToProtobuf for ContractFunctionResult {
             contract_call_result: self.bytes.clone(),
             error_message: self.error_message.clone().unwrap_or_default(),
             bloom: self.bloom.clone(),
-            gas_used: self.gas,
+            gas_used: self.gas_used,
             log_info: self.logs.to_protobuf(),
-            created_contract_i_ds: Vec::new(),
+            created_contract_i_ds: self.created_contract_ids.to_protobuf(),
             evm_address: self.evm_address.and_then(|it| it.evm_address.map(|it| it.to_vec())),
             gas: self.gas as i64,
             amount: self.hbar_amount as i64,
@@ -358,6 +396,19 @@ mod tests {
         "72616E646F6D2062797465732032000000000000000000000000000000000000"
     );
 
+    const DYNAMIC_RESULT: [u8; 192] = hex!(
+        // offset of the `bytes` value
+        "0000000000000000000000000000000000000000000000000000000000000040"
+        // offset of the `string` value
+        "0000000000000000000000000000000000000000000000000000000000000080"
+        // length of the `bytes` value (7 bytes)
+        "0000000000000000000000000000000000000000000000000000000000000007"
+        "deadbeefc0ffee00000000000000000000000000000000000000000000000000"
+        // length of the `string` value (19 bytes, 14 characters)
+        "0000000000000000000000000000000000000000000000000000000000000013"
+        "68c3a96c6c6f2c2077c3b6726c6420f09f8c8d00000000000000000000000000"
+    );
+
     // previous one, just offset by a bit, to ensure the logic works.
     // notes below, where `slot` is just an offset at a multiple of 32 bytes.
     const STRING_ARRAY_RESULT_2: [u8; 320] = hex!(
@@ -384,6 +435,42 @@ mod tests {
         "72616E646F6D2062797465732032000000000000decaff000000000000000000"
     );
 
+    #[test]
+    fn dynamic_results() {
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            contract_call_result: DYNAMIC_RESULT.to_vec(),
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result).unwrap();
+
+        assert_eq!(result.get_bytes(0).unwrap(), hex!("deadbeefc0ffee"));
+        assert_eq!(result.get_string(1).unwrap(), "héllo, wörld 🌍");
+        assert_eq!(result.get_str(1).unwrap(), "héllo, wörld 🌍");
+    }
+
+    #[test]
+    fn dynamic_results_out_of_bounds() {
+        let mut bytes = DYNAMIC_RESULT.to_vec();
+        // make the length of the `bytes` value point past the end of the result.
+        bytes[95] = 0xff;
+        // and make the offset of the `string` value not fit in a `u32`.
+        bytes[32] = 0x01;
+
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            contract_call_result: bytes,
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result).unwrap();
+
+        assert_eq!(result.get_bytes(0), None);
+        assert_eq!(result.get_string(1), None);
+        assert_eq!(result.get_bytes(6), None);
+    }
+
     #[test]
     fn evm_address() {
         const EVM_ADDRESS: [u8; 20] = hex!("98329e006610472e6b372c080833f6d79ed833cf");
@@ -472,6 +559,56 @@ mod tests {
         )
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn from_call_local_response() {
+        let response =
+            services::response::Response::ContractCallLocal(services::ContractCallLocalResponse {
+                header: Some(services::ResponseHeader {
+                    node_transaction_precheck_code: services::ResponseCodeEnum::Ok as i32,
+                    response_type: services::ResponseType::AnswerOnly as i32,
+                    cost: 0,
+                    state_proof: Vec::new(),
+                }),
+                function_result: Some(services::ContractFunctionResult {
+                    contract_id: Some(ContractId::new(0, 0, 1001).to_protobuf()),
+                    contract_call_result: CALL_RESULT.to_vec(),
+                    error_message: String::new(),
+                    gas_used: 21_000,
+                    log_info: Vec::from([services::ContractLoginfo {
+                        contract_id: Some(ContractId::new(0, 0, 1001).to_protobuf()),
+                        bloom: b"bloom".to_vec(),
+                        topic: Vec::from([b"topic".to_vec()]),
+                        data: b"data".to_vec(),
+                    }]),
+                    created_contract_i_ds: Vec::from([ContractId::new(0, 0, 1002).to_protobuf()]),
+                    gas: 30_000,
+                    ..Default::default()
+                }),
+            });
+
+        let result = ContractFunctionResult::from_protobuf(response).unwrap();
+
+        assert_eq!(result.contract_id, ContractId::new(0, 0, 1001));
+        assert_eq!(result.as_bytes(), CALL_RESULT);
+        assert_eq!(result.error_message, None);
+        assert_eq!(result.gas_used, 21_000);
+        assert_eq!(result.gas, 30_000);
+        assert_eq!(result.created_contract_ids, [ContractId::new(0, 0, 1002)]);
+
+        assert_eq!(result.logs.len(), 1);
+        assert_eq!(result.logs[0].contract_id, ContractId::new(0, 0, 1001));
+        assert_eq!(result.logs[0].topics, [b"topic".to_vec()]);
+        assert_eq!(result.logs[0].data, b"data");
+
+        assert_eq!(result.get_str(4).unwrap(), "Hello, world!");
+
+        // `gas_used` and `gas` are separate fields.
+        let pb = result.to_protobuf();
+        assert_eq!(pb.gas_used, 21_000);
+        assert_eq!(pb.gas, 30_000);
+    }
+
     #[test]
     fn str_array_results() {
         let result = services::ContractFunctionResult {
@@ -487,6 +624,23 @@ mod tests {
         assert_eq!(strings[1], "random bytes 2")
     }
 
+    #[test]
+    fn str_array_results_out_of_bounds() {
+        let mut bytes = STRING_ARRAY_RESULT.to_vec();
+        // claim far more strings than there's room for.
+        bytes[60..64].copy_from_slice(&[0xff; 4]);
+
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            contract_call_result: bytes,
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result).unwrap();
+
+        assert_eq!(result.get_str_array(0), None);
+    }
+
     // previous one, just offset by a bit, to ensure the logic works.
     #[test]
     fn str_array_results2() {
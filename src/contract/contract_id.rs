@@ -135,11 +135,12 @@ impl ContractId {
     ///
     /// # Errors
     /// - [`Error::CannotCreateChecksum`] if self has an `evm_address`.
+    /// - [`Error::CannotCreateChecksumWithoutLedgerId`] if the client has no `ledger_id`.
     pub fn to_string_with_checksum(&self, client: &Client) -> Result<String, Error> {
         if self.evm_address.is_some() {
             Err(Error::CannotCreateChecksum)
         } else {
-            Ok(EntityId::to_string_with_checksum(self.to_string(), client))
+            EntityId::to_string_with_checksum(self.shard, self.realm, self.num, client)
         }
     }
 
@@ -147,6 +148,7 @@ impl ContractId {
     ///
     /// # Errors
     /// - [`Error::BadEntityId`] if there is a checksum, and the checksum is not valid for the client's `ledger_id`.
+    /// - [`Error::CannotValidateChecksumWithoutLedgerId`] if there is a checksum, and the client has no `ledger_id`.
     pub fn validate_checksum(&self, client: &Client) -> Result<(), Error> {
         if self.evm_address.is_some() {
             Ok(())
@@ -354,4 +356,9 @@ mod tests {
                 .unwrap(),
         )
     }
+
+    #[test]
+    fn from_u64() {
+        assert_eq!(ContractId::from(5005), ContractId::new(0, 0, 5005));
+    }
 }
@@ -18,12 +18,15 @@
  * ‍
  */
 
+use std::collections::HashMap;
+
 use hedera_proto::services;
 use time::{
     Duration,
     OffsetDateTime,
 };
 
+use crate::account::validate_max_automatic_token_associations;
 use crate::protobuf::ToProtobuf;
 use crate::{
     AccountId,
@@ -32,6 +35,8 @@ use crate::{
     Key,
     LedgerId,
     StakingInfo,
+    TokenId,
+    TokenRelationship,
 };
 
 /// Current information on a smart contract instance.
@@ -69,11 +74,16 @@ pub struct ContractInfo {
     /// Whether the contract has been deleted.
     pub is_deleted: bool,
 
+    /// The tokens associated to the contract, by token ID.
+    pub token_relationships: HashMap<TokenId, TokenRelationship>,
+
     /// ID of the an account to charge for auto-renewal of this contract.
     pub auto_renew_account_id: Option<AccountId>,
 
     /// The maximum number of tokens that a contract can be implicitly associated with.
-    pub max_automatic_token_associations: u32,
+    ///
+    /// `-1` means that there's no limit.
+    pub max_automatic_token_associations: i32,
 
     /// The ledger ID the response was returned from
     pub ledger_id: LedgerId,
@@ -125,6 +135,10 @@ impl FromProtobuf<services::contract_get_info_response::ContractInfo> for Contra
         let admin_key = Option::from_protobuf(pb.admin_key)?;
         let ledger_id = LedgerId::from_bytes(pb.ledger_id);
         let staking_info = Option::from_protobuf(pb.staking_info)?;
+        let token_relationships = Vec::<TokenRelationship>::from_protobuf(pb.token_relationships)?
+            .into_iter()
+            .map(|it| (it.token_id, it))
+            .collect();
 
         Ok(Self {
             contract_id: ContractId::from_protobuf(contract_id)?,
@@ -132,11 +146,14 @@ impl FromProtobuf<services::contract_get_info_response::ContractInfo> for Contra
             contract_account_id: pb.contract_account_id,
             is_deleted: pb.deleted,
             balance: pb.balance,
+            token_relationships,
             expiration_time,
             auto_renew_period,
             auto_renew_account_id,
             contract_memo: pb.memo,
-            max_automatic_token_associations: pb.max_automatic_token_associations as u32,
+            max_automatic_token_associations: validate_max_automatic_token_associations(
+                pb.max_automatic_token_associations,
+            )?,
             admin_key,
             storage: pb.storage as u64,
             ledger_id,
@@ -149,6 +166,11 @@ impl ToProtobuf for ContractInfo {
     type Protobuf = services::contract_get_info_response::ContractInfo;
 
     fn to_protobuf(&self) -> Self::Protobuf {
+        // sorted so that the same info always encodes to the same bytes, regardless of the map's order.
+        let mut token_relationships: Vec<_> = self.token_relationships.values().collect();
+        token_relationships
+            .sort_unstable_by_key(|it| (it.token_id.shard, it.token_id.realm, it.token_id.num));
+
         #[allow(deprecated)]
         services::contract_get_info_response::ContractInfo {
             contract_id: Some(self.contract_id.to_protobuf()),
@@ -163,11 +185,12 @@ impl ToProtobuf for ContractInfo {
             deleted: self.is_deleted,
             ledger_id: self.ledger_id.to_bytes(),
             auto_renew_account_id: self.auto_renew_account_id.to_protobuf(),
-            max_automatic_token_associations: self.max_automatic_token_associations as i32,
+            max_automatic_token_associations: self.max_automatic_token_associations,
             staking_info: self.staking_info.to_protobuf(),
-
-            // unimplemented fields
-            token_relationships: Vec::new(),
+            token_relationships: token_relationships
+                .into_iter()
+                .map(TokenRelationship::to_protobuf)
+                .collect(),
         }
     }
 }
@@ -183,8 +206,10 @@ mod tests {
         ToProtobuf,
     };
     use crate::{
+        ContractId,
         ContractInfo,
         LedgerId,
+        TokenId,
     };
 
     fn make_info() -> services::contract_get_info_response::ContractInfo {
@@ -232,6 +257,7 @@ mod tests {
                 contract_memo: "7",
                 balance: 8,
                 is_deleted: false,
+                token_relationships: {},
                 auto_renew_account_id: None,
                 max_automatic_token_associations: 0,
                 ledger_id: "testnet",
@@ -317,6 +343,7 @@ mod tests {
                 contract_memo: "7",
                 balance: 8,
                 is_deleted: false,
+                token_relationships: {},
                 auto_renew_account_id: None,
                 max_automatic_token_associations: 0,
                 ledger_id: "testnet",
@@ -325,4 +352,59 @@ mod tests {
         "#]]
         .assert_debug_eq(&ContractInfo::from_bytes(&make_info().encode_to_vec()).unwrap());
     }
+
+    #[test]
+    fn from_response() {
+        let mut info = make_info();
+        info.token_relationships = vec![services::TokenRelationship {
+            token_id: Some(TokenId::new(0, 0, 9).to_protobuf()),
+            symbol: "TOK".to_owned(),
+            balance: 10,
+            kyc_status: services::TokenKycStatus::Granted as i32,
+            freeze_status: services::TokenFreezeStatus::FreezeNotApplicable as i32,
+            decimals: 2,
+            automatic_association: true,
+        }];
+
+        let response =
+            services::response::Response::ContractGetInfo(services::ContractGetInfoResponse {
+                header: None,
+                contract_info: Some(info),
+            });
+
+        let info = ContractInfo::from_protobuf(response).unwrap();
+
+        assert_eq!(info.contract_id, ContractId::new(0, 0, 1));
+        assert_eq!(info.expiration_time.unwrap().unix_timestamp_nanos(), 4_000);
+        assert_eq!(info.auto_renew_period, Some(time::Duration::days(1)));
+        assert_eq!(info.ledger_id, LedgerId::testnet());
+
+        let relationship = &info.token_relationships[&TokenId::new(0, 0, 9)];
+
+        assert_eq!(relationship.symbol, "TOK");
+        assert_eq!(relationship.balance, 10);
+        assert_eq!(relationship.kyc_status, Some(true));
+        assert_eq!(relationship.freeze_status, None);
+        assert_eq!(relationship.decimals, 2);
+        assert!(relationship.automatic_association);
+    }
+
+    #[test]
+    fn to_bytes_token_relationships_sorted() {
+        let relationship = |num| services::TokenRelationship {
+            token_id: Some(TokenId::new(0, 0, num).to_protobuf()),
+            symbol: format!("TOK{num}"),
+            ..Default::default()
+        };
+
+        let mut info = make_info();
+        info.token_relationships = (1..=16).rev().map(relationship).collect();
+
+        let info = ContractInfo::from_bytes(&info.encode_to_vec()).unwrap();
+
+        let info =
+            services::contract_get_info_response::ContractInfo::decode(&*info.to_bytes()).unwrap();
+
+        assert_eq!(info.token_relationships, (1..=16).map(relationship).collect::<Vec<_>>());
+    }
 }
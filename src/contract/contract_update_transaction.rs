@@ -26,12 +26,17 @@ use time::{
 };
 use tonic::transport::Channel;
 
+use crate::account::{
+    validate_max_automatic_token_associations,
+    validate_transaction_max_automatic_token_associations,
+};
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::staked_id::StakedId;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -83,8 +88,8 @@ impl ContractUpdateTransaction {
     }
 
     /// Sets the contract to be updated.
-    pub fn contract_id(&mut self, contract_id: ContractId) -> &mut Self {
-        self.data_mut().contract_id = Some(contract_id);
+    pub fn contract_id(&mut self, contract_id: impl Into<ContractId>) -> &mut Self {
+        self.data_mut().contract_id = Some(contract_id.into());
         self
     }
 
@@ -143,6 +148,10 @@ impl ContractUpdateTransaction {
     }
 
     /// Sets the maximum number of tokens that this contract can be automatically associated with.
+    ///
+    /// `-1` means unlimited automatic token associations.
+    ///
+    /// Anything less than `-1` is rejected when the transaction is frozen.
     pub fn max_automatic_token_associations(&mut self, max: i32) -> &mut Self {
         self.data_mut().max_automatic_token_associations = Some(max);
         self
@@ -157,8 +166,8 @@ impl ContractUpdateTransaction {
 
     /// Sets the account to be used at the contract's expiration time to extend the
     /// life of the contract.
-    pub fn auto_renew_account_id(&mut self, account_id: AccountId) -> &mut Self {
-        self.data_mut().auto_renew_account_id = Some(account_id);
+    pub fn auto_renew_account_id(&mut self, account_id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().auto_renew_account_id = Some(account_id.into());
         self
     }
 
@@ -169,8 +178,8 @@ impl ContractUpdateTransaction {
     }
 
     /// Sets the ID of the account to which this contract is proxy staked.
-    pub fn proxy_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().proxy_account_id = Some(id);
+    pub fn proxy_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().proxy_account_id = Some(id.into());
         self
     }
 
@@ -182,8 +191,8 @@ impl ContractUpdateTransaction {
 
     /// Sets the ID of the account to which this contract is staking.
     /// This is mutually exclusive with `staked_node_id`.
-    pub fn staked_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().staked_id = Some(id.into());
+    pub fn staked_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().staked_id = Some(StakedId::AccountId(id.into()));
         self
     }
 
@@ -215,7 +224,15 @@ impl ContractUpdateTransaction {
     }
 }
 
-impl TransactionData for ContractUpdateTransactionData {}
+impl TransactionData for ContractUpdateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        if let Some(max) = self.max_automatic_token_associations {
+            validate_transaction_max_automatic_token_associations(max)?;
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for ContractUpdateTransactionData {
     fn execute(
@@ -225,6 +242,10 @@ impl TransactionExecute for ContractUpdateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { SmartContractServiceClient::new(channel).update_contract(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Contract
+    }
 }
 
 impl ValidateChecksums for ContractUpdateTransactionData {
@@ -241,7 +262,7 @@ impl ToTransactionDataProtobuf for ContractUpdateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::ContractUpdateInstance(self.to_protobuf())
     }
@@ -268,7 +289,10 @@ impl FromProtobuf<services::ContractUpdateTransactionBody> for ContractUpdateTra
             contract_memo: pb.memo_field.map(|it| match it {
                 MemoField::Memo(it) | MemoField::MemoWrapper(it) => it,
             }),
-            max_automatic_token_associations: pb.max_automatic_token_associations,
+            max_automatic_token_associations: pb
+                .max_automatic_token_associations
+                .map(validate_max_automatic_token_associations)
+                .transpose()?,
             auto_renew_account_id: Option::from_protobuf(pb.auto_renew_account_id)?,
             proxy_account_id: Option::from_protobuf(pb.proxy_account_id)?,
             staked_id: Option::from_protobuf(pb.staked_id)?,
@@ -235,7 +235,7 @@ impl EntityId {
     /// Validates that the the checksum computed for the given `shard.realm.num` matches the given checksum.
     ///
     /// # Errors
-    /// - [`Error::CannotPerformTaskWithoutLedgerId`] if the client has no `ledger_id`.
+    /// - [`Error::CannotValidateChecksumWithoutLedgerId`] if there is a checksum, and the client has no `ledger_id`.
     /// - [`Error::BadEntityId`] if there is a checksum, and the checksum is not valid for the client's `ledger_id`.
     pub(crate) fn validate_checksum(
         shard: u64,
@@ -249,9 +249,7 @@ impl EntityId {
         };
 
         let ledger_id = client.ledger_id_internal();
-        let ledger_id = ledger_id
-            .as_deref()
-            .expect("Client had no ledger ID (help: call `client.set_ledger_id()`");
+        let ledger_id = ledger_id.as_deref().ok_or(Error::CannotValidateChecksumWithoutLedgerId)?;
 
         Self::validate_checksum_internal(
             shard,
@@ -283,8 +281,7 @@ impl EntityId {
         present_checksum: Checksum,
         ledger_id: &RefLedgerId,
     ) -> Result<(), Error> {
-        let expected_checksum =
-            Self::generate_checksum(&format!("{shard}.{realm}.{num}"), ledger_id);
+        let expected_checksum = checksum(ledger_id, shard, realm, num);
         if present_checksum == expected_checksum {
             Ok(())
         } else {
@@ -292,20 +289,28 @@ impl EntityId {
         }
     }
 
-    pub(crate) fn to_string_with_checksum(mut entity_id_string: String, client: &Client) -> String {
+    pub(crate) fn to_string_with_checksum(
+        shard: u64,
+        realm: u64,
+        num: u64,
+        client: &Client,
+    ) -> crate::Result<String> {
         let ledger_id = client.ledger_id_internal();
-        let ledger_id = ledger_id
-            .as_ref()
-            .expect("Client had no ledger ID (help: call `client.set_ledger_id()`");
+        let ledger_id = ledger_id.as_ref().ok_or(Error::CannotCreateChecksumWithoutLedgerId)?;
 
-        let checksum = Self::generate_checksum(&entity_id_string, ledger_id.as_ref_ledger_id());
-        entity_id_string.push('-');
-        entity_id_string.push_str(&checksum.0);
+        let checksum = checksum(ledger_id.as_ref_ledger_id(), shard, realm, num);
 
-        entity_id_string
+        Ok(format!("{shard}.{realm}.{num}-{checksum}"))
     }
 }
 
+/// Computes the checksum of the entity ID `shard.realm.num` for `ledger_id`.
+///
+/// This is the weighted mod-p scheme shared by every Hedera SDK (see HIP-15).
+pub(crate) fn checksum(ledger_id: &RefLedgerId, shard: u64, realm: u64, num: u64) -> Checksum {
+    EntityId::generate_checksum(&format!("{shard}.{realm}.{num}"), ledger_id)
+}
+
 impl Debug for EntityId {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "\"{self}\"")
@@ -334,6 +339,7 @@ impl FromStr for EntityId {
 
 #[cfg(test)]
 mod tests {
+    use super::checksum;
     use crate::ledger_id::RefLedgerId;
     use crate::{
         EntityId,
@@ -453,4 +459,21 @@ mod tests {
             assert_eq!(expected, &actual);
         }
     }
+
+    #[test]
+    fn checksum_known_values() {
+        let cases = [
+            (RefLedgerId::MAINNET, (0, 0, 123), "vfmkw"),
+            (RefLedgerId::TESTNET, (0, 0, 123), "esxsf"),
+            (RefLedgerId::PREVIEWNET, (0, 0, 123), "ogizo"),
+            (RefLedgerId::MAINNET, (0, 0, 1001), "urkbk"),
+            (RefLedgerId::TESTNET, (0, 0, 1001), "eevit"),
+            (RefLedgerId::MAINNET, (1, 2, 3), "islfi"),
+            (RefLedgerId::TESTNET, (1, 2, 3), "sfwmr"),
+        ];
+
+        for (ledger_id, (shard, realm, num), expected) in cases {
+            assert_eq!(checksum(ledger_id, shard, realm, num).to_string(), expected);
+        }
+    }
 }
@@ -21,10 +21,14 @@
 use std::error::Error as StdError;
 use std::result::Result as StdResult;
 
+use time::OffsetDateTime;
+
 use crate::entity_id::Checksum;
 use crate::{
     AccountId,
     Hbar,
+    RequestType,
+    ScheduleId,
     Status,
     TransactionId,
 };
@@ -39,8 +43,35 @@ pub(crate) type BoxStdError = Box<dyn StdError + Send + Sync + 'static>;
 #[non_exhaustive]
 pub enum Error {
     /// Request timed out.
-    #[error("failed to complete request within the maximum time allowed; most recent attempt failed with: {0}")]
-    TimedOut(#[source] Box<Error>),
+    #[error("failed to complete request within the maximum time allowed; most recent attempt failed with: {source}")]
+    TimedOut {
+        /// Whether a request may have reached a node without the node rejecting it.
+        ///
+        /// When `true`, the transaction may still reach consensus,
+        /// so check its receipt before retrying with the same transaction ID.
+        /// When `false`, it's safe to retry with the same transaction ID.
+        submitted: bool,
+
+        /// The error from the most recent attempt.
+        #[source]
+        source: Box<Error>,
+    },
+
+    /// Every attempt allowed by the client's `max_attempts` failed.
+    #[error("failed to complete request within {attempts} attempts; most recent attempt failed with: {source}")]
+    MaxAttemptsExceeded {
+        /// The number of attempts made.
+        attempts: usize,
+
+        /// Whether a request may have reached a node without the node rejecting it.
+        ///
+        /// This means the same as `submitted` on [`Error::TimedOut`].
+        submitted: bool,
+
+        /// The error of the most recent attempt.
+        #[source]
+        source: Box<Error>,
+    },
 
     /// GRPC status code was an error.
     #[error("grpc: {0:?}")]
@@ -55,6 +86,13 @@ pub enum Error {
     #[error("freeze failed due to node account IDs being unset")]
     FreezeUnsetNodeAccountIds,
 
+    /// Freeze failed due to a field the transaction requires being unset.
+    #[error("freeze failed due to `{field}` being unset")]
+    FreezeUnsetField {
+        /// The name of the unset field.
+        field: &'static str,
+    },
+
     /// A transaction failed pre-check.
     ///
     /// The transaction had the ID `transaction_id`.
@@ -134,6 +172,16 @@ pub enum Error {
     #[error("an entity ID with an `alias` or `evm_address` cannot have a checksum")]
     CannotCreateChecksum,
 
+    /// A checksum cannot be validated, because the client has no ledger ID.
+    #[error(
+        "cannot validate a checksum without a ledger ID (help: call `client.set_ledger_id()`)"
+    )]
+    CannotValidateChecksumWithoutLedgerId,
+
+    /// A checksum cannot be created, because the client has no ledger ID.
+    #[error("cannot create a checksum without a ledger ID (help: call `client.set_ledger_id()`)")]
+    CannotCreateChecksumWithoutLedgerId,
+
     /// Failed to parse a [`PublicKey`](crate::PublicKey) or [`PrivateKey`](crate::PrivateKey).
     #[error("failed to parse a key: {0}")]
     KeyParse(#[source] BoxStdError),
@@ -183,6 +231,16 @@ pub enum Error {
         query_cost: Hbar,
     },
 
+    /// The contents of a chunked transaction need more chunks than the transaction is allowed to use.
+    #[error("message requires {used_chunks} chunks but max_chunks is {max_chunks}, try using a larger max_chunks")]
+    MaxChunksExceeded {
+        /// The number of chunks the contents would be split into.
+        used_chunks: usize,
+
+        /// The configured maximum number of chunks.
+        max_chunks: usize,
+    },
+
     /// The associated node account was not found in the network.
     #[error("node account `{0}` was not found in the configured network")]
     NodeAccountUnknown(Box<AccountId>),
@@ -190,6 +248,8 @@ pub enum Error {
     /// Received an unrecognized status code from the Hedera Network.
     ///
     /// This can happen when the SDK is outdated, try updating your SDK.
+    ///
+    /// Not returned anymore, unrecognized status codes are reported as [`Status::Unrecognized`].
     #[error("received unrecognized status code: {0}, try updating your SDK")]
     ResponseStatusUnrecognized(i32),
 
@@ -203,12 +263,105 @@ pub enum Error {
         transaction_id: Option<Box<TransactionId>>,
     },
 
+    /// A [`ScheduleSignTransaction`](crate::ScheduleSignTransaction) was for a schedule that already executed.
+    #[error("schedule `{schedule_id}` already executed")]
+    ScheduleAlreadyExecuted {
+        /// The ID of the schedule.
+        schedule_id: Box<ScheduleId>,
+
+        /// When the schedule executed, if it could be determined.
+        executed_at: Option<OffsetDateTime>,
+    },
+
+    /// A signer (such as the operator) signs asynchronously, which is only possible when executing a [`Transaction`](crate::Transaction).
+    ///
+    /// For example, [`Transaction::to_bytes`](crate::Transaction::to_bytes) and paid queries need to sign synchronously.
+    #[error("an async signer can only sign when executing a transaction")]
+    AsyncSignerRequiresExecute,
+
+    /// A signature was added to a transaction that isn't frozen.
+    #[error("a transaction must be frozen before adding a signature to it")]
+    AddSignatureNotFrozen,
+
+    /// A signature was added to a transaction that's made of more than one request (one per node, per chunk).
+    ///
+    /// Each request has different body bytes, so serialize the transaction per-node and sign each one separately.
+    #[error("cannot add a signature to a transaction with `{requests}` requests, serialize and sign each node's transaction separately")]
+    AddSignatureMultipleRequests {
+        /// The number of requests in the transaction.
+        requests: usize,
+    },
+
+    /// Pinging one or more nodes failed, see [`Client::ping_all`](crate::Client::ping_all).
+    #[error(
+        "failed to ping nodes {}",
+        .failures.iter().map(|(id, _)| format!("`{id}`")).collect::<Vec<_>>().join(", ")
+    )]
+    PingAllFailed {
+        /// The account ID of each node that failed, along with why it failed.
+        failures: Vec<(AccountId, Error)>,
+    },
+
     /// Failed to verify a signature.
     #[error("failed to verify a signature: {0}")]
     SignatureVerify(#[source] BoxStdError),
+
+    /// A request to the mirror node REST API failed, or returned a response that couldn't be understood.
+    #[error("mirror node REST request failed: {0}")]
+    MirrorNodeRest(#[source] BoxStdError),
+
+    /// A secure connection to a node couldn't be established, for example, because its certificate didn't match the address book.
+    #[error("transport security failure: {0}")]
+    TransportSecurity(#[source] BoxStdError),
+
+    /// The mirror node REST API has no record of the requested entity.
+    #[error("mirror node has no record at `{path}`")]
+    MirrorNodeNotFound {
+        /// The path that was requested, relative to `/api/v1`.
+        path: String,
+    },
+
+    /// A transaction is missing a field the network requires, or has one that the network would reject.
+    #[error("invalid transaction: {0}")]
+    InvalidTransaction(#[source] BoxStdError),
+
+    /// A fee schedule has no prices for a transaction's type, so its cost can't be estimated.
+    #[error("fee schedule has no prices for `{request_type:?}`")]
+    FeeScheduleMissing {
+        /// The type of the transaction.
+        request_type: RequestType,
+    },
+
+    /// A threshold key's threshold is greater than the number of keys, so it can never be met.
+    #[error("threshold of {threshold} is greater than the number of keys ({keys})")]
+    ThresholdUnreachable {
+        /// The threshold.
+        threshold: u32,
+
+        /// The number of keys.
+        keys: usize,
+    },
 }
 
 impl Error {
+    /// Returns the [`Status`] that caused this error, if it was caused by a status returned by the network.
+    ///
+    /// This allows matching on pre-check and receipt statuses without parsing error messages.
+    #[must_use]
+    pub fn status(&self) -> Option<Status> {
+        match self {
+            Self::TransactionPreCheckStatus { status, .. }
+            | Self::QueryPreCheckStatus { status, .. }
+            | Self::QueryPaymentPreCheckStatus { status, .. }
+            | Self::QueryNoPaymentPreCheckStatus { status }
+            | Self::ReceiptStatus { status, .. } => Some(*status),
+            Self::ScheduleAlreadyExecuted { .. } => Some(Status::ScheduleAlreadyExecuted),
+            Self::TimedOut { source: error, .. }
+            | Self::MaxAttemptsExceeded { source: error, .. } => error.status(),
+            _ => None,
+        }
+    }
+
     pub(crate) fn from_protobuf<E: Into<BoxStdError>>(error: E) -> Self {
         Self::FromProtobuf(error.into())
     }
@@ -228,6 +381,15 @@ impl Error {
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    pub(crate) fn invalid_transaction(error: impl Into<BoxStdError>) -> Self {
+        Self::InvalidTransaction(error.into())
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    pub(crate) fn mirror_node_rest(error: impl Into<BoxStdError>) -> Self {
+        Self::MirrorNodeRest(error.into())
+    }
 }
 
 /// Failed to parse a mnemonic.
@@ -281,3 +443,54 @@ pub enum MnemonicEntropyError {
     #[error("used a passphrase with a legacy mnemonic")]
     LegacyWithPassphrase,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use crate::{
+        Status,
+        TransactionId,
+    };
+
+    #[test]
+    fn status_from_code() {
+        assert_eq!(Status::from(22), Status::Success);
+        assert_eq!(Status::from(-1), Status::Unrecognized(-1));
+        assert_eq!(i32::from(Status::Unrecognized(-1)), -1);
+    }
+
+    #[test]
+    fn status_names() {
+        assert_eq!(Status::Success.as_str_name(), "SUCCESS");
+        assert_eq!(Status::from_str_name("SUCCESS"), Some(Status::Success));
+        assert_eq!(Status::from_str_name("NOT_A_STATUS"), None);
+
+        assert_eq!(Status::InvalidSignature.to_string(), "INVALID_SIGNATURE");
+        assert_eq!(Status::Unrecognized(-1).to_string(), "UNRECOGNIZED(-1)");
+    }
+
+    #[test]
+    fn status() {
+        let transaction_id = TransactionId::generate(1001.into());
+
+        let receipt = Error::ReceiptStatus {
+            status: Status::InvalidSignature,
+            transaction_id: Some(Box::new(transaction_id)),
+        };
+
+        assert_eq!(receipt.status(), Some(Status::InvalidSignature));
+
+        let pre_check = Error::TransactionPreCheckStatus {
+            status: Status::InsufficientTxFee,
+            transaction_id: Box::new(transaction_id),
+            cost: None,
+        };
+
+        assert_eq!(
+            Error::TimedOut { submitted: false, source: Box::new(pre_check) }.status(),
+            Some(Status::InsufficientTxFee)
+        );
+
+        assert_eq!(Error::ResponseStatusUnrecognized(-1).status(), None);
+    }
+}
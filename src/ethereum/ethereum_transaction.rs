@@ -27,6 +27,7 @@ use crate::protobuf::FromProtobuf;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
@@ -89,8 +90,8 @@ impl EthereumTransaction {
     /// the `call_data` element as a zero length string with the original contents in
     /// the referenced file at time of execution. `The ethereum_data` will need to be
     /// "rehydrated" with the `call_data` for signature validation to pass.
-    pub fn call_data_file_id(&mut self, id: FileId) -> &mut Self {
-        self.data_mut().call_data_file_id = Some(id);
+    pub fn call_data_file_id(&mut self, id: impl Into<FileId>) -> &mut Self {
+        self.data_mut().call_data_file_id = Some(id.into());
         self
     }
 
@@ -119,6 +120,10 @@ impl TransactionExecute for EthereumTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { SmartContractServiceClient::new(channel).call_ethereum(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Contract
+    }
 }
 
 impl ValidateChecksums for EthereumTransactionData {
@@ -132,18 +137,14 @@ impl ToTransactionDataProtobuf for EthereumTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
-        let call_data = self.call_data_file_id.to_protobuf();
-
-        services::transaction_body::Data::EthereumTransaction(services::EthereumTransactionBody {
-            ethereum_data: self.ethereum_data.clone(),
-            call_data,
-            max_gas_allowance: self.max_gas_allowance_hbar.to_tinybars(),
-        })
+        services::transaction_body::Data::EthereumTransaction(self.to_protobuf())
     }
 }
 
+// note: there's no `ToSchedulableTransactionDataProtobuf` impl, the network can't schedule ethereum transactions.
+
 impl From<EthereumTransactionData> for AnyTransactionData {
     fn from(transaction: EthereumTransactionData) -> Self {
         Self::Ethereum(transaction)
@@ -160,24 +161,42 @@ impl FromProtobuf<services::EthereumTransactionBody> for EthereumTransactionData
     }
 }
 
+impl ToProtobuf for EthereumTransactionData {
+    type Protobuf = services::EthereumTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::EthereumTransactionBody {
+            ethereum_data: self.ethereum_data.clone(),
+            call_data: self.call_data_file_id.to_protobuf(),
+            max_gas_allowance: self.max_gas_allowance_hbar.to_tinybars(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::expect;
 
+    use super::EthereumTransactionData;
     use crate::transaction::test_helpers::{
         check_body,
         transaction_body,
     };
+    use crate::transaction::TransactionExecute;
     use crate::{
         AnyTransaction,
         EthereumTransaction,
+        FileId,
+        FromProtobuf,
+        Service,
+        ToProtobuf,
     };
 
     fn make_transaction() -> EthereumTransaction {
         let mut tx = EthereumTransaction::new_for_tests();
 
         tx.ethereum_data(vec![0xde, 0xad, 0xbe, 0xef])
-            .call_data_file_id("4.5.6".parse().unwrap())
+            .call_data_file_id("4.5.6".parse::<FileId>().unwrap())
             .max_gas_allowance_hbar("3".parse().unwrap())
             .freeze()
             .unwrap();
@@ -228,4 +247,29 @@ mod tests {
 
         assert_eq!(tx, tx2);
     }
+
+    #[test]
+    fn to_from_protobuf() {
+        let data = make_transaction().data().clone();
+
+        let pb = data.to_protobuf();
+
+        assert_eq!(pb.ethereum_data, [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(pb.max_gas_allowance, 300_000_000);
+
+        let data2 = EthereumTransactionData::from_protobuf(pb.clone()).unwrap();
+
+        assert_eq!(data2.to_protobuf(), pb);
+    }
+
+    #[test]
+    fn service() {
+        assert_eq!(EthereumTransactionData::default().service(), Service::Contract);
+
+        let tx = make_transaction();
+        assert_eq!(tx.service(), Service::Contract);
+
+        let tx = AnyTransaction::from(tx);
+        assert_eq!(tx.service(), Service::Contract);
+    }
 }
@@ -22,6 +22,7 @@ use std::fmt;
 use std::str::FromStr;
 
 use hex::FromHexError;
+use sha3::Digest;
 
 use crate::{
     EntityId,
@@ -40,11 +41,54 @@ impl EvmAddress {
         unsafe { &*(bytes.as_ptr().cast::<EvmAddress>()) }
     }
 
+    /// Create an `EvmAddress` from its 20 raw bytes.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `bytes` isn't exactly 20 bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        <&Self>::try_from(bytes).copied()
+    }
+
     /// Gets the underlying bytes this EVM address is made from.
     #[must_use]
     pub fn to_bytes(self) -> [u8; 20] {
         self.0
     }
+
+    /// Returns `self` as a `0x` prefixed hex string with the mixed case checksum from [EIP-55].
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    #[must_use]
+    pub fn to_checksum_string(self) -> String {
+        let lower = format!("{self:x}");
+        let hash = sha3::Keccak256::digest(lower.as_bytes());
+
+        let checksummed: String = lower
+            .chars()
+            .enumerate()
+            .map(|(index, ch)| {
+                // each hex digit of the hash decides the case of the matching character.
+                let nibble = (hash[index / 2] >> (4 * (1 - index % 2))) & 0xf;
+
+                if nibble >= 8 {
+                    ch.to_ascii_uppercase()
+                } else {
+                    ch
+                }
+            })
+            .collect();
+
+        format!("0x{checksummed}")
+    }
+
+    /// Returns the entity number this address encodes if it's a "long-zero" address,
+    /// that is, the solidity address of an entity in shard 0, realm 0.
+    #[must_use]
+    pub(crate) fn long_zero_num(self) -> Option<u64> {
+        let (prefix, num) = self.0.split_at(12);
+
+        prefix.iter().all(|it| *it == 0).then(|| u64::from_be_bytes(num.try_into().unwrap()))
+    }
 }
 
 // potential point of confusion: This type is specifically for the `shard.realm.num` in 20 byte format.
@@ -191,6 +235,7 @@ impl TryFrom<Vec<u8>> for EvmAddress {
 }
 
 // Note: *requires* 0x prefix.
+// Case insensitive, unless the address is mixed case, in which case it has to be a valid EIP-55 checksum.
 impl FromStr for EvmAddress {
     type Err = Error;
 
@@ -201,10 +246,23 @@ impl FromStr for EvmAddress {
             .strip_prefix("0x")
             .ok_or_else(|| Error::basic_parse("expected `0x` prefix in evm address"))?;
 
-        hex::decode_to_slice(address, &mut buf).map(|()| Self(buf)).map_err(|err| match err {
-            FromHexError::InvalidStringLength => error_len(address.len() / 2),
-            err => Error::basic_parse(err),
-        })
+        let parsed = hex::decode_to_slice(address, &mut buf).map(|()| Self(buf)).map_err(
+            |err| match err {
+                FromHexError::InvalidStringLength => error_len(address.len() / 2),
+                err => Error::basic_parse(err),
+            },
+        )?;
+
+        let mixed_case = address.bytes().any(|it| it.is_ascii_uppercase())
+            && address.bytes().any(|it| it.is_ascii_lowercase());
+
+        if mixed_case && parsed.to_checksum_string() != s {
+            return Err(Error::basic_parse(format!(
+                "invalid EIP-55 checksum in evm address `{s}`"
+            )));
+        }
+
+        Ok(parsed)
     }
 }
 
@@ -290,6 +348,58 @@ mod tests {
         assert_matches!(res, Err(crate::Error::BasicParse(_)))
     }
 
+    #[test]
+    fn checksum_round_trip() {
+        // test vectors from EIP-55.
+        for address in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let parsed: EvmAddress = address.parse().unwrap();
+
+            assert_eq!(parsed.to_checksum_string(), address);
+            assert_eq!(parsed.to_string(), address.to_lowercase());
+
+            // a single case is never checked.
+            assert_eq!(address.to_lowercase().parse::<EvmAddress>().unwrap(), parsed);
+            assert_eq!(
+                format!("0x{}", address[2..].to_uppercase()).parse::<EvmAddress>().unwrap(),
+                parsed
+            );
+        }
+    }
+
+    #[test]
+    fn bad_checksum_fails() {
+        let res: Result<EvmAddress, _> = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".parse();
+
+        assert_matches!(res, Err(crate::Error::BasicParse(_)))
+    }
+
+    #[test]
+    fn non_hex_fails() {
+        let res: Result<EvmAddress, _> = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaeg".parse();
+
+        assert_matches!(res, Err(crate::Error::BasicParse(_)))
+    }
+
+    #[test]
+    fn from_bytes() {
+        assert_eq!(EvmAddress::from_bytes(&[0x0c; 20]).unwrap(), EvmAddress([0x0c; 20]));
+        assert_matches!(EvmAddress::from_bytes(&[0x0c; 19]), Err(crate::Error::BasicParse(_)));
+    }
+
+    #[test]
+    fn long_zero_num() {
+        let address: EvmAddress = "0x000000000000000000000000000000000000138d".parse().unwrap();
+        assert_eq!(address.long_zero_num(), Some(5005));
+
+        let address: EvmAddress = "0x0000000000000000000000010000000000000001".parse().unwrap();
+        assert_eq!(address.long_zero_num(), None);
+    }
+
     #[test]
     fn display() {
         expect![[r#"
@@ -21,7 +21,10 @@
 use hedera_proto::services;
 use time::OffsetDateTime;
 
-use crate::protobuf::FromProtobuf;
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
 
 /// The current and next exchange rates between [`Hbar`](crate::HbarUnit::Hbar) and USD-cents.
 #[derive(Debug, Clone)]
@@ -52,6 +55,17 @@ impl FromProtobuf<services::ExchangeRateSet> for ExchangeRates {
     }
 }
 
+impl ToProtobuf for ExchangeRates {
+    type Protobuf = services::ExchangeRateSet;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::ExchangeRateSet {
+            current_rate: Some(self.current_rate.to_protobuf()),
+            next_rate: Some(self.next_rate.to_protobuf()),
+        }
+    }
+}
+
 /// Denotes a conversion between Hbars and cents (USD).
 #[derive(Debug, Clone)]
 pub struct ExchangeRate {
@@ -71,6 +85,17 @@ impl ExchangeRate {
     pub fn exchange_rate_in_cents(&self) -> f64 {
         f64::from(self.cents) / f64::from(self.hbars)
     }
+
+    /// Converts `tinycents` to tinybars at this rate, rounding down.
+    pub(crate) fn tinycents_to_tinybars(&self, tinycents: u64) -> u64 {
+        if self.cents == 0 {
+            return 0;
+        }
+
+        let tinybars = u128::from(tinycents) * u128::from(self.hbars) / u128::from(self.cents);
+
+        u64::try_from(tinybars).unwrap_or(u64::MAX)
+    }
 }
 
 impl FromProtobuf<services::ExchangeRate> for ExchangeRate {
@@ -82,6 +107,18 @@ impl FromProtobuf<services::ExchangeRate> for ExchangeRate {
     }
 }
 
+impl ToProtobuf for ExchangeRate {
+    type Protobuf = services::ExchangeRate;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::ExchangeRate {
+            hbar_equiv: self.hbars as i32,
+            cent_equiv: self.cents as i32,
+            expiration_time: Some(self.expiration_time.into()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::expect;
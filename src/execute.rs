@@ -20,8 +20,12 @@
 mod error;
 
 use std::any::type_name;
-use std::borrow::Cow;
 use std::ops::ControlFlow;
+use std::sync::atomic::{
+    AtomicBool,
+    AtomicUsize,
+    Ordering,
+};
 use std::time::{
     Duration,
     Instant,
@@ -38,9 +42,14 @@ use rand::seq::SliceRandom;
 use rand::thread_rng;
 use tonic::metadata::AsciiMetadataValue;
 use tonic::transport::Channel;
+use tracing::Instrument;
 use triomphe::Arc;
 
-use crate::client::NetworkData;
+use crate::client::{
+    status_certificate_verify_error,
+    NetworkData,
+    TransactionIdGenerator,
+};
 use crate::execute::error::is_tonic_status_transient;
 use crate::ping_query::PingQuery;
 use crate::{
@@ -78,6 +87,14 @@ pub(crate) trait Execute: ValidateChecksums {
     /// Get the _explicit_ transaction ID that this request will use.
     fn transaction_id(&self) -> Option<TransactionId>;
 
+    /// Get the transaction ID to use for the first attempt of this request.
+    ///
+    /// Unlike [`transaction_id`](Self::transaction_id), this can be an ID that was generated ahead of time,
+    /// which can still be regenerated.
+    fn initial_transaction_id(&self) -> Option<TransactionId> {
+        self.transaction_id()
+    }
+
     /// Get whether to generate transaction IDs for request creation.
     fn requires_transaction_id(&self) -> bool;
 
@@ -144,8 +161,11 @@ struct ExecuteContext {
     network: Arc<NetworkData>,
     backoff_config: ExponentialBackoff,
     max_attempts: usize,
+    // limit on the total time spent on the request, across every attempt.
+    request_timeout: Option<Duration>,
     // timeout for a single grpc request.
     grpc_timeout: Option<Duration>,
+    transaction_id_generator: Option<TransactionIdGenerator>,
 }
 
 pub(crate) async fn execute<E>(
@@ -158,9 +178,7 @@ where
 {
     if client.auto_validate_checksums() {
         let ledger_id = client.ledger_id_internal();
-        let ledger_id = ledger_id
-            .as_ref()
-            .expect("Client had auto_validate_checksums enabled but no ledger ID");
+        let ledger_id = ledger_id.as_deref().ok_or(Error::CannotValidateChecksumWithoutLedgerId)?;
 
         executable.validate_checksums(ledger_id.as_ref_ledger_id())?;
     }
@@ -187,17 +205,21 @@ where
         .with_initial_interval(backoff.initial_backoff)
         .with_max_interval(backoff.max_backoff);
 
-    if let Some(timeout) = timeout.or(backoff.request_timeout) {
+    let request_timeout = timeout.or(backoff.request_timeout);
+
+    if let Some(timeout) = request_timeout {
         backoff_builder.with_max_elapsed_time(Some(timeout));
     }
 
     execute_inner(
         &ExecuteContext {
             max_attempts: backoff.max_attempts,
+            request_timeout,
             backoff_config: backoff_builder.build(),
             operator_account_id,
             network: client.net().0.load_full(),
             grpc_timeout: backoff.grpc_timeout,
+            transaction_id_generator: client.transaction_id_generator(),
         },
         executable,
     )
@@ -215,7 +237,9 @@ where
                 network: Arc::clone(&ctx.network),
                 backoff_config: ctx.backoff_config.clone(),
                 max_attempts: ctx.max_attempts,
+                request_timeout: ctx.request_timeout,
                 grpc_timeout: ctx.grpc_timeout,
+                transaction_id_generator: ctx.transaction_id_generator.clone(),
             };
             let ping_query = PingQuery::new(ctx.network.node_ids()[index]);
 
@@ -230,11 +254,33 @@ where
 
     // if we need to generate a transaction ID for this request (and one was not provided),
     // generate one now
-    let explicit_transaction_id = executable.transaction_id();
-    let mut transaction_id = executable
-        .requires_transaction_id()
-        .then_some(explicit_transaction_id)
-        .and_then(|it| it.or_else(|| ctx.operator_account_id.map(TransactionId::generate)));
+    let initial_transaction_id = executable.initial_transaction_id();
+    let mut transaction_id =
+        executable.requires_transaction_id().then_some(initial_transaction_id).and_then(|it| {
+            it.or_else(|| {
+                ctx.operator_account_id.map(|id| {
+                    TransactionId::generate_with(id, ctx.transaction_id_generator.as_ref())
+                })
+            })
+        });
+
+    let span = tracing::debug_span!(
+        "execute",
+        request = type_name::<E>(),
+        transaction_id = tracing::field::Empty
+    );
+
+    if let Some(transaction_id) = &transaction_id {
+        span.record("transaction_id", tracing::field::display(transaction_id));
+    }
+
+    // every request sent to a node, across every node and every backoff.
+    let send_attempts = AtomicUsize::new(0);
+    let send_attempts = &send_attempts;
+
+    // whether any request may have reached a node without the node rejecting it.
+    let submitted = AtomicBool::new(false);
+    let submitted = &submitted;
 
     // if we were explicitly given a list of nodes to use, we iterate through each
     // of the given nodes (in a random order)
@@ -269,29 +315,23 @@ where
             let mut random_node_indexes = std::pin::pin!(random_node_indexes);
 
             while let Some(node_index) = random_node_indexes.next().await {
-                let tmp = execute_single(ctx, executable, node_index, &mut transaction_id).await;
-
-                log::log!(
-                    match &tmp {
-                        Ok(ControlFlow::Break(_)) => log::Level::Debug,
-                        Ok(ControlFlow::Continue(_)) => log::Level::Warn,
-                        Err(e) =>
-                            if e.is_transient() {
-                                log::Level::Warn
-                            } else {
-                                log::Level::Error
-                            },
-                    },
-                    "Execution of {} on node at index {node_index} / node id {} {}",
-                    type_name::<E>(),
-                    ctx.network.channel(node_index).0,
-                    match &tmp {
-                        Ok(ControlFlow::Break(_)) => Cow::Borrowed("succeeded"),
-                        Ok(ControlFlow::Continue(err)) =>
-                            format!("will continue due to {err:?}").into(),
-                        Err(err) => format!("failed due to {err:?}").into(),
-                    },
-                );
+                let send_attempt = send_attempts.fetch_add(1, Ordering::Relaxed) + 1;
+
+                let tmp = execute_single(
+                    ctx,
+                    executable,
+                    node_index,
+                    send_attempt,
+                    &mut transaction_id,
+                    submitted,
+                )
+                .await;
+
+                // every other outcome is already traced by `execute_single` or the retry loop.
+                if let Err(retry::Error::Permanent(error)) = &tmp {
+                    let node_account_id = ctx.network.channel(node_index).0;
+                    tracing::error!(%node_account_id, send_attempt, %error, "request failed");
+                }
 
                 match tmp? {
                     ControlFlow::Continue(err) => last_error = Some(err),
@@ -310,7 +350,20 @@ where
     // the outer loop continues until we timeout or reach the maximum number of "attempts"
     // an attempt is counted when we have a successful response from a node that must either
     // be retried immediately (on a new node) or retried after a backoff.
-    crate::retry(backoff, Some(ctx.max_attempts), layer).await
+    crate::retry(backoff, Some(ctx.max_attempts), ctx.request_timeout, layer)
+        .instrument(span)
+        .await
+        .map_err(|error| match error {
+            Error::TimedOut { source, .. } => {
+                Error::TimedOut { submitted: submitted.load(Ordering::Relaxed), source }
+            }
+            Error::MaxAttemptsExceeded { attempts, source, .. } => Error::MaxAttemptsExceeded {
+                attempts,
+                submitted: submitted.load(Ordering::Relaxed),
+                source,
+            },
+            error => error,
+        })
 }
 
 fn map_tonic_error(
@@ -321,12 +374,24 @@ fn map_tonic_error(
 ) -> retry::Error {
     const MIME_HTML: &[u8] = b"text/html";
 
+    // the node isn't who the address book says it is, so we don't want to talk to it, and neither does the user.
+    if let Some(error) = status_certificate_verify_error(&status) {
+        network.mark_node_unhealthy(node_index);
+
+        return retry::Error::Permanent(Error::TransportSecurity(error.into()));
+    }
+
     match status.code() {
         // if the node says it isn't available, then we should just try again with a different node.
         tonic::Code::Unavailable | tonic::Code::ResourceExhausted => {
             // NOTE: this is an "unhealthy" node
             network.mark_node_unhealthy(node_index);
 
+            // the node might have moved, so reconnect (resolving its addresses again) the next time it's used.
+            if status.code() == tonic::Code::Unavailable {
+                network.reset_node_channel(node_index);
+            }
+
             // try the next node in our allowed list, immediately
             retry::Error::Transient(status.into())
         }
@@ -361,38 +426,44 @@ fn map_tonic_error(
     }
 }
 
+/// Returns `true` if `error` proves that the request never reached the node.
+///
+/// A refused connection (or a node that says it's unavailable) surfaces as gRPC `UNAVAILABLE`.
+fn is_undelivered(error: &Error) -> bool {
+    matches!(error, Error::GrpcStatus(status) if status.code() == tonic::Code::Unavailable)
+}
+
 async fn execute_single<E: Execute + Sync>(
     ctx: &ExecuteContext,
     executable: &E,
     node_index: usize,
+    send_attempt: usize,
     transaction_id: &mut Option<TransactionId>,
+    submitted: &AtomicBool,
 ) -> retry::Result<ControlFlow<E::Response, Error>> {
     let (node_account_id, channel) = ctx.network.channel(node_index);
 
-    log::debug!(
-        "Preparing {} on node at index {node_index} / node id {node_account_id}",
-        type_name::<E>()
-    );
-
     let (request, context) = executable
         .make_request(transaction_id.as_ref(), node_account_id)
         // Does not represent a network error or error returned by a node
         .map_err(retry::Error::Permanent)?;
 
-    log::debug!(
-        "Executing {} on node at index {node_index} / node id {node_account_id}",
-        type_name::<E>()
-    );
+    tracing::debug!(%node_account_id, send_attempt, "sending request");
 
     let fut = executable.execute(channel, request);
 
+    // until the node answers, there's no telling whether it received the request.
+    // if this future is dropped (IE: the request timed out) while waiting, it stays that way.
+    let was_submitted = submitted.swap(true, Ordering::Relaxed);
+
     let response = match ctx.grpc_timeout {
         Some(it) => match tokio::time::timeout(it, fut).await {
             Ok(it) => it,
             Err(_) => {
+                tracing::debug!(%node_account_id, send_attempt, "grpc timeout exceeded");
                 return Ok(ControlFlow::Continue(crate::Error::GrpcStatus(
                     tonic::Status::deadline_exceeded("explicitly given grpc timeout was exceeded"),
-                )))
+                )));
             }
         },
         None => fut.await,
@@ -405,21 +476,33 @@ async fn execute_single<E: Execute + Sync>(
     let response = match response {
         Ok(response) => response,
         Err(retry::Error::Transient(err)) => {
+            tracing::debug!(%node_account_id, send_attempt, error = %err, "node failed to respond");
+
+            // the request never made it to the node, so it can't have been submitted by this attempt.
+            if is_undelivered(&err) {
+                submitted.store(was_submitted, Ordering::Relaxed);
+            }
+
             return Ok(ControlFlow::Continue(err));
         }
 
         Err(e) => return Err(e),
     };
 
-    // at this point, any failure isn't from the node, it's from the request.
-    ctx.network.mark_node_healthy(node_index);
+    // the node answered, so this request's outcome is known.
+    submitted.store(was_submitted, Ordering::Relaxed);
 
-    let status = E::response_pre_check_status(&response)
-        .and_then(|status| {
-            // not sure how to proceed, fail immediately
-            Status::try_from(status).or_else(|_| Err(Error::ResponseStatusUnrecognized(status)))
-        })
-        .map_err(retry::Error::Permanent)?;
+    let status = E::response_pre_check_status(&response).map(Status::from);
+
+    // at this point, any failure isn't from the node, it's from the request (other than the node being busy).
+    match status {
+        Ok(Status::Busy | Status::PlatformNotActive) => ctx.network.mark_node_busy(node_index),
+        _ => ctx.network.mark_node_healthy(node_index),
+    }
+
+    let status = status.map_err(retry::Error::Permanent)?;
+
+    tracing::debug!(%node_account_id, send_attempt, ?status, "node responded");
 
     match status {
         Status::Ok if executable.should_retry(&response) => Err(retry::Error::Transient(
@@ -446,10 +529,15 @@ async fn execute_single<E: Execute + Sync>(
             // the transaction that was generated has since expired
             // re-generate the transaction ID and try again, immediately
 
-            let new = TransactionId::generate(ctx.operator_account_id.unwrap());
+            let new = TransactionId::generate_with(
+                ctx.operator_account_id.unwrap(),
+                ctx.transaction_id_generator.as_ref(),
+            );
 
             *transaction_id = Some(new);
 
+            tracing::Span::current().record("transaction_id", tracing::field::display(new));
+
             Ok(ControlFlow::Continue(executable.make_error_pre_check(
                 status,
                 transaction_id.as_ref(),
@@ -488,23 +576,28 @@ fn random_node_indexes(
     let now = Instant::now();
 
     if let Some(indexes) = explicit_node_indexes {
-        let tmp: Vec<_> =
-            indexes.iter().copied().filter(|index| network.is_node_healthy(*index, now)).collect();
+        assert!(!indexes.is_empty(), "empty explicitly set nodes");
 
-        let mut indexes = if tmp.is_empty() { indexes.to_vec() } else { tmp };
+        let mut healthy: Vec<_> =
+            indexes.iter().copied().filter(|index| network.is_node_healthy(*index, now)).collect();
 
-        assert!(!indexes.is_empty(), "empty explicitly set nodes");
+        if healthy.is_empty() {
+            // every node is backing off, so use the one that'll stop backing off first.
+            return network.soonest_healthy_node_index(indexes.iter().copied()).map(|it| vec![it]);
+        }
 
-        indexes.shuffle(&mut rng);
+        healthy.shuffle(&mut rng);
 
-        return Some(indexes);
+        return Some(healthy);
     }
 
     {
         let mut indexes: Vec<_> = network.healthy_node_indexes(now).collect();
 
         if indexes.is_empty() {
-            return None;
+            return network
+                .soonest_healthy_node_index(0..network.node_ids().len())
+                .map(|it| vec![it]);
         }
 
         // would put this inline, but borrowck wouldn't allow that.
@@ -6,6 +6,9 @@ use crate::protobuf::{
     ToProtobuf,
 };
 
+/// Resource prices in a fee schedule are in thousandths of a tinycent.
+const FEE_DIVISOR_FACTOR: u64 = 1000;
+
 /// Contains the current and next [`FeeSchedule`]s.
 ///
 /// See the [Hedera documentation]
@@ -86,6 +89,20 @@ impl FeeSchedule {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Returns the default (un-scoped) resource prices for `request_type`, if this schedule has any.
+    #[must_use]
+    pub fn fee_data(&self, request_type: &RequestType) -> Option<&FeeData> {
+        let schedule =
+            self.transaction_fee_schedules.iter().find(|it| it.request_type == *request_type)?;
+
+        #[allow(deprecated)]
+        schedule
+            .fees
+            .iter()
+            .find(|it| it.kind == FeeDataType::Default)
+            .or(schedule.fee_data.as_deref())
+    }
 }
 
 impl FromProtobuf<services::FeeSchedule> for FeeSchedule {
@@ -432,6 +449,68 @@ pub enum RequestType {
     TokenCancelAirdrop,
 }
 
+impl RequestType {
+    /// Returns the functionality a transaction with the given `data` uses.
+    pub(crate) fn from_transaction_data(data: &services::transaction_body::Data) -> Self {
+        use services::transaction_body::Data;
+        match data {
+            Data::ContractCall(_) => Self::ContractCall,
+            Data::ContractCreateInstance(_) => Self::ContractCreate,
+            Data::ContractUpdateInstance(_) => Self::ContractUpdate,
+            Data::ContractDeleteInstance(_) => Self::ContractDelete,
+            Data::EthereumTransaction(_) => Self::EthereumTransaction,
+            Data::CryptoApproveAllowance(_) => Self::CryptoApproveAllowance,
+            Data::CryptoDeleteAllowance(_) => Self::CryptoDeleteAllowance,
+            Data::CryptoAddLiveHash(_) => Self::CryptoAddLiveHash,
+            Data::CryptoCreateAccount(_) => Self::CryptoCreate,
+            Data::CryptoDelete(_) => Self::CryptoDelete,
+            Data::CryptoDeleteLiveHash(_) => Self::CryptoDeleteLiveHash,
+            Data::CryptoTransfer(_) => Self::CryptoTransfer,
+            Data::CryptoUpdateAccount(_) => Self::CryptoUpdate,
+            Data::FileAppend(_) => Self::FileAppend,
+            Data::FileCreate(_) => Self::FileCreate,
+            Data::FileDelete(_) => Self::FileDelete,
+            Data::FileUpdate(_) => Self::FileUpdate,
+            Data::SystemDelete(_) => Self::SystemDelete,
+            Data::SystemUndelete(_) => Self::SystemUndelete,
+            Data::Freeze(_) => Self::Freeze,
+            Data::ConsensusCreateTopic(_) => Self::ConsensusCreateTopic,
+            Data::ConsensusUpdateTopic(_) => Self::ConsensusUpdateTopic,
+            Data::ConsensusDeleteTopic(_) => Self::ConsensusDeleteTopic,
+            Data::ConsensusSubmitMessage(_) => Self::ConsensusSubmitMessage,
+            Data::UncheckedSubmit(_) => Self::UncheckedSubmit,
+            Data::TokenCreation(_) => Self::TokenCreate,
+            Data::TokenFreeze(_) => Self::TokenFreezeAccount,
+            Data::TokenUnfreeze(_) => Self::TokenUnfreezeAccount,
+            Data::TokenGrantKyc(_) => Self::TokenGrantKycToAccount,
+            Data::TokenRevokeKyc(_) => Self::TokenRevokeKycFromAccount,
+            Data::TokenDeletion(_) => Self::TokenDelete,
+            Data::TokenUpdate(_) => Self::TokenUpdate,
+            Data::TokenMint(_) => Self::TokenMint,
+            Data::TokenBurn(_) => Self::TokenBurn,
+            Data::TokenWipe(_) => Self::TokenAccountWipe,
+            Data::TokenAssociate(_) => Self::TokenAssociateToAccount,
+            Data::TokenDissociate(_) => Self::TokenDissociateFromAccount,
+            Data::TokenFeeScheduleUpdate(_) => Self::TokenFeeScheduleUpdate,
+            Data::TokenPause(_) => Self::TokenPause,
+            Data::TokenUnpause(_) => Self::TokenUnpause,
+            Data::TokenUpdateNfts(_) => Self::TokenUpdateNfts,
+            Data::TokenReject(_) => Self::TokenReject,
+            Data::TokenAirdrop(_) => Self::TokenAirdrop,
+            Data::TokenClaimAirdrop(_) => Self::TokenClaimAirdrop,
+            Data::TokenCancelAirdrop(_) => Self::TokenCancelAirdrop,
+            Data::ScheduleCreate(_) => Self::ScheduleCreate,
+            Data::ScheduleDelete(_) => Self::ScheduleDelete,
+            Data::ScheduleSign(_) => Self::ScheduleSign,
+            Data::NodeStakeUpdate(_) => Self::NodeStakeUpdate,
+            Data::UtilPrng(_) => Self::UtilPrng,
+            Data::NodeCreate(_) => Self::NodeCreate,
+            Data::NodeUpdate(_) => Self::NodeUpdate,
+            Data::NodeDelete(_) => Self::NodeDelete,
+        }
+    }
+}
+
 impl FromProtobuf<services::HederaFunctionality> for RequestType {
     fn from_protobuf(pb: services::HederaFunctionality) -> crate::Result<Self> {
         use services::HederaFunctionality;
@@ -649,6 +728,14 @@ impl FeeData {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Returns the total fee, in tinycents, for a transaction of `bytes` bytes signed `signatures` times.
+    pub(crate) fn transaction_fee(&self, bytes: u64, signatures: u64) -> u64 {
+        [&self.node, &self.network, &self.service]
+            .into_iter()
+            .map(|it| it.transaction_fee(bytes, signatures))
+            .fold(0, u64::saturating_add)
+    }
 }
 
 impl FromProtobuf<services::FeeData> for FeeData {
@@ -728,6 +815,19 @@ impl FeeComponents {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Returns the fee, in tinycents, for a transaction of `bytes` bytes signed `signatures` times.
+    ///
+    /// Only the bandwidth and verification prices are considered,
+    /// the network charges for storage and other resources which depend on the transaction's effects.
+    fn transaction_fee(&self, bytes: u64, signatures: u64) -> u64 {
+        let fee = self
+            .constant
+            .saturating_add(self.bandwidth_byte.saturating_mul(bytes))
+            .saturating_add(self.verification.saturating_mul(signatures));
+
+        fee.min(self.max).max(self.min) / FEE_DIVISOR_FACTOR
+    }
 }
 
 impl FromProtobuf<services::FeeComponents> for FeeComponents {
@@ -35,6 +35,7 @@ use crate::transaction::{
     ChunkData,
     ChunkInfo,
     ChunkedTransactionData,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -129,6 +130,14 @@ impl TransactionExecute for FileAppendTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { FileServiceClient::new(channel).append_content(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::File
+    }
+
+    fn max_chunks(&self) -> usize {
+        self.chunk_data.max_chunks
+    }
 }
 
 impl TransactionExecuteChunked for FileAppendTransactionData {}
@@ -139,6 +148,7 @@ impl ValidateChecksums for FileAppendTransactionData {
     }
 }
 
+// note: there's no `ToProtobuf` impl, the body depends on which chunk is being sent.
 impl ToTransactionDataProtobuf for FileAppendTransactionData {
     fn to_transaction_data_protobuf(
         &self,
@@ -211,8 +221,11 @@ impl FromProtobuf<Vec<services::FileAppendTransactionBody>> for FileAppendTransa
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
+    use hedera_proto::services;
 
+    use crate::protobuf::ToProtobuf;
     use crate::transaction::test_helpers::{
         check_body,
         transaction_bodies,
@@ -302,6 +315,69 @@ mod tests {
         .assert_debug_eq(&txes);
     }
 
+    #[test]
+    fn serialize_100_byte_contents() {
+        let contents = [0x5a; 100];
+
+        let mut tx = FileAppendTransaction::new_for_tests();
+        tx.file_id(FILE_ID).contents(contents).freeze().unwrap();
+
+        let txes: Vec<_> = transaction_bodies(tx).into_iter().map(check_body).collect();
+
+        // one (single chunk) body per node.
+        assert_eq!(txes.len(), 2);
+
+        for tx in txes {
+            let body = assert_matches!(tx, services::transaction_body::Data::FileAppend(it) => it);
+
+            assert_eq!(body.file_id, Some(FILE_ID.to_protobuf()));
+            assert_eq!(body.contents, contents);
+        }
+    }
+
+    #[test]
+    fn chunked_10_kb() {
+        let contents = vec![0x5a; 10 * 1024];
+
+        let mut tx = FileAppendTransaction::new_for_tests();
+        tx.file_id(FILE_ID).contents(contents.clone()).freeze().unwrap();
+
+        let txes = transaction_bodies(tx);
+
+        // 3 chunks (4096 + 4096 + 2048 bytes), each for 2 nodes.
+        assert_eq!(txes.len(), 6);
+
+        let chunks: Vec<_> = txes
+            .into_iter()
+            .map(check_body)
+            .step_by(2)
+            .map(|it| assert_matches!(it, services::transaction_body::Data::FileAppend(it) => it.contents))
+            .collect();
+
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), [4096, 4096, 2048]);
+        assert_eq!(chunks.concat(), contents);
+    }
+
+    #[test]
+    fn chunked_exact_multiple() {
+        let mut tx = FileAppendTransaction::new_for_tests();
+        tx.file_id(FILE_ID).contents(vec![0x5a; 8192]).freeze().unwrap();
+
+        // 2 chunks, each for 2 nodes.
+        assert_eq!(transaction_bodies(tx).len(), 4);
+    }
+
+    #[test]
+    fn freeze_too_many_chunks() {
+        let mut tx = FileAppendTransaction::new_for_tests();
+        tx.file_id(FILE_ID).contents(vec![0x5a; 10 * 1024]).max_chunks(2);
+
+        assert_matches!(
+            tx.freeze(),
+            Err(crate::Error::MaxChunksExceeded { used_chunks: 3, max_chunks: 2 })
+        );
+    }
+
     #[test]
     fn to_from_bytes() {
         let tx = make_transaction();
@@ -35,6 +35,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -159,8 +160,8 @@ impl FileCreateTransaction {
     ///
     /// # Network Support
     /// Please note that this not supported on any hedera network at this time.
-    pub fn auto_renew_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().auto_renew_account_id = Some(id);
+    pub fn auto_renew_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().auto_renew_account_id = Some(id.into());
         self
     }
 
@@ -192,6 +193,10 @@ impl TransactionExecute for FileCreateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { FileServiceClient::new(channel).create_file(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::File
+    }
 }
 
 impl ValidateChecksums for FileCreateTransactionData {
@@ -207,7 +212,7 @@ impl ToTransactionDataProtobuf for FileCreateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::FileCreate(self.to_protobuf())
     }
@@ -258,6 +263,7 @@ impl ToProtobuf for FileCreateTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
     use hex_literal::hex;
@@ -398,6 +404,22 @@ mod tests {
         assert_eq!(tx, tx2);
     }
 
+    #[test]
+    fn serialize_100_byte_contents() {
+        let contents = [0x5a; 100];
+
+        let mut tx = FileCreateTransaction::new_for_tests();
+
+        tx.contents(contents).keys(keys()).freeze().unwrap();
+
+        let tx = check_body(transaction_body(tx));
+
+        let body = assert_matches!(tx, services::transaction_body::Data::FileCreate(it) => it);
+
+        assert_eq!(body.contents, contents);
+        assert_eq!(body.keys, Some(KeyList::from_iter(keys()).to_protobuf()));
+    }
+
     #[test]
     fn from_proto_body() {
         let tx = services::FileCreateTransactionBody {
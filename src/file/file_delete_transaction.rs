@@ -30,6 +30,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -81,6 +82,10 @@ impl TransactionExecute for FileDeleteTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { FileServiceClient::new(channel).delete_file(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::File
+    }
 }
 
 impl ValidateChecksums for FileDeleteTransactionData {
@@ -94,7 +99,7 @@ impl ToTransactionDataProtobuf for FileDeleteTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::FileDelete(self.to_protobuf())
     }
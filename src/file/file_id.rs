@@ -107,15 +107,18 @@ impl FileId {
     }
 
     /// Convert `self` to a string with a valid checksum.
-    #[must_use]
-    pub fn to_string_with_checksum(&self, client: &Client) -> String {
-        EntityId::to_string_with_checksum(self.to_string(), client)
+    ///
+    /// # Errors
+    /// - [`Error::CannotCreateChecksumWithoutLedgerId`] if the client has no `ledger_id`.
+    pub fn to_string_with_checksum(&self, client: &Client) -> crate::Result<String> {
+        EntityId::to_string_with_checksum(self.shard, self.realm, self.num, client)
     }
 
     /// Validates `self.checksum` (if it exists) for `client`.
     ///
     /// # Errors
     /// - [`Error::BadEntityId`] if there is a checksum, and the checksum is not valid for the client's `ledger_id`.
+    /// - [`Error::CannotValidateChecksumWithoutLedgerId`] if there is a checksum, and the client has no `ledger_id`.
     pub fn validate_checksum(&self, client: &Client) -> Result<(), Error> {
         EntityId::validate_checksum(self.shard, self.realm, self.num, self.checksum, client)
     }
@@ -191,7 +194,13 @@ impl From<EntityId> for FileId {
 
 #[cfg(test)]
 mod tests {
-    use crate::FileId;
+    use assert_matches::assert_matches;
+
+    use crate::{
+        Client,
+        Error,
+        FileId,
+    };
 
     #[test]
     fn should_serialize_from_string() {
@@ -223,4 +232,23 @@ mod tests {
             FileId::new(0, 0, 5005).to_solidity_address().unwrap()
         );
     }
+
+    #[test]
+    fn from_u64() {
+        assert_eq!(FileId::from(5005), FileId::new(0, 0, 5005));
+    }
+
+    #[tokio::test]
+    async fn to_string_with_checksum() {
+        let client = Client::for_testnet();
+
+        assert_eq!(FileId::from(5005).to_string_with_checksum(&client).unwrap(), "0.0.5005-dkxwh");
+
+        client.set_ledger_id(None);
+
+        assert_matches!(
+            FileId::from(5005).to_string_with_checksum(&client),
+            Err(Error::CannotCreateChecksumWithoutLedgerId)
+        );
+    }
 }
@@ -34,6 +34,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -161,8 +162,8 @@ impl FileUpdateTransaction {
     ///
     /// # Network Support
     /// Please note that this not supported on any hedera network at this time.
-    pub fn auto_renew_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().auto_renew_account_id = Some(id);
+    pub fn auto_renew_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().auto_renew_account_id = Some(id.into());
         self
     }
 
@@ -195,6 +196,10 @@ impl TransactionExecute for FileUpdateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { FileServiceClient::new(channel).update_file(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::File
+    }
 }
 
 impl ValidateChecksums for FileUpdateTransactionData {
@@ -208,7 +213,7 @@ impl ToTransactionDataProtobuf for FileUpdateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::FileUpdate(self.to_protobuf())
     }
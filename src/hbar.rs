@@ -3,12 +3,18 @@ use std::fmt::{
     Display,
     Formatter,
 };
-use std::ops;
 use std::str::FromStr;
+use std::{
+    iter,
+    ops,
+};
 
 use rust_decimal::prelude::*;
 
-use crate::Error;
+use crate::{
+    Error,
+    ExchangeRate,
+};
 
 /// Type alias for `i64` representing `tinybar`
 pub type Tinybar = i64;
@@ -173,10 +179,52 @@ impl Hbar {
     where
         T: Into<Decimal>,
     {
+        Self::checked_from_unit(amount.into(), unit).unwrap()
+    }
+
+    /// Convert from `amount` in `unit` to `Hbar`, without any rounding.
+    ///
+    /// Unlike [`from_unit`](Self::from_unit), this fails instead of truncating
+    /// when `amount` has more decimal places than `unit` supports.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `amount` isn't a whole number of tinybars.
+    /// - [`Error::BasicParse`] if the result would overflow a i64.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::{Hbar, HbarUnit};
+    /// use rust_decimal::Decimal;
+    /// # use std::str::FromStr;
+    /// let amount = Decimal::from_str("12.34567891").unwrap();
+    /// assert_eq!(Hbar::from_decimal(amount, HbarUnit::Hbar)?, Hbar::from_tinybars(1_234_567_891));
+    ///
+    /// let amount = Decimal::from_str("0.1").unwrap();
+    /// assert!(Hbar::from_decimal(amount, HbarUnit::Tinybar).is_err());
+    /// # Ok::<(), hedera::Error>(())
+    /// ```
+    pub fn from_decimal(amount: Decimal, unit: HbarUnit) -> crate::Result<Self> {
+        let tinybars = amount.checked_mul(unit.tinybars().into()).ok_or_else(|| {
+            Error::basic_parse(format!("`{amount} {unit}` is out of range for an amount of hbar"))
+        })?;
+
+        if !tinybars.fract().is_zero() {
+            return Err(Error::basic_parse(format!(
+                "`{amount} {unit}` is not a whole number of tinybars"
+            )));
+        }
+
+        tinybars.to_i64().map(Hbar::from_tinybars).ok_or_else(|| {
+            Error::basic_parse(format!("`{amount} {unit}` is out of range for an amount of hbar"))
+        })
+    }
+
+    /// Convert from `amount` in `unit` to `Hbar`, returning `None` if the result would overflow a i64.
+    fn checked_from_unit(amount: Decimal, unit: HbarUnit) -> Option<Self> {
         let unit_tinybars: Decimal = unit.tinybars().into();
-        let amount_tinybars = amount.into().checked_mul(unit_tinybars).unwrap();
+        let amount_tinybars = amount.checked_mul(unit_tinybars)?;
 
-        Hbar::from_tinybars(amount_tinybars.to_i64().unwrap())
+        amount_tinybars.to_i64().map(Hbar::from_tinybars)
     }
 
     /// Returns the value of `self` in `Tinybar`s.
@@ -209,11 +257,149 @@ impl Hbar {
         self.to(HbarUnit::Hbar)
     }
 
+    /// Returns `self` formatted as an exact amount of `unit`s, followed by the unit's symbol.
+    ///
+    /// Unlike going through [`to`](Self::to), this uses integer tinybar math,
+    /// so the output never depends on rounding or the host's locale.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::{Hbar, HbarUnit};
+    /// assert_eq!(Hbar::from_tinybars(123_456_789).to_string_with_unit(HbarUnit::Hbar), "1.23456789 ℏ");
+    /// assert_eq!(Hbar::new(-2).to_string_with_unit(HbarUnit::Millibar), "-2000 mℏ");
+    /// ```
+    #[must_use]
+    pub fn to_string_with_unit(self, unit: HbarUnit) -> String {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let tinybars = self.0.unsigned_abs();
+
+        // all units are positive powers of 10.
+        let unit_tinybars = unit.tinybars().unsigned_abs();
+        let whole = tinybars / unit_tinybars;
+        let fractional = tinybars % unit_tinybars;
+
+        if fractional == 0 {
+            return format!("{sign}{whole} {}", unit.symbol());
+        }
+
+        let width = unit_tinybars.ilog10() as usize;
+        let fractional = format!("{fractional:0width$}");
+        let fractional = fractional.trim_end_matches('0');
+
+        format!("{sign}{whole}.{fractional} {}", unit.symbol())
+    }
+
+    /// Returns the value of `self` in US dollars at the given exchange `rate`,
+    /// or `None` if `rate.hbars` is zero or the result would overflow.
+    ///
+    /// The conversion is done with the rate's integer hbar and cent equivalents, so it doesn't drift like floats do.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::{ExchangeRate, Hbar};
+    /// use rust_decimal::Decimal;
+    /// # let expiration_time = time::OffsetDateTime::UNIX_EPOCH;
+    /// // 1 ℏ = 12 ¢
+    /// let rate = ExchangeRate { hbars: 1, cents: 12, expiration_time };
+    /// assert_eq!(Hbar::new(1).to_usd(&rate), Some(Decimal::new(12, 2)));
+    /// ```
+    #[must_use]
+    pub fn to_usd(&self, rate: &ExchangeRate) -> Option<Decimal> {
+        let cents = Decimal::from(self.to_tinybars())
+            .checked_mul(Decimal::from(rate.cents))?
+            .checked_div(Decimal::from(rate.hbars))?;
+
+        Some(cents / Decimal::from(HbarUnit::Hbar.tinybars()) / Decimal::ONE_HUNDRED)
+    }
+
+    /// Converts `amount` US dollars to hbar at the given exchange `rate`, rounding towards zero to the nearest tinybar,
+    /// or `None` if `rate.cents` is zero or the result would overflow a i64.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::{ExchangeRate, Hbar};
+    /// use rust_decimal::Decimal;
+    /// # let expiration_time = time::OffsetDateTime::UNIX_EPOCH;
+    /// // 1 ℏ = 12 ¢
+    /// let rate = ExchangeRate { hbars: 1, cents: 12, expiration_time };
+    /// assert_eq!(Hbar::from_usd(Decimal::new(3, 0), &rate), Some(Hbar::new(25)));
+    /// ```
+    #[must_use]
+    pub fn from_usd(amount: Decimal, rate: &ExchangeRate) -> Option<Self> {
+        let tinycents = amount
+            .checked_mul(Decimal::ONE_HUNDRED)?
+            .checked_mul(Decimal::from(HbarUnit::Hbar.tinybars()))?;
+
+        let tinybars = tinycents
+            .checked_mul(Decimal::from(rate.hbars))?
+            .checked_div(Decimal::from(rate.cents))?;
+
+        tinybars.trunc().to_i64().map(Self::from_tinybars)
+    }
+
     /// Returns [`-self`](std::ops::Neg::neg).
     #[must_use]
     pub fn negated(self) -> Self {
         -self
     }
+
+    /// Returns `true` if `self` is exactly `0 ℏ`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::Hbar;
+    /// assert!(Hbar::ZERO.is_zero());
+    /// assert!(!Hbar::from_tinybars(1).is_zero());
+    /// ```
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if `self` is less than `0 ℏ`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::Hbar;
+    /// assert!(Hbar::new(-1).is_negative());
+    /// assert!(!Hbar::ZERO.is_negative());
+    /// ```
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Checked addition, returns `None` if the result would overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::Hbar;
+    /// assert_eq!(Hbar::new(1).checked_add(Hbar::new(2)), Some(Hbar::new(3)));
+    /// assert_eq!(Hbar::from_tinybars(i64::MAX).checked_add(Hbar::from_tinybars(1)), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Checked subtraction, returns `None` if the result would overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::Hbar;
+    /// assert_eq!(Hbar::new(1).checked_sub(Hbar::new(2)), Some(Hbar::new(-1)));
+    /// assert_eq!(Hbar::from_tinybars(i64::MIN).checked_sub(Hbar::from_tinybars(1)), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
 }
 
 impl From<Hbar> for Decimal {
@@ -249,9 +435,14 @@ impl FromStr for Hbar {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (amount, unit) = s.split_once(' ').unwrap_or((s, "ℏ"));
-        let amount: Decimal = amount.parse().map_err(Error::basic_parse)?;
+        let amount: Decimal = amount.parse().map_err(|err| {
+            Error::basic_parse(format!("`{s}` is not a valid amount of hbar: {err}"))
+        })?;
         let unit = HbarUnit::from_str(unit)?;
-        Ok(Hbar::from_unit(amount, unit))
+
+        Hbar::checked_from_unit(amount, unit).ok_or_else(|| {
+            Error::basic_parse(format!("`{s}` is out of range for an amount of hbar"))
+        })
     }
 }
 
@@ -291,6 +482,24 @@ impl ops::SubAssign for Hbar {
     }
 }
 
+impl iter::Sum for Hbar {
+    /// Sums the tinybars of every item, saturating at [`Hbar::MIN`] and [`Hbar::MAX`].
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        // an `i128` can't overflow from adding `i64`s unless there are more than 2^64 of them.
+        let tinybars = iter.fold(0_i128, |total, it| total + i128::from(it.0));
+
+        let tinybars = tinybars.clamp(Self::MIN.0.into(), Self::MAX.0.into());
+
+        Self(i64::try_from(tinybars).expect("tinybars were clamped to the range of an `i64`"))
+    }
+}
+
+impl<'a> iter::Sum<&'a Hbar> for Hbar {
+    fn sum<I: Iterator<Item = &'a Hbar>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 impl<T> ops::Mul<T> for Hbar
 where
     i64: ops::Mul<T, Output = i64>,
@@ -335,9 +544,11 @@ where
 mod tests {
     use std::str::FromStr;
 
+    use assert_matches::assert_matches;
     use rust_decimal::Decimal;
 
     use crate::{
+        ExchangeRate,
         Hbar,
         HbarUnit,
     };
@@ -383,4 +594,201 @@ mod tests {
         assert_eq!(m.to_tinybars(), 3);
         assert_eq!((-m).to_tinybars(), -3);
     }
+
+    #[test]
+    fn parse_natural_forms() {
+        assert_eq!(Hbar::from_str("1 tℏ").unwrap(), Hbar::from_tinybars(1));
+        assert_eq!(Hbar::from_str("0.5").unwrap(), Hbar::from_tinybars(50_000_000));
+        assert_eq!(Hbar::from_str("1.5 ℏ").unwrap().to_string(), "1.5 ℏ");
+    }
+
+    #[test]
+    fn parse_out_of_range() {
+        assert_matches!(Hbar::from_str("9223372036854775808 tℏ"), Err(crate::Error::BasicParse(_)));
+        assert_matches!(Hbar::from_str("100 Gℏ"), Err(crate::Error::BasicParse(_)));
+        assert_matches!(Hbar::from_str("0.5 xℏ"), Err(crate::Error::BasicParse(_)));
+    }
+
+    #[test]
+    fn checked_arithmetic_overflow() {
+        let max = Hbar::from_tinybars(i64::MAX);
+        let min = Hbar::from_tinybars(i64::MIN);
+        let one = Hbar::from_tinybars(1);
+
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(min.checked_sub(one), None);
+        assert_eq!(max.checked_sub(one), Some(Hbar::from_tinybars(i64::MAX - 1)));
+        assert_eq!(min.checked_add(one), Some(Hbar::from_tinybars(i64::MIN + 1)));
+    }
+
+    #[test]
+    fn to_string_with_unit() {
+        let amount = Hbar::from_tinybars(123_456_789);
+
+        assert_eq!(amount.to_string_with_unit(HbarUnit::Tinybar), "123456789 tℏ");
+        assert_eq!(amount.to_string_with_unit(HbarUnit::Microbar), "1234567.89 μℏ");
+        assert_eq!(amount.to_string_with_unit(HbarUnit::Hbar), "1.23456789 ℏ");
+        assert_eq!(amount.to_string_with_unit(HbarUnit::Gigabar), "0.00000000123456789 Gℏ");
+        assert_eq!(amount.negated().to_string_with_unit(HbarUnit::Hbar), "-1.23456789 ℏ");
+        assert_eq!(Hbar::ZERO.to_string_with_unit(HbarUnit::Kilobar), "0 kℏ");
+        assert_eq!(Hbar::MAX.to_string_with_unit(HbarUnit::Hbar), "50000000000 ℏ");
+        assert_eq!(
+            Hbar::from_tinybars(i64::MIN).to_string_with_unit(HbarUnit::Hbar),
+            "-92233720368.54775808 ℏ"
+        );
+    }
+
+    #[test]
+    fn parse_boundaries() {
+        assert_eq!(Hbar::from_str("-1.23456789").unwrap(), Hbar::from_tinybars(-123_456_789));
+        assert_eq!(
+            Hbar::from_str("9223372036854775807 tℏ").unwrap(),
+            Hbar::from_tinybars(i64::MAX)
+        );
+        assert_eq!(Hbar::from_str("50000000000 ℏ").unwrap(), Hbar::MAX);
+        assert_eq!(Hbar::from_str("-50 Gℏ").unwrap(), Hbar::MIN);
+
+        for amount in [Hbar::MAX, Hbar::MIN, Hbar::from_tinybars(-123_456_789)] {
+            for unit in [HbarUnit::Tinybar, HbarUnit::Hbar, HbarUnit::Gigabar] {
+                assert_eq!(Hbar::from_str(&amount.to_string_with_unit(unit)).unwrap(), amount);
+            }
+        }
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(Hbar::MAX.to_tinybars(), 5_000_000_000_000_000_000);
+        assert_eq!(Hbar::MIN, -Hbar::MAX);
+        assert_eq!(Hbar::ZERO, Hbar::default());
+
+        assert!(Hbar::ZERO.is_zero());
+        assert!(!Hbar::ZERO.is_negative());
+        assert!(Hbar::MIN.is_negative());
+        assert!(!Hbar::MAX.is_negative());
+        assert!(!Hbar::MAX.is_zero());
+    }
+
+    #[test]
+    fn usd_conversion() {
+        let expiration_time = time::OffsetDateTime::UNIX_EPOCH;
+
+        let rate = ExchangeRate { hbars: 30_000, cents: 580_150, expiration_time };
+
+        // 580,150 ¢ / 30,000 ℏ
+        assert_eq!(Hbar::new(30_000).to_usd(&rate), Some(Decimal::new(5_801_50, 2)));
+        assert_eq!(Hbar::new(3).to_usd(&rate), Some(Decimal::new(58_015, 5)));
+        assert_eq!(Hbar::new(-3).to_usd(&rate), Some(Decimal::new(-58_015, 5)));
+
+        assert_eq!(Hbar::from_usd(Decimal::new(5_801_50, 2), &rate), Some(Hbar::new(30_000)));
+        assert_eq!(Hbar::from_usd(Decimal::ONE, &rate), Some(Hbar::from_tinybars(517_107_644)));
+        assert_eq!(Hbar::from_usd(Decimal::ZERO, &rate), Some(Hbar::ZERO));
+    }
+
+    #[test]
+    fn usd_conversion_zero_rate() {
+        let expiration_time = time::OffsetDateTime::UNIX_EPOCH;
+
+        assert_eq!(
+            Hbar::new(1).to_usd(&ExchangeRate { hbars: 0, cents: 12, expiration_time }),
+            None
+        );
+        assert_eq!(
+            Hbar::from_usd(Decimal::ONE, &ExchangeRate { hbars: 1, cents: 0, expiration_time }),
+            None
+        );
+    }
+
+    #[test]
+    fn from_usd_overflow() {
+        let expiration_time = time::OffsetDateTime::UNIX_EPOCH;
+
+        // 1 ℏ = 1 ¢, so $1 is 10^10 tinybars and a billion dollars no longer fits in a i64.
+        let rate = ExchangeRate { hbars: 1, cents: 1, expiration_time };
+
+        assert_eq!(
+            Hbar::from_usd(Decimal::from(900_000_000), &rate),
+            Some(Hbar::from_tinybars(9_000_000_000_000_000_000))
+        );
+        assert_eq!(Hbar::from_usd(Decimal::from(1_000_000_000), &rate), None);
+        assert_eq!(Hbar::from_usd(Decimal::MAX, &rate), None);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let err = Hbar::from_str("abc").unwrap_err();
+
+        assert_matches!(err, crate::Error::BasicParse(_));
+        assert!(err.to_string().contains("`abc` is not a valid amount of hbar"));
+    }
+
+    #[test]
+    fn from_decimal() {
+        let amount = Decimal::from_str("12.34567891").unwrap();
+
+        assert_eq!(
+            Hbar::from_decimal(amount, HbarUnit::Hbar).unwrap(),
+            Hbar::from_tinybars(1_234_567_891)
+        );
+        assert_eq!(
+            Hbar::from_decimal(-amount, HbarUnit::Kilobar).unwrap(),
+            Hbar::from_tinybars(-1_234_567_891_000)
+        );
+        assert_eq!(Hbar::from_decimal(Decimal::from(50), HbarUnit::Gigabar).unwrap(), Hbar::MAX);
+    }
+
+    #[test]
+    fn from_decimal_sub_tinybar() {
+        let err =
+            Hbar::from_decimal(Decimal::from_str("0.1").unwrap(), HbarUnit::Tinybar).unwrap_err();
+
+        assert_matches!(err, crate::Error::BasicParse(_));
+        assert!(err.to_string().contains("`0.1 tℏ` is not a whole number of tinybars"));
+
+        // one more decimal place than hbar supports.
+        assert_matches!(
+            Hbar::from_decimal(Decimal::from_str("1.234567891").unwrap(), HbarUnit::Hbar),
+            Err(crate::Error::BasicParse(_))
+        );
+    }
+
+    #[test]
+    fn from_decimal_out_of_range() {
+        assert_matches!(
+            Hbar::from_decimal(Decimal::from(100), HbarUnit::Gigabar),
+            Err(crate::Error::BasicParse(_))
+        );
+    }
+
+    #[test]
+    fn sum() {
+        let transfers =
+            [Hbar::new(5), Hbar::new(-3), Hbar::from_tinybars(-150_000_000), Hbar::new(-1)];
+
+        assert_eq!(transfers.iter().sum::<Hbar>(), Hbar::from_tinybars(50_000_000));
+        assert_eq!(
+            transfers.into_iter().chain([Hbar::from_tinybars(-50_000_000)]).sum::<Hbar>(),
+            Hbar::ZERO
+        );
+        assert_eq!(std::iter::empty::<Hbar>().sum::<Hbar>(), Hbar::ZERO);
+
+        let mut total = Hbar::ZERO;
+        for amount in transfers {
+            total += amount;
+        }
+        total -= Hbar::from_tinybars(50_000_000);
+        assert_eq!(total, Hbar::ZERO);
+    }
+
+    #[test]
+    fn sum_saturates() {
+        let one = Hbar::from_tinybars(1);
+
+        assert_eq!([Hbar::MAX, one].into_iter().sum::<Hbar>(), Hbar::MAX);
+        assert_eq!([Hbar::MIN, -one].into_iter().sum::<Hbar>(), Hbar::MIN);
+        assert_eq!([Hbar::MAX, -one].into_iter().sum::<Hbar>(), Hbar::MAX - one);
+
+        // the total saturates, not each step, so this doesn't get stuck at the maximum.
+        let max = Hbar::from_tinybars(i64::MAX);
+        assert_eq!([max, max, -max, -max].into_iter().sum::<Hbar>(), Hbar::ZERO);
+    }
 }
@@ -4,7 +4,11 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
-use crate::Key;
+use crate::{
+    Error,
+    Key,
+    PublicKey,
+};
 
 // note: it appears keylists "just" implement the APIs of arrays in their language, which means, uh...
 // todo: Copy over the _entire_ `Vec` API?.
@@ -40,6 +44,24 @@ impl KeyList {
         Self::default()
     }
 
+    /// Create a threshold key, where at least `threshold` of `keys` must sign.
+    ///
+    /// # Errors
+    /// - [`Error::ThresholdUnreachable`] if `threshold` is greater than the number of keys,
+    ///   such a key can never be met, and is rejected by the network.
+    pub fn threshold<K: Into<Key>>(
+        threshold: u32,
+        keys: impl IntoIterator<Item = K>,
+    ) -> crate::Result<Self> {
+        let keys: Vec<Key> = keys.into_iter().map(Into::into).collect();
+
+        if usize::try_from(threshold).map_or(true, |threshold| threshold > keys.len()) {
+            return Err(Error::ThresholdUnreachable { threshold, keys: keys.len() });
+        }
+
+        Ok(Self { keys, threshold: Some(threshold) })
+    }
+
     /// Returns `true` if this keylist is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -83,6 +105,30 @@ impl FromIterator<Key> for KeyList {
     }
 }
 
+impl FromIterator<PublicKey> for KeyList {
+    fn from_iter<T: IntoIterator<Item = PublicKey>>(iter: T) -> Self {
+        iter.into_iter().map(Key::Single).collect()
+    }
+}
+
+impl IntoIterator for KeyList {
+    type Item = Key;
+    type IntoIter = std::vec::IntoIter<Key>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a KeyList {
+    type Item = &'a Key;
+    type IntoIter = std::slice::Iter<'a, Key>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.iter()
+    }
+}
+
 impl From<Vec<Key>> for KeyList {
     fn from(value: Vec<Key>) -> Self {
         Self { keys: value, threshold: None }
@@ -109,6 +155,7 @@ impl FromProtobuf<services::ThresholdKey> for KeyList {
     where
         Self: Sized,
     {
+        // the network is the source of truth for keys it returns, so don't second guess them here.
         let keys = Vec::from_protobuf(pb.keys.unwrap_or_default().keys)?;
         Ok(Self { keys, threshold: Some(pb.threshold) })
     }
@@ -124,6 +171,8 @@ mod tests {
         ToProtobuf,
     };
     use crate::{
+        Error,
+        Key,
         KeyList,
         PrivateKey,
         PublicKey,
@@ -236,4 +285,65 @@ mod tests {
 
         assert!(key_list.is_empty());
     }
+
+    #[test]
+    fn from_iter_public_keys() {
+        let key_list: KeyList = keys().into_iter().collect();
+
+        assert_eq!(key_list, KeyList::from(keys()));
+        assert_eq!(key_list.threshold, None);
+
+        for (actual, expected) in key_list.into_iter().zip(keys()) {
+            assert_eq!(actual, Key::Single(expected));
+        }
+    }
+
+    #[test]
+    fn nested_threshold_key_round_trip() {
+        let [a, b, c] = keys();
+
+        let threshold_key = KeyList::threshold(2, [a, b, c]).unwrap();
+        let key = Key::KeyList(KeyList::from([Key::KeyList(threshold_key.clone()), a.into()]));
+
+        let pb = key.to_protobuf();
+
+        let outer = assert_matches!(pb.key.clone(), Some(services::key::Key::KeyList(it)) => it);
+        assert_eq!(outer.keys.len(), 2);
+        let inner = assert_matches!(
+            outer.keys[0].key.clone(),
+            Some(services::key::Key::ThresholdKey(it)) => it
+        );
+        assert_eq!(inner.threshold, 2);
+        assert_eq!(inner.keys.unwrap().keys.len(), 3);
+
+        let decoded = Key::from_protobuf(pb).unwrap();
+
+        assert_eq!(decoded, key);
+
+        let decoded = assert_matches!(decoded, Key::KeyList(it) => it);
+        assert_eq!(decoded[0], Key::KeyList(threshold_key));
+    }
+
+    #[test]
+    fn from_protobuf_threshold_too_large() {
+        let threshold_key_pb = services::ThresholdKey {
+            threshold: 4,
+            keys: Some(services::KeyList {
+                keys: keys().iter().map(|it| it.to_protobuf()).collect(),
+            }),
+        };
+
+        let key_list = KeyList::from_protobuf(threshold_key_pb).unwrap();
+
+        assert_eq!(key_list.threshold, Some(4));
+        assert_eq!(key_list.len(), 3);
+    }
+
+    #[test]
+    fn threshold_too_large() {
+        assert_matches!(
+            KeyList::threshold(4, keys()),
+            Err(Error::ThresholdUnreachable { threshold: 4, keys: 3 })
+        );
+    }
 }
@@ -34,3 +34,11 @@ pub(crate) enum KeyKind {
     Ed25519,
     Ecdsa,
 }
+
+/// PEM encodes `der` with the type label `tag`, using `\n` line endings.
+pub(crate) fn encode_pem(tag: &str, der: Vec<u8>) -> String {
+    ::pem::encode_config(
+        &::pem::Pem::new(tag, der),
+        ::pem::EncodeConfig::new().set_line_ending(::pem::LineEnding::LF),
+    )
+}
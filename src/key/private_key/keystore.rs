@@ -0,0 +1,182 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! The keystore format used by the other Hedera SDKs to store a password protected private key.
+//!
+//! A keystore is a JSON document with the private key encrypted with AES-128-CTR,
+//! using a key derived from the passphrase with PBKDF2 (HMAC-SHA256),
+//! and an HMAC-SHA384 of the ciphertext to check the passphrase.
+
+use aes::cipher::generic_array::GenericArray;
+use aes::cipher::{
+    BlockEncrypt,
+    KeyInit,
+};
+use hmac::{
+    Hmac,
+    Mac,
+};
+use sha2::{
+    Sha256,
+    Sha384,
+};
+
+use crate::Error;
+
+const VERSION: u64 = 1;
+const CIPHER: &str = "AES-128-CTR";
+const KDF: &str = "pbkdf2";
+const PRF: &str = "hmac-sha256";
+const DK_LEN: u64 = 32;
+const ITERATIONS: u32 = 262_144;
+
+/// Encrypts `key_bytes` with `passphrase`, returning the keystore as JSON.
+pub(super) fn create(key_bytes: &[u8], passphrase: &str) -> Vec<u8> {
+    let salt: [u8; 32] = rand::random();
+    let iv: [u8; 16] = rand::random();
+
+    let key = derive_key(passphrase, &salt, ITERATIONS);
+
+    let mut ciphertext = key_bytes.to_vec();
+    aes_128_ctr(&key[..16], iv, &mut ciphertext);
+
+    let mac = mac(&key[16..], &ciphertext).finalize().into_bytes();
+
+    serde_json::json!({
+        "version": VERSION,
+        "crypto": {
+            "ciphertext": hex::encode(&ciphertext),
+            "cipherparams": { "iv": hex::encode(iv) },
+            "cipher": CIPHER,
+            "kdf": KDF,
+            "kdfparams": {
+                "dkLen": DK_LEN,
+                "salt": hex::encode(salt),
+                "c": ITERATIONS,
+                "prf": PRF,
+            },
+            "mac": hex::encode(mac),
+        },
+    })
+    .to_string()
+    .into_bytes()
+}
+
+/// Decrypts the key stored in the keystore `bytes` with `passphrase`.
+///
+/// # Errors
+/// - [`Error::KeyParse`] if `bytes` isn't a supported keystore.
+/// - [`Error::KeyParse`] if `passphrase` is incorrect.
+pub(super) fn load(bytes: &[u8], passphrase: &str) -> crate::Result<Vec<u8>> {
+    let json: serde_json::Value = serde_json::from_slice(bytes).map_err(Error::key_parse)?;
+
+    if json["version"].as_u64() != Some(VERSION) {
+        return Err(Error::key_parse(format!("unsupported keystore version: {}", json["version"])));
+    }
+
+    let crypto = &json["crypto"];
+    let kdf_params = &crypto["kdfparams"];
+
+    let cipher = str_field(crypto, "cipher")?;
+    if !cipher.eq_ignore_ascii_case(CIPHER) {
+        return Err(Error::key_parse(format!("unsupported keystore cipher: `{cipher}`")));
+    }
+
+    let kdf = str_field(crypto, "kdf")?;
+    if kdf != KDF {
+        return Err(Error::key_parse(format!(
+            "unsupported keystore key derivation function: `{kdf}`"
+        )));
+    }
+
+    let prf = str_field(kdf_params, "prf")?;
+    if prf != PRF {
+        return Err(Error::key_parse(format!(
+            "unsupported keystore key derivation hash function: `{prf}`"
+        )));
+    }
+
+    if kdf_params["dkLen"].as_u64() != Some(DK_LEN) {
+        return Err(Error::key_parse(format!(
+            "unsupported keystore derived key length: {}",
+            kdf_params["dkLen"]
+        )));
+    }
+
+    let iterations = kdf_params["c"]
+        .as_u64()
+        .and_then(|it| u32::try_from(it).ok())
+        .ok_or_else(|| Error::key_parse("keystore is missing a valid `c`"))?;
+
+    let iv: [u8; 16] = hex_field(&crypto["cipherparams"], "iv")?
+        .try_into()
+        .map_err(|_| Error::key_parse("keystore `iv` must be 16 bytes"))?;
+
+    let salt = hex_field(kdf_params, "salt")?;
+    let mut ciphertext = hex_field(crypto, "ciphertext")?;
+    let expected_mac = hex_field(crypto, "mac")?;
+
+    let key = derive_key(passphrase, &salt, iterations);
+
+    // verify the mac before decrypting anything, in constant time.
+    mac(&key[16..], &ciphertext)
+        .verify_slice(&expected_mac)
+        .map_err(|_| Error::key_parse("keystore HMAC mismatch; passphrase is incorrect"))?;
+
+    aes_128_ctr(&key[..16], iv, &mut ciphertext);
+
+    Ok(ciphertext)
+}
+
+fn str_field<'a>(value: &'a serde_json::Value, name: &str) -> crate::Result<&'a str> {
+    value[name].as_str().ok_or_else(|| Error::key_parse(format!("keystore is missing `{name}`")))
+}
+
+fn hex_field(value: &serde_json::Value, name: &str) -> crate::Result<Vec<u8>> {
+    hex::decode(str_field(value, name)?).map_err(Error::key_parse)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], iterations: u32) -> [u8; 32] {
+    pbkdf2::pbkdf2_hmac_array::<Sha256, 32>(passphrase.as_bytes(), salt, iterations)
+}
+
+fn mac(key: &[u8], ciphertext: &[u8]) -> Hmac<Sha384> {
+    let mut mac = Hmac::<Sha384>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(ciphertext);
+    mac
+}
+
+/// Encrypts (or decrypts) `data` in place with AES-128 in CTR mode, using `iv` as the initial (big endian) counter.
+fn aes_128_ctr(key: &[u8], iv: [u8; 16], data: &mut [u8]) {
+    let cipher = aes::Aes128::new(GenericArray::from_slice(key));
+
+    let mut counter = u128::from_be_bytes(iv);
+
+    for chunk in data.chunks_mut(16) {
+        let mut block = GenericArray::from(counter.to_be_bytes());
+        cipher.encrypt_block(&mut block);
+
+        for (byte, key_byte) in chunk.iter_mut().zip(block) {
+            *byte ^= key_byte;
+        }
+
+        counter = counter.wrapping_add(1);
+    }
+}
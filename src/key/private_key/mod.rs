@@ -18,6 +18,7 @@
  * ‍
  */
 
+mod keystore;
 #[cfg(test)]
 mod tests;
 
@@ -462,6 +463,27 @@ impl PrivateKey {
         inner(pem.as_ref(), password.as_ref())
     }
 
+    /// Decrypt a `PrivateKey` from a keystore, as created by [`to_keystore`](Self::to_keystore) or the other Hedera SDKs.
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `keystore` isn't a supported keystore.
+    /// - [`Error::KeyParse`] if `passphrase` is incorrect.
+    /// - [`Error::KeyParse`] if the decrypted data is not a valid `PrivateKey`.
+    pub fn from_keystore(keystore: &[u8], passphrase: &str) -> crate::Result<Self> {
+        Self::from_bytes(&keystore::load(keystore, passphrase)?)
+    }
+
+    /// Encrypt this `PrivateKey` with `passphrase` into a keystore, the JSON format used by the other Hedera SDKs.
+    ///
+    /// Like the other SDKs, an ed25519 key is stored as its 32 raw bytes,
+    /// an ecdsa key is stored DER encoded, as its raw bytes would be read back as an ed25519 key.
+    ///
+    /// The result can be decrypted with [`from_keystore`](Self::from_keystore).
+    #[must_use]
+    pub fn to_keystore(&self, passphrase: &str) -> Vec<u8> {
+        keystore::create(&self.to_bytes(), passphrase)
+    }
+
     /// Return this `PrivateKey`, serialized as der encoded bytes.
     // panic should be impossible (`unreachable`)
     #[allow(clippy::missing_panics_doc)]
@@ -518,6 +540,14 @@ impl PrivateKey {
         hex::encode(self.to_bytes_der())
     }
 
+    /// Return this `PrivateKey`, serialized as unencrypted PKCS#8 [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-10).
+    ///
+    /// The result can be parsed with [`from_pem`](Self::from_pem).
+    #[must_use]
+    pub fn to_pem(&self) -> String {
+        crate::key::encode_pem("PRIVATE KEY", self.to_bytes_der())
+    }
+
     /// Returns the raw bytes of `self` after hex encoding.
     #[must_use]
     pub fn to_string_raw(&self) -> String {
@@ -618,7 +648,7 @@ impl PrivateKey {
     ) -> crate::Result<Vec<u8>> {
         transaction.freeze()?;
 
-        let sig = transaction.add_signature_signer(&AnySigner::PrivateKey(self.clone()));
+        let sig = transaction.add_signature_signer(&AnySigner::PrivateKey(self.clone()))?;
 
         Ok(sig)
     }
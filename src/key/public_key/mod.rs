@@ -172,6 +172,27 @@ impl PublicKey {
         Ok(Self::ecdsa(data))
     }
 
+    /// Parse a `PublicKey` from [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-13) encoded bytes.
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `pem` is not valid PEM.
+    /// - [`Error::KeyParse`] if the type label (BEGIN XYZ) is not `PUBLIC KEY`.
+    /// - [`Error::KeyParse`] if the data contained inside the PEM is not a valid `PublicKey`.
+    pub fn from_pem(pem: impl AsRef<[u8]>) -> crate::Result<Self> {
+        fn inner(pem: &[u8]) -> crate::Result<PublicKey> {
+            let pem = ::pem::parse(pem).map_err(Error::key_parse)?;
+
+            match pem.tag() {
+                "PUBLIC KEY" => PublicKey::from_bytes_der(pem.contents()),
+                type_label => Err(Error::key_parse(format!(
+                    "incorrect PEM type label: expected: `PUBLIC KEY`, got: `{type_label}`"
+                ))),
+            }
+        }
+
+        inner(pem.as_ref())
+    }
+
     /// Parse a `PublicKey` from a sequence of der encoded bytes.
     ///
     /// # Errors
@@ -301,6 +322,14 @@ impl PublicKey {
         hex::encode(self.to_bytes_der())
     }
 
+    /// Return this `PublicKey`, serialized as [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-13).
+    ///
+    /// The result can be parsed with [`from_pem`](Self::from_pem).
+    #[must_use]
+    pub fn to_pem(&self) -> String {
+        crate::key::encode_pem("PUBLIC KEY", self.to_bytes_der())
+    }
+
     /// Returns the raw bytes of `self` after hex encoding.
     #[must_use]
     pub fn to_string_raw(&self) -> String {
@@ -406,24 +435,30 @@ impl PublicKey {
 
     /// Returns `Ok(())` if this public key has signed the given transaction.
     ///
+    /// The signature is checked against the body bytes of the transaction for every node (and chunk).
+    ///
     /// # Errors
+    /// - [`Error::SignatureVerify`] if the transaction isn't frozen.
     /// - [`Error::SignatureVerify`] if the private key associated with this public key did _not_ sign this transaction,
     ///   or the signature associated was invalid.
     pub fn verify_transaction<D: crate::transaction::TransactionExecute>(
         &self,
-        transaction: &mut Transaction<D>,
+        transaction: &Transaction<D>,
     ) -> crate::Result<()> {
-        if transaction.signers().map(AnySigner::public_key).any(|it| self == &it) {
-            return Ok(());
+        if !transaction.is_frozen() {
+            return Err(Error::signature_verify(
+                "transaction must be frozen to verify its signatures",
+            ));
         }
 
-        transaction.freeze()?;
-
-        let Some(sources) = transaction.sources() else {
+        // the operator only signs when the transaction is executed, so it doesn't count unless it explicitly signed.
+        if transaction.sources().is_none()
+            && !transaction.signers().map(AnySigner::public_key).any(|it| self == &it)
+        {
             return Err(Error::signature_verify("signer not in transaction"));
-        };
+        }
 
-        self.verify_transaction_sources(sources)
+        self.verify_transaction_sources(&transaction.make_sources()?)
     }
 
     #[must_use]
@@ -24,6 +24,7 @@ use assert_matches::assert_matches;
 use expect_test::expect;
 use hex_literal::hex;
 
+use crate::transaction::test_helpers::frozen_transfer;
 use crate::{
     EvmAddress,
     PrivateKey,
@@ -228,3 +229,54 @@ fn k256_uncompressed_pkcs8_ec_spki_der() {
         "03aaac1c3ac1bea0245b8e00ce1e2018f9eab61b6331fbef7266f2287750a65977"
     )
 }
+
+#[test]
+fn verify_transaction() {
+    for key in [PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa()] {
+        let mut tx = frozen_transfer([3.into(), 4.into()]);
+
+        assert_matches!(
+            key.public_key().verify_transaction(&tx),
+            Err(crate::Error::SignatureVerify(_))
+        );
+
+        tx.sign(key.clone());
+
+        key.public_key().verify_transaction(&tx).unwrap();
+    }
+}
+
+#[test]
+fn verify_transaction_tampered_signature() {
+    let key = PrivateKey::generate_ed25519();
+
+    let mut tx = frozen_transfer([3.into(), 4.into()]);
+
+    // signs with the right key, but flips a bit of the signature.
+    tx.sign_with(key.public_key(), {
+        let key = key.clone();
+        move |message| {
+            let mut signature = key.sign(message);
+            signature[0] ^= 1;
+            signature
+        }
+    });
+
+    assert_matches!(
+        key.public_key().verify_transaction(&tx),
+        Err(crate::Error::SignatureVerify(_))
+    );
+}
+
+#[test]
+fn verify_transaction_not_frozen() {
+    let key = PrivateKey::generate_ed25519();
+
+    let mut tx = crate::TransferTransaction::new();
+    tx.sign(key.clone());
+
+    assert_matches!(
+        key.public_key().verify_transaction(&tx),
+        Err(crate::Error::SignatureVerify(_))
+    );
+}
@@ -132,9 +132,13 @@ mod file;
 mod hbar;
 mod key;
 mod ledger_id;
+#[cfg(feature = "mirror-rest")]
+mod mirror_node;
 mod mirror_query;
 #[cfg(feature = "mnemonic")]
 mod mnemonic;
+#[cfg(test)]
+mod mock;
 mod network_version_info;
 mod network_version_info_query;
 mod node_address;
@@ -183,8 +187,11 @@ pub use account::{
     AllProxyStakers,
     ProxyStaker,
 };
-pub use client::Client;
 pub(crate) use client::Operator;
+pub use client::{
+    Client,
+    CustomNetwork,
+};
 pub use contract::{
     ContractBytecodeQuery,
     ContractCallQuery,
@@ -250,7 +257,7 @@ pub use hbar::{
     HbarUnit,
     Tinybar,
 };
-pub use hedera_proto::services::ResponseCodeEnum as Status;
+pub use hedera_proto::Status;
 pub use key::{
     Key,
     KeyList,
@@ -258,6 +265,13 @@ pub use key::{
     PublicKey,
 };
 pub use ledger_id::LedgerId;
+#[cfg(feature = "mirror-rest")]
+pub use mirror_node::{
+    MirrorAccountInfo,
+    MirrorContractInfo,
+    MirrorNodeGateway,
+    MirrorTokenRelationship,
+};
 pub use mirror_query::{
     AnyMirrorQuery,
     AnyMirrorQueryResponse,
@@ -272,6 +286,7 @@ pub use node_address::NodeAddress;
 pub use node_address_book::NodeAddressBook;
 pub use node_address_book_query::NodeAddressBookQuery;
 pub(crate) use node_address_book_query::NodeAddressBookQueryData;
+pub use pending_airdrop_id::PendingAirdropId;
 pub use pending_airdrop_record::PendingAirdropRecord;
 pub use prng_transaction::PrngTransaction;
 pub(crate) use protobuf::{
@@ -336,6 +351,7 @@ pub use token::{
     TokenPauseTransaction,
     TokenRejectFlow,
     TokenRejectTransaction,
+    TokenRelationship,
     TokenRevokeKycTransaction,
     TokenSupplyType,
     TokenType,
@@ -358,6 +374,7 @@ pub use topic::{
 };
 pub use transaction::{
     AnyTransaction,
+    Service,
     Transaction,
 };
 pub use transaction_hash::TransactionHash;
@@ -0,0 +1,619 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Read only lookups against the mirror node REST API.
+
+use std::str::FromStr;
+
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use crate::{
+    AccountId,
+    Client,
+    ContractId,
+    Error,
+    EvmAddress,
+    FileId,
+    Hbar,
+    Tinybar,
+    TokenId,
+};
+
+/// Looks up accounts, contracts and tokens via the mirror node REST API.
+///
+/// This is free to use, unlike the equivalent paid queries against consensus nodes,
+/// but the mirror node may lag slightly behind consensus.
+///
+/// Requests go to the first address of the client's [`mirror_network`](Client::mirror_network).
+#[derive(Clone, Debug)]
+pub struct MirrorNodeGateway {
+    client: Client,
+}
+
+impl MirrorNodeGateway {
+    /// Create a new gateway using the mirror network (and request timeout) configured on `client`.
+    #[must_use]
+    pub fn new(client: &Client) -> Self {
+        Self { client: client.clone() }
+    }
+
+    /// Returns information about the given account.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorNodeNotFound`] if the mirror node doesn't know of `account_id`.
+    /// - [`Error::MirrorNodeRest`] if the request fails, or the response can't be understood.
+    pub async fn account_info(&self, account_id: AccountId) -> crate::Result<MirrorAccountInfo> {
+        let json = get_json(&self.client, &format!("/accounts/{account_id}"), None).await?;
+
+        MirrorAccountInfo::from_json(&json)
+    }
+
+    /// Returns information about the given contract.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorNodeNotFound`] if the mirror node doesn't know of `contract_id`.
+    /// - [`Error::MirrorNodeRest`] if the request fails, or the response can't be understood.
+    pub async fn contract_info(
+        &self,
+        contract_id: ContractId,
+    ) -> crate::Result<MirrorContractInfo> {
+        let json = get_json(&self.client, &format!("/contracts/{contract_id}"), None).await?;
+
+        MirrorContractInfo::from_json(&json)
+    }
+
+    /// Returns every token associated with the given account, following pagination to the end.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorNodeNotFound`] if the mirror node doesn't know of `account_id`.
+    /// - [`Error::MirrorNodeRest`] if any request fails, or a response can't be understood.
+    pub async fn tokens_for_account(
+        &self,
+        account_id: AccountId,
+    ) -> crate::Result<Vec<MirrorTokenRelationship>> {
+        let mut tokens = Vec::new();
+        let mut path = format!("/accounts/{account_id}/tokens");
+
+        loop {
+            let json = get_json(&self.client, &path, None).await?;
+
+            let Some(page) = field(&json, "tokens")?.as_array() else {
+                return Err(Error::mirror_node_rest("expected `tokens` to be an array"));
+            };
+
+            for token in page {
+                tokens.push(MirrorTokenRelationship::from_json(token)?);
+            }
+
+            // `next` is relative to the host, and includes the `/api/v1` prefix.
+            match json.get("links").and_then(|it| opt_str(it, "next")) {
+                Some(next) => path = next.strip_prefix("/api/v1").unwrap_or(next).to_owned(),
+                None => return Ok(tokens),
+            }
+        }
+    }
+}
+
+/// An account, as reported by the mirror node REST API.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct MirrorAccountInfo {
+    /// The ID of the account.
+    pub account_id: AccountId,
+
+    /// The EVM address of the account.
+    pub evm_address: Option<EvmAddress>,
+
+    /// The hbar balance of the account.
+    pub balance: Hbar,
+
+    /// The memo associated with the account.
+    pub memo: String,
+
+    /// Whether or not the account has been deleted.
+    pub deleted: bool,
+
+    /// If true, the account must sign any transaction depositing into it.
+    pub receiver_signature_required: bool,
+
+    /// The maximum number of tokens that the account can be implicitly associated with.
+    pub max_automatic_token_associations: i32,
+
+    /// The duration for expiration time will extend every this many seconds.
+    pub auto_renew_period: Option<Duration>,
+
+    /// The time at which the account is set to expire.
+    pub expiration_time: Option<OffsetDateTime>,
+}
+
+impl MirrorAccountInfo {
+    fn from_json(json: &serde_json::Value) -> crate::Result<Self> {
+        let balance = field(json, "balance")?;
+
+        Ok(Self {
+            account_id: parse_field(json, "account")?,
+            evm_address: opt_parse_field(json, "evm_address")?,
+            balance: Hbar::from_tinybars(tinybar_field(balance, "balance")?),
+            memo: opt_str(json, "memo").unwrap_or_default().to_owned(),
+            deleted: bool_field(json, "deleted")?,
+            receiver_signature_required: bool_field(json, "receiver_sig_required")?,
+            max_automatic_token_associations: opt_i32_field(
+                json,
+                "max_automatic_token_associations",
+            )?
+            .unwrap_or_default(),
+            auto_renew_period: opt_seconds_field(json, "auto_renew_period")?,
+            expiration_time: opt_timestamp_field(json, "expiry_timestamp")?,
+        })
+    }
+}
+
+/// A contract, as reported by the mirror node REST API.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct MirrorContractInfo {
+    /// The ID of the contract.
+    pub contract_id: ContractId,
+
+    /// The EVM address of the contract.
+    pub evm_address: Option<EvmAddress>,
+
+    /// The memo associated with the contract.
+    pub memo: String,
+
+    /// Whether or not the contract has been deleted.
+    pub deleted: bool,
+
+    /// The file containing the initcode of the contract.
+    pub file_id: Option<FileId>,
+
+    /// The account charged to extend the contract's expiry.
+    pub auto_renew_account_id: Option<AccountId>,
+
+    /// The duration for expiration time will extend every this many seconds.
+    pub auto_renew_period: Option<Duration>,
+
+    /// The maximum number of tokens that the contract can be implicitly associated with.
+    pub max_automatic_token_associations: i32,
+
+    /// The time at which the contract was created.
+    pub created_time: Option<OffsetDateTime>,
+
+    /// The time at which the contract is set to expire.
+    pub expiration_time: Option<OffsetDateTime>,
+}
+
+impl MirrorContractInfo {
+    fn from_json(json: &serde_json::Value) -> crate::Result<Self> {
+        Ok(Self {
+            contract_id: parse_field(json, "contract_id")?,
+            evm_address: opt_parse_field(json, "evm_address")?,
+            memo: opt_str(json, "memo").unwrap_or_default().to_owned(),
+            deleted: bool_field(json, "deleted")?,
+            file_id: opt_parse_field(json, "file_id")?,
+            auto_renew_account_id: opt_parse_field(json, "auto_renew_account")?,
+            auto_renew_period: opt_seconds_field(json, "auto_renew_period")?,
+            max_automatic_token_associations: opt_i32_field(
+                json,
+                "max_automatic_token_associations",
+            )?
+            .unwrap_or_default(),
+            created_time: opt_timestamp_field(json, "created_timestamp")?,
+            expiration_time: opt_timestamp_field(json, "expiration_timestamp")?,
+        })
+    }
+}
+
+/// A token associated with an account, as reported by the mirror node REST API.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct MirrorTokenRelationship {
+    /// The ID of the token.
+    pub token_id: TokenId,
+
+    /// The number of the token's smallest denomination held by the account.
+    pub balance: u64,
+
+    /// The number of decimal places the token is divisible by.
+    pub decimals: u32,
+
+    /// Whether or not the association was made automatically.
+    pub automatic_association: bool,
+
+    /// The time at which the association was made.
+    pub created_time: Option<OffsetDateTime>,
+}
+
+impl MirrorTokenRelationship {
+    fn from_json(json: &serde_json::Value) -> crate::Result<Self> {
+        let decimals = u64_field(json, "decimals")?;
+
+        Ok(Self {
+            token_id: parse_field(json, "token_id")?,
+            balance: u64_field(json, "balance")?,
+            decimals: u32::try_from(decimals)
+                .map_err(|_| Error::mirror_node_rest("expected `decimals` to fit in a u32"))?,
+            automatic_association: bool_field(json, "automatic_association")?,
+            created_time: opt_timestamp_field(json, "created_timestamp")?,
+        })
+    }
+}
+
+/// Sends a `GET` for `path` (relative to `/api/v1`) to the client's mirror network, and parses the response as JSON.
+///
+/// `timeout` defaults to the client's request timeout.
+pub(crate) async fn get_json(
+    client: &Client,
+    path: &str,
+    timeout: Option<std::time::Duration>,
+) -> crate::Result<serde_json::Value> {
+    let base_url = client
+        .mirrornet()
+        .load()
+        .rest_base_url()
+        .ok_or_else(|| Error::mirror_node_rest("no mirror network configured"))?;
+
+    let mut request = client.mirror_rest_client().get(format!("{base_url}{path}"));
+
+    if let Some(timeout) = timeout.or_else(|| client.request_timeout()) {
+        request = request.timeout(timeout);
+    }
+
+    let response = request.send().await.map_err(Error::mirror_node_rest)?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(Error::MirrorNodeNotFound { path: path.to_owned() });
+    }
+
+    let body = response
+        .error_for_status()
+        .map_err(Error::mirror_node_rest)?
+        .text()
+        .await
+        .map_err(Error::mirror_node_rest)?;
+
+    serde_json::from_str(&body).map_err(Error::mirror_node_rest)
+}
+
+pub(crate) fn field<'a>(
+    value: &'a serde_json::Value,
+    name: &str,
+) -> crate::Result<&'a serde_json::Value> {
+    value.get(name).ok_or_else(|| Error::mirror_node_rest(format!("missing field `{name}`")))
+}
+
+fn str_field<'a>(value: &'a serde_json::Value, name: &str) -> crate::Result<&'a str> {
+    field(value, name)?
+        .as_str()
+        .ok_or_else(|| Error::mirror_node_rest(format!("expected `{name}` to be a string")))
+}
+
+pub(crate) fn u64_field(value: &serde_json::Value, name: &str) -> crate::Result<u64> {
+    field(value, name)?.as_u64().ok_or_else(|| {
+        Error::mirror_node_rest(format!("expected `{name}` to be a non-negative integer"))
+    })
+}
+
+pub(crate) fn tinybar_field(value: &serde_json::Value, name: &str) -> crate::Result<Tinybar> {
+    Tinybar::try_from(u64_field(value, name)?)
+        .map_err(|_| Error::mirror_node_rest(format!("expected `{name}` to fit in an i64")))
+}
+
+fn bool_field(value: &serde_json::Value, name: &str) -> crate::Result<bool> {
+    field(value, name)?
+        .as_bool()
+        .ok_or_else(|| Error::mirror_node_rest(format!("expected `{name}` to be a boolean")))
+}
+
+pub(crate) fn parse_field<T: FromStr<Err = Error>>(
+    value: &serde_json::Value,
+    name: &str,
+) -> crate::Result<T> {
+    str_field(value, name)?.parse()
+}
+
+/// Returns the string `name`, treating `null` the same as a missing field.
+fn opt_str<'a>(value: &'a serde_json::Value, name: &str) -> Option<&'a str> {
+    value.get(name).and_then(serde_json::Value::as_str)
+}
+
+fn opt_parse_field<T: FromStr<Err = Error>>(
+    value: &serde_json::Value,
+    name: &str,
+) -> crate::Result<Option<T>> {
+    opt_str(value, name).map(str::parse).transpose()
+}
+
+fn opt_i32_field(value: &serde_json::Value, name: &str) -> crate::Result<Option<i32>> {
+    match value.get(name) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(it) => {
+            it.as_i64().and_then(|it| i32::try_from(it).ok()).map(Some).ok_or_else(|| {
+                Error::mirror_node_rest(format!("expected `{name}` to fit in an i32"))
+            })
+        }
+    }
+}
+
+fn opt_seconds_field(value: &serde_json::Value, name: &str) -> crate::Result<Option<Duration>> {
+    match value.get(name) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(it) => it.as_i64().map(|it| Some(Duration::seconds(it))).ok_or_else(|| {
+            Error::mirror_node_rest(format!("expected `{name}` to be a number of seconds"))
+        }),
+    }
+}
+
+/// Parses a mirror node timestamp, which is a string of the form `seconds.nanoseconds`.
+fn opt_timestamp_field(
+    value: &serde_json::Value,
+    name: &str,
+) -> crate::Result<Option<OffsetDateTime>> {
+    let Some(timestamp) = opt_str(value, name) else {
+        return Ok(None);
+    };
+
+    let error = || Error::mirror_node_rest(format!("expected `{name}` to be a timestamp"));
+
+    let (seconds, nanos) = timestamp.split_once('.').unwrap_or((timestamp, "0"));
+
+    let seconds: i64 = seconds.parse().map_err(|_| error())?;
+    let nanos: i64 = match nanos.len() {
+        1..=9 => format!("{nanos:0<9}").parse().map_err(|_| error())?,
+        _ => return Err(error()),
+    };
+
+    OffsetDateTime::from_unix_timestamp(seconds)
+        .map(|it| Some(it + Duration::nanoseconds(nanos)))
+        .map_err(|_| error())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+    use tokio::io::{
+        AsyncReadExt,
+        AsyncWriteExt,
+    };
+    use tokio::net::TcpListener;
+    use tokio::task::JoinHandle;
+
+    use crate::{
+        AccountId,
+        Client,
+        ContractId,
+        Error,
+        FileId,
+        Hbar,
+        MirrorNodeGateway,
+        TokenId,
+    };
+
+    const ACCOUNT: &str = r#"{
+        "account": "0.0.5005",
+        "alias": null,
+        "auto_renew_period": 7776000,
+        "balance": {"balance": 1500, "timestamp": "1718822400.000000000", "tokens": []},
+        "deleted": false,
+        "evm_address": "0x000000000000000000000000000000000000138d",
+        "expiry_timestamp": "1718822400.500000000",
+        "key": {"_type": "ED25519", "key": "e0c8ec2758a5879ffac226a13c0c516b799e72e35141a0dd828f94d37988a4b7"},
+        "max_automatic_token_associations": -1,
+        "memo": "hello",
+        "receiver_sig_required": true,
+        "staked_node_id": null,
+        "links": {"next": null}
+    }"#;
+
+    /// A mock of the mirror node REST API, answering each request in turn with the next `(status, body)`.
+    ///
+    /// Resolves to the request line of every request it received.
+    async fn serve(
+        responses: Vec<(&'static str, &'static str)>,
+    ) -> (Client, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.windows(4).any(|it| it == b"\r\n\r\n") {
+                    let read = stream.read(&mut buf).await.unwrap();
+                    assert_ne!(read, 0);
+                    request.extend_from_slice(&buf[..read]);
+                }
+
+                let response = format!(
+                    "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+
+                let request = String::from_utf8(request).unwrap();
+                requests.push(request.lines().next().unwrap().to_owned());
+            }
+
+            requests
+        });
+
+        (Client::for_mirror_network([address.to_string()]), server)
+    }
+
+    #[tokio::test]
+    async fn account_info() -> crate::Result<()> {
+        let (client, server) = serve(vec![("200 OK", ACCOUNT)]).await;
+
+        let info = MirrorNodeGateway::new(&client).account_info(AccountId::new(0, 0, 5005)).await?;
+
+        assert_eq!(server.await.unwrap(), ["GET /api/v1/accounts/0.0.5005 HTTP/1.1"]);
+
+        assert_eq!(info.account_id, AccountId::new(0, 0, 5005));
+        assert_eq!(
+            info.evm_address,
+            Some("0x000000000000000000000000000000000000138d".parse().unwrap())
+        );
+        assert_eq!(info.balance, Hbar::from_tinybars(1500));
+        assert_eq!(info.memo, "hello");
+        assert!(!info.deleted);
+        assert!(info.receiver_signature_required);
+        assert_eq!(info.max_automatic_token_associations, -1);
+        assert_eq!(info.auto_renew_period, Some(Duration::days(90)));
+        assert_eq!(
+            info.expiration_time,
+            Some(
+                OffsetDateTime::from_unix_timestamp(1_718_822_400).unwrap()
+                    + Duration::milliseconds(500)
+            )
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn contract_info() -> crate::Result<()> {
+        const CONTRACT: &str = r#"{
+            "auto_renew_account": null,
+            "auto_renew_period": 7776000,
+            "contract_id": "0.0.10001",
+            "created_timestamp": "1718822400.000000001",
+            "deleted": false,
+            "evm_address": "0x0000000000000000000000000000000000002711",
+            "expiration_timestamp": null,
+            "file_id": "0.0.1000",
+            "max_automatic_token_associations": 0,
+            "memo": "cool contract"
+        }"#;
+
+        let (client, server) = serve(vec![("200 OK", CONTRACT)]).await;
+
+        let info =
+            MirrorNodeGateway::new(&client).contract_info(ContractId::new(0, 0, 10001)).await?;
+
+        assert_eq!(server.await.unwrap(), ["GET /api/v1/contracts/0.0.10001 HTTP/1.1"]);
+
+        assert_eq!(info.contract_id, ContractId::new(0, 0, 10001));
+        assert_eq!(info.memo, "cool contract");
+        assert_eq!(info.file_id, Some(FileId::new(0, 0, 1000)));
+        assert_eq!(info.auto_renew_account_id, None);
+        assert_eq!(info.expiration_time, None);
+        assert_eq!(
+            info.created_time,
+            Some(
+                OffsetDateTime::from_unix_timestamp(1_718_822_400).unwrap()
+                    + Duration::nanoseconds(1)
+            )
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tokens_for_account_follows_pages() -> crate::Result<()> {
+        const PAGE_1: &str = r#"{
+            "tokens": [{"automatic_association": true, "balance": 5, "created_timestamp": "1718822400.000000000", "decimals": 3, "token_id": "0.0.1001"}],
+            "links": {"next": "/api/v1/accounts/0.0.5005/tokens?limit=1&token.id=gt:0.0.1001"}
+        }"#;
+
+        const PAGE_2: &str = r#"{
+            "tokens": [{"automatic_association": false, "balance": 0, "created_timestamp": null, "decimals": 0, "token_id": "0.0.1002"}],
+            "links": {"next": null}
+        }"#;
+
+        let (client, server) = serve(vec![("200 OK", PAGE_1), ("200 OK", PAGE_2)]).await;
+
+        let tokens =
+            MirrorNodeGateway::new(&client).tokens_for_account(AccountId::new(0, 0, 5005)).await?;
+
+        assert_eq!(
+            server.await.unwrap(),
+            [
+                "GET /api/v1/accounts/0.0.5005/tokens HTTP/1.1",
+                "GET /api/v1/accounts/0.0.5005/tokens?limit=1&token.id=gt:0.0.1001 HTTP/1.1",
+            ]
+        );
+
+        assert_eq!(tokens.len(), 2);
+
+        assert_eq!(tokens[0].token_id, TokenId::new(0, 0, 1001));
+        assert_eq!(tokens[0].balance, 5);
+        assert_eq!(tokens[0].decimals, 3);
+        assert!(tokens[0].automatic_association);
+
+        assert_eq!(tokens[1].token_id, TokenId::new(0, 0, 1002));
+        assert_eq!(tokens[1].created_time, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn http_errors() {
+        let (client, server) = serve(vec![
+            ("404 Not Found", r#"{"_status":{"messages":[{"message":"Not found"}]}}"#),
+            ("500 Internal Server Error", "{}"),
+        ])
+        .await;
+
+        let gateway = MirrorNodeGateway::new(&client);
+
+        let path = assert_matches!(
+            gateway.account_info(AccountId::new(0, 0, 404)).await,
+            Err(Error::MirrorNodeNotFound { path }) => path
+        );
+        assert_eq!(path, "/accounts/0.0.404");
+
+        assert_matches!(
+            gateway.account_info(AccountId::new(0, 0, 500)).await,
+            Err(Error::MirrorNodeRest(_))
+        );
+
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn balance_out_of_range() {
+        const ACCOUNT: &str = r#"{
+            "account": "0.0.5005",
+            "balance": {"balance": 9223372036854775808, "timestamp": "1718822400.000000000", "tokens": []},
+            "deleted": false,
+            "receiver_sig_required": false
+        }"#;
+
+        let (client, server) = serve(vec![("200 OK", ACCOUNT)]).await;
+
+        assert_matches!(
+            MirrorNodeGateway::new(&client).account_info(AccountId::new(0, 0, 5005)).await,
+            Err(Error::MirrorNodeRest(_))
+        );
+
+        server.await.unwrap();
+    }
+}
@@ -278,7 +278,7 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
                     } else {
                         // maximum time allowed has elapsed
                         // NOTE: it should be impossible to reach here without capturing at least one error
-                        yield Err(Error::TimedOut(Error::from(status).into()));
+                        yield Err(Error::TimedOut { submitted: false, source: Error::from(status).into() });
                         return;
                     }
                 }
@@ -0,0 +1,310 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Mock gRPC services for tests, each answering a single method.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use std::task::{
+    Context,
+    Poll,
+};
+
+use hedera_proto::services;
+use tonic::codegen::{
+    http,
+    Body,
+    BoxFuture,
+    Service,
+    StdError,
+};
+use tonic::server::NamedService;
+use triomphe::Arc;
+
+use crate::{
+    AccountId,
+    Client,
+    ToProtobuf,
+};
+
+/// A mock of a single unary gRPC method, serve it by wrapping it in [`Unary`].
+pub(crate) trait MockUnary: Clone + Send + 'static {
+    /// The fully qualified name of the service, such as `proto.CryptoService`.
+    const SERVICE: &'static str;
+
+    /// The name of the method, such as `cryptoGetBalance`.
+    const METHOD: &'static str;
+
+    type Request: prost::Message + Default + 'static;
+    type Response: prost::Message + 'static;
+
+    fn call(&self, request: Self::Request) -> BoxFuture<Self::Response, tonic::Status>;
+}
+
+/// A mock of a single server streaming gRPC method, serve it by wrapping it in [`ServerStreaming`].
+pub(crate) trait MockServerStreaming: Clone + Send + 'static {
+    /// The fully qualified name of the service, such as `com.hedera.mirror.api.proto.ConsensusService`.
+    const SERVICE: &'static str;
+
+    /// The name of the method, such as `subscribeTopic`.
+    const METHOD: &'static str;
+
+    type Request: prost::Message + Default + 'static;
+    type Response: prost::Message + 'static;
+
+    /// Returns the messages to stream back, the stream ends early at the first `Err`.
+    fn call(&self, request: Self::Request) -> Vec<Result<Self::Response, tonic::Status>>;
+}
+
+/// A gRPC service that only knows the method of `M`.
+#[derive(Clone)]
+pub(crate) struct Unary<M>(pub(crate) M);
+
+/// A gRPC service that only knows the method of `M`.
+#[derive(Clone)]
+pub(crate) struct ServerStreaming<M>(pub(crate) M);
+
+impl<M: MockUnary> NamedService for Unary<M> {
+    const NAME: &'static str = M::SERVICE;
+}
+
+impl<M: MockServerStreaming> NamedService for ServerStreaming<M> {
+    const NAME: &'static str = M::SERVICE;
+}
+
+impl<M, B> Service<http::Request<B>> for Unary<M>
+where
+    M: MockUnary,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        assert_eq!(request.uri().path(), format!("/{}/{}", M::SERVICE, M::METHOD));
+
+        let handler = UnaryHandler(self.0.clone());
+
+        Box::pin(async move {
+            Ok(tonic::server::Grpc::new(tonic::codec::ProstCodec::default())
+                .unary(handler, request)
+                .await)
+        })
+    }
+}
+
+impl<M, B> Service<http::Request<B>> for ServerStreaming<M>
+where
+    M: MockServerStreaming,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        assert_eq!(request.uri().path(), format!("/{}/{}", M::SERVICE, M::METHOD));
+
+        let handler = ServerStreamingHandler(self.0.clone());
+
+        Box::pin(async move {
+            Ok(tonic::server::Grpc::new(tonic::codec::ProstCodec::default())
+                .server_streaming(handler, request)
+                .await)
+        })
+    }
+}
+
+struct UnaryHandler<M>(M);
+
+impl<M: MockUnary> tonic::server::UnaryService<M::Request> for UnaryHandler<M> {
+    type Response = M::Response;
+    type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+
+    fn call(&mut self, request: tonic::Request<M::Request>) -> Self::Future {
+        let response = self.0.call(request.into_inner());
+
+        Box::pin(async move { response.await.map(tonic::Response::new) })
+    }
+}
+
+struct ServerStreamingHandler<M>(M);
+
+impl<M: MockServerStreaming> tonic::server::ServerStreamingService<M::Request>
+    for ServerStreamingHandler<M>
+{
+    type Response = M::Response;
+    type ResponseStream =
+        futures_util::stream::Iter<std::vec::IntoIter<Result<Self::Response, tonic::Status>>>;
+    type Future = std::future::Ready<Result<tonic::Response<Self::ResponseStream>, tonic::Status>>;
+
+    fn call(&mut self, request: tonic::Request<M::Request>) -> Self::Future {
+        let responses = self.0.call(request.into_inner());
+
+        std::future::ready(Ok(tonic::Response::new(futures_util::stream::iter(responses))))
+    }
+}
+
+/// Serves `service` on a random local port, returning its address.
+pub(crate) async fn serve<S>(service: S) -> String
+where
+    S: Service<
+            http::Request<tonic::transport::Body>,
+            Response = http::Response<tonic::body::BoxBody>,
+            Error = Infallible,
+        > + NamedService
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    serve_counting(service, Arc::default()).await
+}
+
+/// Like [`serve`], but counts every accepted connection in `connections`.
+pub(crate) async fn serve_counting<S>(service: S, connections: Arc<AtomicUsize>) -> String
+where
+    S: Service<
+            http::Request<tonic::transport::Body>,
+            Response = http::Response<tonic::body::BoxBody>,
+            Error = Infallible,
+        > + NamedService
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let incoming = async_stream::stream! {
+        loop {
+            let accepted = listener.accept().await;
+            connections.fetch_add(1, Ordering::Relaxed);
+            yield accepted.map(|(stream, _)| stream);
+        }
+    };
+
+    tokio::spawn(
+        tonic::transport::Server::builder().add_service(service).serve_with_incoming(incoming),
+    );
+
+    format!("127.0.0.1:{port}")
+}
+
+/// Returns a client whose only node (`0.0.3`) is at `address`.
+pub(crate) fn client_for(address: String) -> Client {
+    Client::for_network(HashMap::from([(address, 3.into())])).unwrap()
+}
+
+/// A `CryptoService` that only knows `cryptoGetBalance`, answering it with `code`.
+///
+/// When `code` is `Ok`, the response's pre-check status is `precheck`.
+#[derive(Clone)]
+pub(crate) struct MockCryptoService {
+    code: tonic::Code,
+    precheck: services::ResponseCodeEnum,
+    // every request the service has received, shared between clones.
+    pub(crate) requests: Arc<AtomicUsize>,
+    // the number of requests answered with `UNAVAILABLE` before `code` is used.
+    unavailable_requests: usize,
+}
+
+impl MockCryptoService {
+    pub(crate) fn new(code: tonic::Code) -> Self {
+        Self {
+            code,
+            precheck: services::ResponseCodeEnum::Ok,
+            requests: Arc::default(),
+            unavailable_requests: 0,
+        }
+    }
+
+    /// Answers the first `count` requests with `UNAVAILABLE`, across `self` and all of its clones.
+    pub(crate) fn unavailable_for(self, count: usize) -> Self {
+        Self { unavailable_requests: count, ..self }
+    }
+
+    /// A service that always responds with a pre-check status of `BUSY`.
+    pub(crate) fn busy() -> Self {
+        Self { precheck: services::ResponseCodeEnum::Busy, ..Self::new(tonic::Code::Ok) }
+    }
+
+    /// Serves `self` on a random local port, returning its address.
+    pub(crate) async fn serve(self) -> String {
+        serve(Unary(self)).await
+    }
+
+    /// Like [`serve`](Self::serve), but counts every accepted connection in `connections`.
+    pub(crate) async fn serve_counting(self, connections: Arc<AtomicUsize>) -> String {
+        serve_counting(Unary(self), connections).await
+    }
+}
+
+impl MockUnary for MockCryptoService {
+    const SERVICE: &'static str = "proto.CryptoService";
+    const METHOD: &'static str = "cryptoGetBalance";
+
+    type Request = services::Query;
+    type Response = services::Response;
+
+    fn call(&self, _request: services::Query) -> BoxFuture<services::Response, tonic::Status> {
+        let request = self.requests.fetch_add(1, Ordering::Relaxed);
+
+        let code = match request < self.unavailable_requests {
+            true => tonic::Code::Unavailable,
+            false => self.code,
+        };
+
+        let response = match code {
+            tonic::Code::Ok => Ok(services::Response {
+                response: Some(services::response::Response::CryptogetAccountBalance(
+                    services::CryptoGetAccountBalanceResponse {
+                        header: Some(services::ResponseHeader {
+                            node_transaction_precheck_code: self.precheck as i32,
+                            ..Default::default()
+                        }),
+                        account_id: Some(AccountId::new(0, 0, 1001).to_protobuf()),
+                        balance: 10,
+                        ..Default::default()
+                    },
+                )),
+            }),
+            code => Err(tonic::Status::new(code, "mock")),
+        };
+
+        Box::pin(std::future::ready(response))
+    }
+}
@@ -85,10 +85,14 @@ impl fmt::Display for PendingAirdropId {
 }
 
 impl PendingAirdropId {
+    /// Create a new `PendingAirdropId` for an airdrop of the NFT `nft_id`.
+    #[must_use]
     pub const fn new_nft_id(sender_id: AccountId, receiver_id: AccountId, nft_id: NftId) -> Self {
         Self { sender_id, receiver_id, token_id: None, nft_id: Some(nft_id) }
     }
 
+    /// Create a new `PendingAirdropId` for an airdrop of the fungible token `token_id`.
+    #[must_use]
     pub const fn new_token_id(
         sender_id: AccountId,
         receiver_id: AccountId,
@@ -115,8 +119,8 @@ impl PendingAirdropId {
 
 impl ValidateChecksums for PendingAirdropId {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
-        let _ = self.sender_id.validate_checksums(ledger_id);
-        let _ = self.receiver_id.validate_checksums(ledger_id);
+        self.sender_id.validate_checksums(ledger_id)?;
+        self.receiver_id.validate_checksums(ledger_id)?;
 
         if let Some(token_id) = self.token_id {
             token_id.validate_checksums(ledger_id)?
@@ -183,3 +187,56 @@ impl ToProtobuf for PendingAirdropId {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::ledger_id::RefLedgerId;
+    use crate::{
+        AccountId,
+        Error,
+        PendingAirdropId,
+        TokenId,
+        ValidateChecksums,
+    };
+
+    #[test]
+    fn to_from_bytes_token_id() {
+        let id = PendingAirdropId::new_token_id(
+            AccountId::new(0, 0, 5005),
+            AccountId::new(0, 0, 5006),
+            TokenId::new(0, 0, 312),
+        );
+
+        assert_eq!(PendingAirdropId::from_bytes(&id.to_bytes()).unwrap(), id);
+    }
+
+    #[test]
+    fn to_from_bytes_nft_id() {
+        let id = PendingAirdropId::new_nft_id(
+            AccountId::new(0, 0, 5005),
+            AccountId::new(0, 0, 5006),
+            TokenId::new(0, 0, 312).nft(2),
+        );
+
+        assert_eq!(PendingAirdropId::from_bytes(&id.to_bytes()).unwrap(), id);
+    }
+
+    #[test]
+    fn validate_checksums_covers_accounts() {
+        // a testnet checksum, which is wrong for mainnet.
+        let bad: AccountId = "0.0.123-esxsf".parse().unwrap();
+        let token_id = TokenId::new(0, 0, 312);
+
+        for id in [
+            PendingAirdropId::new_token_id(bad, AccountId::new(0, 0, 5006), token_id),
+            PendingAirdropId::new_token_id(AccountId::new(0, 0, 5005), bad, token_id),
+        ] {
+            assert_matches!(
+                id.validate_checksums(RefLedgerId::MAINNET),
+                Err(Error::BadEntityId { num: 123, .. })
+            );
+        }
+    }
+}
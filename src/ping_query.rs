@@ -122,7 +122,7 @@ impl Execute for PingQuery {
 
     fn make_error_pre_check(
         &self,
-        status: hedera_proto::services::ResponseCodeEnum,
+        status: crate::Status,
         _transaction_id: Option<&crate::TransactionId>,
         _response: Self::GrpcResponse,
     ) -> crate::Error {
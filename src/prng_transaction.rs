@@ -29,6 +29,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -101,7 +102,7 @@ impl ToTransactionDataProtobuf for PrngTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::UtilPrng(self.to_protobuf())
     }
@@ -115,6 +116,10 @@ impl TransactionExecute for PrngTransactionData {
     ) -> crate::BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { UtilServiceClient::new(channel).prng(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Util
+    }
 }
 
 #[cfg(test)]
@@ -19,7 +19,9 @@
  */
 
 use std::fmt::Debug;
+use std::time::Duration;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
 
@@ -32,6 +34,7 @@ use crate::query::{
 use crate::{
     AccountId,
     BoxGrpcFuture,
+    Client,
     Error,
     FromProtobuf,
     Hbar,
@@ -86,6 +89,18 @@ pub trait QueryExecute:
         channel: Channel,
         request: services::Query,
     ) -> BoxGrpcFuture<'_, services::Response>;
+
+    /// Execute this query against the mirror node REST API instead of consensus nodes.
+    ///
+    /// Returns `None` if the query can't be (or shouldn't be) answered by the mirror node.
+    #[allow(unused_variables)]
+    fn execute_mirror<'a>(
+        &'a self,
+        client: &'a Client,
+        timeout: Option<Duration>,
+    ) -> Option<BoxFuture<'a, crate::Result<Self::Response>>> {
+        None
+    }
 }
 
 impl<D> Execute for Query<D>
@@ -113,7 +128,7 @@ where
     }
 
     fn operator_account_id(&self) -> Option<&AccountId> {
-        self.payment.operator_account_id()
+        self.payment.get_payer_account_id().or_else(|| self.payment.operator_account_id())
     }
 
     fn should_retry_pre_check(&self, status: Status) -> bool {
@@ -29,6 +29,8 @@ use crate::{
     Client,
     Error,
     Hbar,
+    PrivateKey,
+    PublicKey,
     TransactionId,
     TransactionReceiptQuery,
 };
@@ -38,6 +40,8 @@ mod cost;
 mod execute;
 pub(super) mod payment_transaction;
 mod protobuf;
+#[cfg(test)]
+mod tests;
 
 pub(crate) use any::AnyQueryData;
 pub use any::{
@@ -183,7 +187,7 @@ where
     /// .
     #[must_use]
     pub fn get_payment_transaction_id(&self) -> Option<TransactionId> {
-        self.payment.get_transaction_id()
+        self.payment.body.transaction_id
     }
 
     /// Sets an explicit transaction ID to use to identify the payment transaction
@@ -195,6 +199,42 @@ where
         self
     }
 
+    /// Returns the account that will pay for this query, if explicitly set.
+    #[must_use]
+    pub fn get_payment_account_id(&self) -> Option<AccountId> {
+        self.payment.get_payer_account_id().copied()
+    }
+
+    /// Sets the account that will pay for this query.
+    ///
+    /// Payment transaction IDs will be generated from this account rather than the client's operator,
+    /// which allows paid queries to be executed on a client without an operator,
+    /// as long as the payment is signed with [`sign_payment`](Self::sign_payment) or [`sign_payment_with`](Self::sign_payment_with).
+    pub fn payment_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.payment.payer_account_id(id.into());
+        self
+    }
+
+    /// Sign the payment transaction for this query.
+    ///
+    /// This is only required if the payer of this query isn't the client's operator.
+    pub fn sign_payment(&mut self, private_key: PrivateKey) -> &mut Self {
+        self.payment.sign(private_key);
+        self
+    }
+
+    /// Sign the payment transaction for this query with the given signer.
+    ///
+    /// This is only required if the payer of this query isn't the client's operator.
+    pub fn sign_payment_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.payment.sign_with(public_key, signer);
+        self
+    }
+
     /// Fetch the cost of this query.
     pub async fn get_cost(&self, client: &Client) -> crate::Result<Hbar> {
         self.get_cost_with_optional_timeout(client, None).await
@@ -252,6 +292,10 @@ where
             })
         }
 
+        if let Some(response) = self.data.execute_mirror(client, timeout) {
+            return response.await;
+        }
+
         // hack: this is a TransactionRecordQuery, which means we need to run the receipt first.
         if let Some(transaction_id) = self.data.transaction_id() {
             if self.data.is_payment_required() {
@@ -283,7 +327,16 @@ where
         }
 
         if self.data.is_payment_required() {
+            if let Some(amount) = self.payment.get_amount() {
+                tracing::debug!(query = std::any::type_name::<D>(), payment = %amount, "paying for query");
+            }
+
             self.payment.freeze_with(client)?;
+
+            // the payment is signed for every attempt, which can't wait on an async signer.
+            if self.payment.has_async_signer() {
+                return Err(Error::AsyncSignerRequiresExecute);
+            }
         }
 
         execute(client, self, timeout).await
@@ -25,11 +25,13 @@ use tonic::transport::Channel;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
 };
 use crate::{
+    AccountId,
     BoxGrpcFuture,
     Error,
     Hbar,
@@ -44,6 +46,7 @@ pub type PaymentTransaction = Transaction<PaymentTransactionData>;
 pub struct PaymentTransactionData {
     amount: Option<Hbar>,
     max_amount: Option<Hbar>,
+    payer_account_id: Option<AccountId>,
 }
 
 impl PaymentTransaction {
@@ -64,6 +67,15 @@ impl PaymentTransaction {
         self.data_mut().max_amount = amount.into();
         self
     }
+
+    pub(super) fn get_payer_account_id(&self) -> Option<&AccountId> {
+        self.data().payer_account_id.as_ref()
+    }
+
+    pub(super) fn payer_account_id(&mut self, id: AccountId) -> &mut Self {
+        self.data_mut().payer_account_id = Some(id);
+        self
+    }
 }
 
 impl TransactionData for PaymentTransactionData {}
@@ -77,11 +89,15 @@ impl TransactionExecute for PaymentTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { CryptoServiceClient::new(channel).crypto_transfer(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Crypto
+    }
 }
 
 impl ValidateChecksums for PaymentTransactionData {
-    fn validate_checksums(&self, _ledger_id: &crate::ledger_id::RefLedgerId) -> Result<(), Error> {
-        Ok(())
+    fn validate_checksums(&self, ledger_id: &crate::ledger_id::RefLedgerId) -> Result<(), Error> {
+        self.payer_account_id.validate_checksums(ledger_id)
     }
 }
 
@@ -91,7 +107,7 @@ impl ToTransactionDataProtobuf for PaymentTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let (transaction_id, node_account_id) = chunk_info.assert_single_transaction();
+        let (transaction_id, node_account_id) = chunk_info.assert_single_transaction::<Self>();
 
         let amount = self.amount.unwrap_or_default();
 
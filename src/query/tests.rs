@@ -0,0 +1,287 @@
+use assert_matches::assert_matches;
+use hedera_proto::services;
+use parking_lot::Mutex;
+use prost::Message;
+use tonic::codegen::BoxFuture;
+use triomphe::Arc;
+
+use crate::execute::Execute;
+use crate::mock::{
+    self,
+    MockUnary,
+    Unary,
+};
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::{
+    AccountId,
+    AccountInfoQuery,
+    Client,
+    Error,
+    Hbar,
+    PrivateKey,
+    TransactionId,
+};
+
+#[tokio::test]
+async fn paid_query_without_operator() -> crate::Result<()> {
+    let client = Client::for_testnet();
+
+    let payer_key = PrivateKey::generate_ed25519();
+    let payer = AccountId::new(0, 0, 1234);
+    let node = AccountId::new(0, 0, 3);
+
+    let mut query = AccountInfoQuery::new();
+    query
+        .account_id(AccountId::new(0, 0, 5005))
+        .node_account_ids([node])
+        .payment_amount(Hbar::from_tinybars(100))
+        .payment_account_id(payer)
+        .sign_payment(payer_key.clone());
+
+    assert_eq!(query.get_payment_account_id(), Some(payer));
+
+    // with no operator on the client, the payer is used to generate transaction IDs.
+    assert_eq!(Execute::operator_account_id(&query), Some(&payer));
+
+    query.payment.freeze_with(&client)?;
+
+    let transaction_id = TransactionId::generate(payer);
+
+    let (request, ()) = Execute::make_request(&query, Some(&transaction_id), node)?;
+
+    let payment = assert_matches!(
+        request.query,
+        Some(services::query::Query::CryptoGetInfo(it)) => it.header.unwrap().payment.unwrap()
+    );
+
+    let signed = services::SignedTransaction::decode(&*payment.signed_transaction_bytes).unwrap();
+
+    let sig_pairs = signed.sig_map.unwrap().sig_pair;
+
+    assert_eq!(sig_pairs.len(), 1);
+    assert_eq!(sig_pairs[0].pub_key_prefix, payer_key.public_key().to_bytes_raw());
+
+    let body = services::TransactionBody::decode(&*signed.body_bytes).unwrap();
+
+    assert_eq!(body.transaction_id, Some(transaction_id.to_protobuf()));
+    assert_eq!(body.node_account_id, Some(node.to_protobuf()));
+
+    Ok(())
+}
+
+#[test]
+fn pinned_node_and_payment_transaction_id() -> crate::Result<()> {
+    let client = Client::for_testnet();
+    client.set_operator(AccountId::new(0, 0, 1001), PrivateKey::generate_ed25519());
+
+    let node = AccountId::new(0, 0, 5);
+    let payment_transaction_id = TransactionId::generate(AccountId::new(0, 0, 1234));
+
+    let mut query = AccountInfoQuery::new();
+    query
+        .account_id(AccountId::new(0, 0, 5005))
+        .node_account_ids([node])
+        .payment_amount(Hbar::from_tinybars(100))
+        .payment_transaction_id(payment_transaction_id);
+
+    assert_eq!(Execute::node_account_ids(&query), Some([node].as_slice()));
+    assert_eq!(Execute::transaction_id(&query), Some(payment_transaction_id));
+
+    query.payment.freeze_with(&client)?;
+
+    let (request, ()) =
+        Execute::make_request(&query, Execute::transaction_id(&query).as_ref(), node)?;
+
+    let payment = assert_matches!(
+        request.query,
+        Some(services::query::Query::CryptoGetInfo(it)) => it.header.unwrap().payment.unwrap()
+    );
+
+    let signed = services::SignedTransaction::decode(&*payment.signed_transaction_bytes).unwrap();
+    let body = services::TransactionBody::decode(&*signed.body_bytes).unwrap();
+
+    assert_eq!(body.transaction_id, Some(payment_transaction_id.to_protobuf()));
+    assert_eq!(body.node_account_id, Some(node.to_protobuf()));
+
+    // the payment goes to the pinned node.
+    let transfers = assert_matches!(
+        body.data,
+        Some(services::transaction_body::Data::CryptoTransfer(it)) => it.transfers.unwrap().account_amounts
+    );
+
+    assert!(transfers
+        .iter()
+        .any(|it| it.account_id == Some(node.to_protobuf()) && it.amount == 100));
+
+    Ok(())
+}
+
+/// A `CryptoService` that only knows `getAccountInfo`, reporting that it costs `cost`.
+///
+/// Queries for the answer get an (almost) empty info for the queried account.
+#[derive(Clone)]
+struct MockCostService {
+    cost: Hbar,
+    // the payment of every query for the answer.
+    payments: Arc<Mutex<Vec<services::Transaction>>>,
+}
+
+impl MockCostService {
+    fn new(cost: Hbar) -> Self {
+        Self { cost, payments: Arc::default() }
+    }
+
+    /// Serves `self` on a random local port, returning a client for it (node `0.0.3`).
+    async fn serve(self) -> Client {
+        mock::client_for(mock::serve(Unary(self)).await)
+    }
+}
+
+impl MockUnary for MockCostService {
+    const SERVICE: &'static str = "proto.CryptoService";
+    const METHOD: &'static str = "getAccountInfo";
+
+    type Request = services::Query;
+    type Response = services::Response;
+
+    fn call(&self, request: services::Query) -> BoxFuture<services::Response, tonic::Status> {
+        let query = assert_matches!(
+            request.query,
+            Some(services::query::Query::CryptoGetInfo(it)) => it
+        );
+
+        let header = query.header.unwrap_or_default();
+
+        let response = match header.response_type() {
+            services::ResponseType::CostAnswer => services::CryptoGetInfoResponse {
+                header: Some(services::ResponseHeader {
+                    response_type: services::ResponseType::CostAnswer as i32,
+                    cost: self.cost.to_tinybars() as u64,
+                    ..Default::default()
+                }),
+                account_info: None,
+            },
+
+            _ => {
+                self.payments.lock().extend(header.payment);
+
+                services::CryptoGetInfoResponse {
+                    header: Some(services::ResponseHeader::default()),
+                    account_info: Some(services::crypto_get_info_response::AccountInfo {
+                        account_id: query.account_id,
+                        key: Some(services::Key {
+                            key: Some(services::key::Key::KeyList(services::KeyList::default())),
+                        }),
+                        ..Default::default()
+                    }),
+                }
+            }
+        };
+
+        Box::pin(std::future::ready(Ok(services::Response {
+            response: Some(services::response::Response::CryptoGetInfo(response)),
+        })))
+    }
+}
+
+#[tokio::test]
+async fn cost_exceeds_default_max_query_payment() {
+    let client = MockCostService::new(Hbar::new(2)).serve().await;
+    client.set_operator(AccountId::new(0, 0, 1001), PrivateKey::generate_ed25519());
+
+    let info_query = || {
+        let mut query = AccountInfoQuery::new();
+        query.account_id(AccountId::new(0, 0, 5005)).node_account_ids([3.into()]);
+        query
+    };
+
+    // neither the query nor the client set a maximum.
+    assert_eq!(client.default_max_query_payment(), Some(Hbar::new(1)));
+
+    assert_matches!(
+        info_query().execute(&client).await,
+        Err(Error::MaxQueryPaymentExceeded { query_cost, max_query_payment })
+            if query_cost == Hbar::new(2) && max_query_payment == Hbar::new(1)
+    );
+
+    client.set_default_max_query_payment(Hbar::from_tinybars(150_000_000));
+
+    assert_matches!(
+        info_query().execute(&client).await,
+        Err(Error::MaxQueryPaymentExceeded { max_query_payment, .. })
+            if max_query_payment == Hbar::from_tinybars(150_000_000)
+    );
+
+    // the query's own maximum takes priority.
+    assert_matches!(
+        info_query().max_payment_amount(Hbar::new(1)).execute(&client).await,
+        Err(Error::MaxQueryPaymentExceeded { max_query_payment, .. })
+            if max_query_payment == Hbar::new(1)
+    );
+}
+
+#[tokio::test]
+async fn paid_query_pays_cost() -> crate::Result<()> {
+    let service = MockCostService::new(Hbar::from_tinybars(100));
+    let client = service.clone().serve().await;
+
+    let payer_key = PrivateKey::generate_ed25519();
+    let payer = AccountId::new(0, 0, 1234);
+    let node = AccountId::new(0, 0, 3);
+
+    let info = AccountInfoQuery::new()
+        .account_id(AccountId::new(0, 0, 5005))
+        .node_account_ids([node])
+        .payment_account_id(payer)
+        .sign_payment(payer_key.clone())
+        .execute(&client)
+        .await?;
+
+    assert_eq!(info.account_id, AccountId::new(0, 0, 5005));
+
+    let payments = service.payments.lock();
+
+    assert_eq!(payments.len(), 1);
+
+    let signed =
+        services::SignedTransaction::decode(&*payments[0].signed_transaction_bytes).unwrap();
+
+    let sig_pairs = signed.sig_map.unwrap().sig_pair;
+
+    assert_eq!(sig_pairs.len(), 1);
+    assert_eq!(sig_pairs[0].pub_key_prefix, payer_key.public_key().to_bytes_raw());
+
+    let body = services::TransactionBody::decode(&*signed.body_bytes).unwrap();
+
+    let transaction_id = TransactionId::from_protobuf(body.transaction_id.unwrap())?;
+
+    assert_eq!(transaction_id.account_id, payer);
+    assert_eq!(body.node_account_id, Some(node.to_protobuf()));
+
+    // the payer pays the node exactly the cost.
+    let transfers = assert_matches!(
+        body.data,
+        Some(services::transaction_body::Data::CryptoTransfer(it)) => it.transfers.unwrap().account_amounts
+    );
+
+    assert_eq!(
+        transfers,
+        [
+            services::AccountAmount {
+                account_id: Some(node.to_protobuf()),
+                amount: 100,
+                is_approval: false,
+            },
+            services::AccountAmount {
+                account_id: Some(payer.to_protobuf()),
+                amount: -100,
+                is_approval: false,
+            },
+        ]
+    );
+
+    Ok(())
+}
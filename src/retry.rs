@@ -1,5 +1,11 @@
+use std::time::Duration;
+
 use futures_core::Future;
-use tokio::time::sleep;
+use tokio::time::{
+    sleep,
+    timeout_at,
+    Instant,
+};
 
 #[derive(Debug)]
 pub(crate) enum Error {
@@ -26,9 +32,12 @@ impl Error {
 pub(crate) type Result<T> = std::result::Result<T, Error>;
 
 /// Durably retry some function according to the `backoff` until the backoff expires.
+///
+/// If there's a `timeout`, it bounds the *total* time spent, including any attempt that's in progress.
 pub(crate) async fn retry<B, Fn, O, Fut>(
     mut backoff: B,
     max_attempts: Option<usize>,
+    timeout: Option<Duration>,
     mut f: Fn,
 ) -> crate::Result<O>
 where
@@ -36,33 +45,78 @@ where
     Fn: FnMut() -> Fut + Send,
     Fut: Future<Output = Result<O>> + Send,
 {
+    let deadline = timeout.map(|it| Instant::now() + it);
     let mut last_error: Option<crate::Error> = None;
     let mut attempt_number = 0;
+    // every way out of the loop other than running out of attempts is a timeout.
+    let mut timed_out = true;
+
+    loop {
+        if max_attempts.map_or(false, |it| attempt_number >= it) {
+            timed_out = false;
+            break;
+        }
+
+        if deadline.map_or(false, |it| Instant::now() >= it) {
+            break;
+        }
 
-    while max_attempts.map_or(true, |it| attempt_number < it) {
         attempt_number += 1;
 
-        match f().await {
+        let result = match deadline {
+            Some(deadline) => match timeout_at(deadline, f()).await {
+                Ok(it) => it,
+                Err(_) => break,
+            },
+            None => f().await,
+        };
+
+        match result {
             Ok(it) => return Ok(it),
             Err(Error::Transient(e)) => last_error = Some(e),
             Err(Error::EmptyTransient) => {}
             Err(Error::Permanent(e)) => return Err(e),
         }
 
-        if let Some(duration) = backoff.next_backoff() {
-            let duration_ms = duration.as_millis();
-            let err_suffix =
-                last_error.as_ref().map(|l| format!(" due to {l:?}")).unwrap_or_default();
-
-            log::warn!("Backing off for {duration_ms}ms after failure of attempt {attempt_number}{err_suffix}");
-            sleep(duration).await;
-            log::warn!("Backed off for {duration_ms}ms after failure of attempt {attempt_number}{err_suffix}");
-        } else {
-            let last_error = last_error.expect("timeout while network had no healthy nodes");
-            return Err(crate::Error::TimedOut(last_error.into()));
+        // there's no point in backing off if there won't be another attempt.
+        if max_attempts.map_or(false, |it| attempt_number >= it) {
+            timed_out = false;
+            break;
         }
+
+        let Some(mut duration) = backoff.next_backoff() else {
+            break;
+        };
+
+        // don't sleep past the deadline, there won't be another attempt after it anyway.
+        if let Some(deadline) = deadline {
+            duration = duration.min(deadline.saturating_duration_since(Instant::now()));
+        }
+
+        tracing::warn!(
+            retry_attempt = attempt_number,
+            backoff_ms = duration.as_millis(),
+            error = ?last_error,
+            "backing off"
+        );
+
+        sleep(duration).await;
     }
 
-    let last_error = last_error.expect("timeout while network had no healthy nodes");
-    Err(crate::Error::TimedOut(last_error.into()))
+    // no node ever responded (they were all unhealthy, or the first attempt didn't finish in time).
+    let last_error = last_error.unwrap_or_else(|| {
+        crate::Error::GrpcStatus(tonic::Status::deadline_exceeded(
+            "no node responded before the request timed out",
+        ))
+    });
+
+    match timed_out {
+        // whether anything was submitted is up to the caller, `retry` only knows the attempts failed.
+        true => Err(crate::Error::TimedOut { submitted: false, source: last_error.into() }),
+        false => Err(crate::Error::MaxAttemptsExceeded {
+            attempts: attempt_number,
+            submitted: false,
+            source: last_error.into(),
+        }),
+    }
 }
@@ -31,6 +31,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -42,6 +43,7 @@ use crate::{
     Error,
     Key,
     Transaction,
+    TransactionId,
     ValidateChecksums,
 };
 
@@ -78,14 +80,35 @@ impl ScheduleCreateTransaction {
 
     /// Sets the scheduled transaction.
     ///
-    /// # Panics
-    /// panics if the transaction is not schedulable, a transaction can be non-schedulable due to:
-    /// - being a transaction kind that's non-schedulable, IE, `EthereumTransaction`, or
-    /// - being a chunked transaction with multiple chunks.
-    pub fn scheduled_transaction<D>(&mut self, transaction: Transaction<D>) -> &mut Self
+    /// # Errors
+    /// - [`Error::InvalidTransaction`] if `transaction` isn't schedulable, a transaction can be non-schedulable due to:
+    ///   - `transaction` being frozen,
+    ///   - `transaction` having node account IDs set (scheduled transactions can't specify nodes),
+    ///   - being a transaction kind that's non-schedulable, IE, `EthereumTransaction`, or
+    ///   - being a chunked transaction with multiple chunks.
+    pub fn scheduled_transaction<D>(
+        &mut self,
+        transaction: Transaction<D>,
+    ) -> crate::Result<&mut Self>
     where
         D: TransactionExecute,
     {
+        if transaction.is_frozen() {
+            return Err(Error::invalid_transaction("a frozen transaction can't be scheduled"));
+        }
+
+        if transaction.get_node_account_ids().is_some() {
+            return Err(Error::invalid_transaction(
+                "a scheduled transaction can't have node account IDs set",
+            ));
+        }
+
+        if transaction.data().maybe_chunk_data().is_some_and(|it| it.used_chunks() > 1) {
+            return Err(Error::invalid_transaction(
+                "a transaction with multiple chunks can't be scheduled",
+            ));
+        }
+
         let body = transaction.into_body();
 
         // this gets infered right but `foo.into().try_into()` looks really really weird.
@@ -94,10 +117,10 @@ impl ScheduleCreateTransaction {
         self.data_mut().scheduled_transaction = Some(SchedulableTransactionBody {
             max_transaction_fee: body.max_transaction_fee,
             transaction_memo: body.transaction_memo,
-            data: Box::new(data.try_into().unwrap()),
+            data: Box::new(data.try_into().map_err(Error::invalid_transaction)?),
         });
 
-        self
+        Ok(self)
     }
 
     /// Returns the timestamp for when the transaction should be evaluated for execution and then expire.
@@ -135,8 +158,8 @@ impl ScheduleCreateTransaction {
 
     /// Sets the id of the account to be charged the service fee for the scheduled transaction at
     /// the consensus time that it executes (if ever).
-    pub fn payer_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().payer_account_id = Some(id);
+    pub fn payer_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().payer_account_id = Some(id.into());
         self
     }
 
@@ -175,6 +198,14 @@ impl TransactionExecute for ScheduleCreateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { ScheduleServiceClient::new(channel).create_schedule(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Schedule
+    }
+
+    fn scheduled_transaction_id(&self, transaction_id: &TransactionId) -> Option<TransactionId> {
+        Some(transaction_id.into_scheduled())
+    }
 }
 
 impl ValidateChecksums for ScheduleCreateTransactionData {
@@ -184,14 +215,22 @@ impl ValidateChecksums for ScheduleCreateTransactionData {
 }
 
 impl ToTransactionDataProtobuf for ScheduleCreateTransactionData {
-    // not really anything I can do about this
-    #[allow(clippy::too_many_lines)]
     fn to_transaction_data_protobuf(
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
+
+        services::transaction_body::Data::ScheduleCreate(self.to_protobuf())
+    }
+}
 
+// note: there's no `ToSchedulableTransactionDataProtobuf` impl, schedules can't be nested.
+
+impl ToProtobuf for ScheduleCreateTransactionData {
+    type Protobuf = services::ScheduleCreateTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
         let body = self.scheduled_transaction.as_ref().map(|scheduled| {
             let data = scheduled.data.to_schedulable_transaction_data_protobuf();
 
@@ -210,14 +249,14 @@ impl ToTransactionDataProtobuf for ScheduleCreateTransactionData {
         let admin_key = self.admin_key.to_protobuf();
         let expiration_time = self.expiration_time.map(Into::into);
 
-        services::transaction_body::Data::ScheduleCreate(services::ScheduleCreateTransactionBody {
+        services::ScheduleCreateTransactionBody {
             scheduled_transaction_body: body,
             memo: self.schedule_memo.clone().unwrap_or_default(),
             admin_key,
             payer_account_id,
             expiration_time,
             wait_for_expiry: self.wait_for_expiry,
-        })
+        }
     }
 }
 
@@ -242,6 +281,7 @@ impl FromProtobuf<services::ScheduleCreateTransactionBody> for ScheduleCreateTra
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
     use time::OffsetDateTime;
@@ -261,9 +301,14 @@ mod tests {
     use crate::{
         AccountId,
         AnyTransaction,
+        Client,
+        FileAppendTransaction,
+        FileId,
         Hbar,
         PublicKey,
         ScheduleCreateTransaction,
+        TokenId,
+        TokenUnpauseTransaction,
         TransferTransaction,
     };
 
@@ -286,6 +331,7 @@ mod tests {
         let mut tx = ScheduleCreateTransaction::new_for_tests();
 
         tx.scheduled_transaction(scheduled_transaction())
+            .unwrap()
             .admin_key(admin_key())
             .payer_account_id(PAYER_ACCOUNT_ID)
             .schedule_memo(SCHEDULE_MEMO)
@@ -489,6 +535,150 @@ mod tests {
         assert_eq!(tx.wait_for_expiry, false);
     }
 
+    #[test]
+    fn schedule_token_unpause() {
+        let mut unpause = TokenUnpauseTransaction::new();
+        unpause.token_id(TokenId::new(0, 0, 1234));
+
+        let mut tx = ScheduleCreateTransaction::new_for_tests();
+        tx.scheduled_transaction(unpause).unwrap().freeze().unwrap();
+
+        let tx = check_body(transaction_body(tx));
+
+        let tx = assert_matches!(tx, services::transaction_body::Data::ScheduleCreate(it) => it);
+
+        assert_eq!(
+            tx.scheduled_transaction_body,
+            Some(services::SchedulableTransactionBody {
+                transaction_fee: Hbar::new(2).to_tinybars() as u64,
+                memo: String::new(),
+                data: Some(services::schedulable_transaction_body::Data::TokenUnpause(
+                    services::TokenUnpauseTransactionBody {
+                        token: Some(TokenId::new(0, 0, 1234).to_protobuf()),
+                    }
+                )),
+            })
+        );
+    }
+
+    #[test]
+    fn schedule_method_token_unpause() {
+        let mut unpause = TokenUnpauseTransaction::new();
+        unpause
+            .token_id(TokenId::new(0, 0, 1234))
+            .max_transaction_fee(Hbar::new(3))
+            .transaction_memo("unpause");
+
+        let expected = unpause.data().to_schedulable_transaction_data_protobuf();
+
+        let tx = unpause.schedule().unwrap();
+
+        let scheduled = tx.data().scheduled_transaction.as_ref().unwrap();
+
+        assert_eq!(scheduled.data.to_schedulable_transaction_data_protobuf(), expected);
+        assert_eq!(scheduled.max_transaction_fee, Some(Hbar::new(3)));
+        assert_eq!(scheduled.transaction_memo, "unpause");
+    }
+
+    #[test]
+    fn schedule_method_frozen_errors() {
+        let mut unpause = TokenUnpauseTransaction::new_for_tests();
+        unpause.token_id(TokenId::new(0, 0, 1234)).freeze().unwrap();
+
+        assert_matches!(unpause.schedule(), Err(crate::Error::InvalidTransaction(_)));
+    }
+
+    #[test]
+    fn scheduled_transaction_frozen_errors() {
+        let mut unpause = TokenUnpauseTransaction::new_for_tests();
+        unpause.token_id(TokenId::new(0, 0, 1234)).freeze().unwrap();
+
+        assert_matches!(
+            ScheduleCreateTransaction::new().scheduled_transaction(unpause),
+            Err(crate::Error::InvalidTransaction(_))
+        );
+    }
+
+    #[test]
+    fn scheduled_transaction_non_schedulable_errors() {
+        let transaction = AnyTransaction::from(ScheduleCreateTransaction::new());
+
+        assert_matches!(
+            ScheduleCreateTransaction::new().scheduled_transaction(transaction),
+            Err(crate::Error::InvalidTransaction(_))
+        );
+    }
+
+    #[test]
+    fn scheduled_transaction_multiple_chunks_errors() {
+        let mut append = FileAppendTransaction::new();
+        append.file_id(FileId::new(0, 0, 1234)).contents(vec![0; 5000]);
+
+        assert_matches!(
+            ScheduleCreateTransaction::new().scheduled_transaction(append),
+            Err(crate::Error::InvalidTransaction(_))
+        );
+    }
+
+    #[test]
+    fn scheduled_transaction_node_account_ids_errors() {
+        let mut unpause = TokenUnpauseTransaction::new();
+        unpause.token_id(TokenId::new(0, 0, 1234)).node_account_ids([AccountId::new(0, 0, 3)]);
+
+        assert_matches!(
+            ScheduleCreateTransaction::new().scheduled_transaction(unpause),
+            Err(crate::Error::InvalidTransaction(_))
+        );
+    }
+
+    #[test]
+    fn schedule_node_account_ids_errors() {
+        let mut transfer = scheduled_transaction();
+        transfer.node_account_ids([AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)]);
+
+        assert_matches!(transfer.schedule(), Err(crate::Error::InvalidTransaction(_)));
+    }
+
+    #[test]
+    fn any_transaction_schedule() {
+        let transfer = AnyTransaction::from(scheduled_transaction());
+
+        let tx = transfer.schedule().unwrap();
+
+        let scheduled = tx.data().scheduled_transaction.as_ref().unwrap();
+
+        assert_eq!(
+            scheduled.data.to_schedulable_transaction_data_protobuf(),
+            scheduled_transaction().data().to_schedulable_transaction_data_protobuf()
+        );
+    }
+
+    #[tokio::test]
+    async fn schedule_picks_its_own_node_account_ids() -> crate::Result<()> {
+        let client = Client::for_testnet();
+        client.set_operator(PAYER_ACCOUNT_ID, unused_private_key());
+
+        let mut tx = scheduled_transaction().schedule()?;
+
+        assert_eq!(tx.get_node_account_ids(), None);
+
+        tx.freeze_with(&client)?;
+
+        assert!(tx.get_node_account_ids().is_some_and(|it| !it.is_empty()));
+
+        let scheduled = assert_matches!(
+            transaction_body(tx).data,
+            Some(services::transaction_body::Data::ScheduleCreate(it)) => it.scheduled_transaction_body.unwrap()
+        );
+
+        assert_matches!(
+            scheduled.data,
+            Some(services::schedulable_transaction_body::Data::CryptoTransfer(_))
+        );
+
+        Ok(())
+    }
+
     mod get_set {
         use super::*;
         #[test]
@@ -30,6 +30,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -61,8 +62,8 @@ impl ScheduleDeleteTransaction {
     }
 
     /// Sets the schedule to delete.
-    pub fn schedule_id(&mut self, id: ScheduleId) -> &mut Self {
-        self.data_mut().schedule_id = Some(id);
+    pub fn schedule_id(&mut self, id: impl Into<ScheduleId>) -> &mut Self {
+        self.data_mut().schedule_id = Some(id.into());
         self
     }
 }
@@ -76,6 +77,10 @@ impl TransactionExecute for ScheduleDeleteTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { ScheduleServiceClient::new(channel).delete_schedule(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Schedule
+    }
 }
 
 impl ValidateChecksums for ScheduleDeleteTransactionData {
@@ -89,7 +94,7 @@ impl ToTransactionDataProtobuf for ScheduleDeleteTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::ScheduleDelete(self.to_protobuf())
     }
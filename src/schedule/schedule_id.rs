@@ -64,7 +64,7 @@ impl ScheduleId {
         Self { shard, realm, num, checksum: None }
     }
 
-    /// Create a new `AccountBalance` from protobuf-encoded `bytes`.
+    /// Create a new `ScheduleId` from protobuf-encoded `bytes`.
     ///
     /// # Errors
     /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the bytes fails to produce a valid protobuf.
@@ -99,15 +99,18 @@ impl ScheduleId {
     }
 
     /// Convert `self` to a string with a valid checksum.
-    #[must_use]
-    pub fn to_string_with_checksum(&self, client: &Client) -> String {
-        EntityId::to_string_with_checksum(self.to_string(), client)
+    ///
+    /// # Errors
+    /// - [`Error::CannotCreateChecksumWithoutLedgerId`] if the client has no `ledger_id`.
+    pub fn to_string_with_checksum(&self, client: &Client) -> crate::Result<String> {
+        EntityId::to_string_with_checksum(self.shard, self.realm, self.num, client)
     }
 
     /// Validates `self.checksum` (if it exists) for `client`.
     ///
     /// # Errors
     /// - [`Error::BadEntityId`] if there is a checksum, and the checksum is not valid for the client's `ledger_id`.
+    /// - [`Error::CannotValidateChecksumWithoutLedgerId`] if there is a checksum, and the client has no `ledger_id`.
     pub fn validate_checksum(&self, client: &Client) -> Result<(), Error> {
         EntityId::validate_checksum(self.shard, self.realm, self.num, self.checksum, client)
     }
@@ -184,13 +187,69 @@ impl From<EntityId> for ScheduleId {
 
 #[cfg(test)]
 mod tests {
-    use crate::ScheduleId;
+    use assert_matches::assert_matches;
+
+    use crate::entity_id::ValidateChecksums;
+    use crate::ledger_id::RefLedgerId;
+    use crate::{
+        Client,
+        Error,
+        FromProtobuf,
+        ScheduleId,
+        ToProtobuf,
+    };
 
     #[test]
     fn should_serialize_from_string() {
         assert_eq!("0.0.5005", "0.0.5005".parse::<ScheduleId>().unwrap().to_string());
     }
 
+    #[test]
+    fn parse_with_checksum() {
+        let id: ScheduleId = "0.0.5005-dkxwh".parse().unwrap();
+
+        assert_eq!(id, ScheduleId { checksum: id.checksum, ..ScheduleId::new(0, 0, 5005) });
+        assert_eq!(id.checksum.unwrap().to_string(), "dkxwh");
+
+        // the checksum isn't part of the plain display form.
+        assert_eq!(id.to_string(), "0.0.5005");
+
+        id.validate_checksums(RefLedgerId::TESTNET).unwrap();
+
+        assert_matches!(
+            id.validate_checksums(RefLedgerId::MAINNET),
+            Err(Error::BadEntityId { shard: 0, realm: 0, num: 5005, .. })
+        );
+    }
+
+    #[test]
+    fn parse_malformed() {
+        assert_matches!("0.0.".parse::<ScheduleId>(), Err(Error::BasicParse(_)));
+        assert_matches!("0.0.5005-dkxw".parse::<ScheduleId>(), Err(Error::BasicParse(_)));
+    }
+
+    #[tokio::test]
+    async fn to_string_with_checksum() {
+        assert_eq!(
+            ScheduleId::from(5005).to_string_with_checksum(&Client::for_testnet()).unwrap(),
+            "0.0.5005-dkxwh"
+        );
+    }
+
+    #[test]
+    fn from_u64() {
+        assert_eq!(ScheduleId::from(5005), ScheduleId::new(0, 0, 5005));
+    }
+
+    #[test]
+    fn to_from_protobuf() {
+        let id = ScheduleId::new(1, 2, 5005);
+        let pb = id.to_protobuf();
+
+        assert_eq!((pb.shard_num, pb.realm_num, pb.schedule_num), (1, 2, 5005));
+        assert_eq!(ScheduleId::from_protobuf(pb).unwrap(), id);
+    }
+
     #[test]
     fn from_bytes() {
         assert_eq!(
@@ -27,6 +27,7 @@ use crate::transaction::TransactionBody;
 use crate::{
     AccountId,
     AnyTransaction,
+    Error,
     FromProtobuf,
     Key,
     KeyList,
@@ -36,7 +37,6 @@ use crate::{
     TransactionId,
 };
 
-// TODO: scheduled_transaction
 /// Response from [`ScheduleInfoQuery`][crate::ScheduleInfoQuery].
 #[derive(Debug, Clone)]
 pub struct ScheduleInfo {
@@ -60,7 +60,8 @@ pub struct ScheduleInfo {
     /// it executes).
     pub scheduled_transaction_id: TransactionId,
 
-    scheduled_transaction: SchedulableTransactionBody,
+    // `None` if the scheduled transaction's type isn't one this SDK knows about.
+    scheduled_transaction: Option<SchedulableTransactionBody>,
 
     /// When set to true, the transaction will be evaluated for execution at `expiration_time`
     /// instead of when all required signatures are received.
@@ -95,16 +96,27 @@ impl ScheduleInfo {
     /// Returns the scheduled transaction.
     ///
     /// This is *not* guaranteed to be a constant time operation.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if the scheduled transaction is of a type this SDK doesn't support.
     pub fn scheduled_transaction(&self) -> crate::Result<AnyTransaction> {
-        // note: this can't error *right now* but the API *will* be faliable eventually, and as such, returns a result to make the change non-breaking.
+        let scheduled_transaction = self.scheduled_transaction.as_ref().ok_or_else(|| {
+            Error::from_protobuf(format!(
+                "scheduled transaction of schedule `{}` has an unsupported transaction type",
+                self.schedule_id
+            ))
+        })?;
+
         Ok(Transaction::from_parts(
             TransactionBody {
-                data: (*self.scheduled_transaction.data).clone().into(),
+                data: (*scheduled_transaction.data).clone().into(),
                 node_account_ids: None,
                 transaction_valid_duration: None,
-                max_transaction_fee: None,
-                transaction_memo: self.scheduled_transaction.transaction_memo.clone(),
+                max_transaction_fee: scheduled_transaction.max_transaction_fee,
+                transaction_memo: scheduled_transaction.transaction_memo.clone(),
                 transaction_id: Some(self.scheduled_transaction_id),
+                generated_transaction_id: None,
+                payer_account_id: None,
                 operator: None,
                 is_frozen: true,
                 regenerate_transaction_id: Some(false),
@@ -146,8 +158,14 @@ impl FromProtobuf<services::ScheduleInfo> for ScheduleInfo {
         let scheduled_transaction_id =
             TransactionId::from_protobuf(pb_getf!(pb, scheduled_transaction_id)?)?;
 
-        let transaction_body =
-            SchedulableTransactionBody::from_protobuf(pb_getf!(pb, scheduled_transaction_body)?)?;
+        let transaction_body = pb_getf!(pb, scheduled_transaction_body)?;
+
+        // an unknown transaction type decodes without `data`, that's reported by `scheduled_transaction` instead,
+        // so that the rest of the schedule is still readable.
+        let transaction_body = match transaction_body.data {
+            Some(_) => Some(SchedulableTransactionBody::from_protobuf(transaction_body)?),
+            None => None,
+        };
 
         let signatories = pb.signers.map(KeyList::from_protobuf).transpose()?.unwrap_or_default();
 
@@ -198,10 +216,10 @@ impl ToProtobuf for ScheduleInfo {
             ledger_id: self.ledger_id.to_bytes(),
             wait_for_expiry: self.wait_for_expiry,
 
-            // unimplemented fields
-            scheduled_transaction_body: Some(
-                self.scheduled_transaction.to_scheduled_body_protobuf(),
-            ),
+            scheduled_transaction_body: self
+                .scheduled_transaction
+                .as_ref()
+                .map(SchedulableTransactionBody::to_scheduled_body_protobuf),
             data: None,
         }
     }
@@ -226,6 +244,7 @@ mod tests {
     use crate::transaction::ToSchedulableTransactionDataProtobuf;
     use crate::{
         AccountDeleteTransaction,
+        AccountId,
         LedgerId,
         ScheduleInfo,
         TransactionId,
@@ -234,7 +253,7 @@ mod tests {
     fn make_info() -> ScheduleInfo {
         let schedueld = AnySchedulableTransactionData::from_protobuf(
             AccountDeleteTransaction::new()
-                .account_id("6.6.6".parse().unwrap())
+                .account_id("6.6.6".parse::<AccountId>().unwrap())
                 .data()
                 .to_schedulable_transaction_data_protobuf(),
         )
@@ -252,11 +271,11 @@ mod tests {
                 nonce: None,
                 scheduled: false,
             },
-            scheduled_transaction: SchedulableTransactionBody {
+            scheduled_transaction: Some(SchedulableTransactionBody {
                 data: Box::new(schedueld),
                 max_transaction_fee: None,
                 transaction_memo: Default::default(),
-            },
+            }),
             wait_for_expiry: true,
             memo: "memo".to_owned(),
             expiration_time: Some(VALID_START),
@@ -639,4 +658,30 @@ mod tests {
         "#]]
         .assert_debug_eq(&make_deleted_info().to_protobuf());
     }
+
+    #[test]
+    fn scheduled_transaction() {
+        let tx = make_info().scheduled_transaction().unwrap();
+
+        let tx = tx.downcast::<AccountDeleteTransaction>().unwrap();
+
+        assert_eq!(tx.get_account_id(), Some("6.6.6".parse().unwrap()));
+        assert_eq!(tx.get_transaction_id(), Some(make_info().scheduled_transaction_id));
+    }
+
+    #[test]
+    fn scheduled_transaction_unsupported_type() {
+        let mut pb = make_info().to_protobuf();
+        pb.scheduled_transaction_body.as_mut().unwrap().data = None;
+
+        // the rest of the schedule is still readable.
+        let info = ScheduleInfo::from_protobuf(pb).unwrap();
+
+        let error = info.scheduled_transaction().unwrap_err();
+
+        expect![[r#"
+            "failed to create a SDK type from a protobuf response: scheduled transaction of schedule `1.2.3` has an unsupported transaction type"
+        "#]]
+        .assert_debug_eq(&error.to_string());
+    }
 }
@@ -99,15 +99,66 @@ impl ValidateChecksums for ScheduleInfoQueryData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
+    use hedera_proto::services;
+    use tonic::codegen::BoxFuture;
 
+    use crate::mock::{
+        self,
+        MockUnary,
+        Unary,
+    };
     use crate::query::ToQueryProtobuf;
+    use crate::transaction::test_helpers::{
+        transaction_body,
+        unused_private_key,
+        TEST_NODE_ACCOUNT_IDS,
+        TEST_TX_ID,
+    };
     use crate::{
+        AccountId,
+        Client,
         Hbar,
         ScheduleId,
         ScheduleInfoQuery,
+        ToProtobuf,
+        TokenId,
+        TokenUnpauseTransaction,
     };
 
+    /// A `ScheduleService` that only knows `getScheduleInfo`, answering it with `info`.
+    #[derive(Clone)]
+    struct MockScheduleService {
+        info: services::ScheduleInfo,
+    }
+
+    impl MockScheduleService {
+        /// Serves `self` on a random local port, returning a client for it (node `0.0.3`).
+        async fn serve(self) -> Client {
+            mock::client_for(mock::serve(Unary(self)).await)
+        }
+    }
+
+    impl MockUnary for MockScheduleService {
+        const SERVICE: &'static str = "proto.ScheduleService";
+        const METHOD: &'static str = "getScheduleInfo";
+
+        type Request = services::Query;
+        type Response = services::Response;
+
+        fn call(&self, _request: services::Query) -> BoxFuture<services::Response, tonic::Status> {
+            Box::pin(std::future::ready(Ok(services::Response {
+                response: Some(services::response::Response::ScheduleGetInfo(
+                    services::ScheduleGetInfoResponse {
+                        header: Some(services::ResponseHeader::default()),
+                        schedule_info: Some(self.info.clone()),
+                    },
+                )),
+            })))
+        }
+    }
+
     #[test]
     fn serialize() {
         expect![[r#"
@@ -149,4 +200,53 @@ mod tests {
 
         assert_eq!(query.get_schedule_id(), Some(ScheduleId::new(0, 0, 5005)));
     }
+
+    #[tokio::test]
+    async fn scheduled_transaction() -> crate::Result<()> {
+        let token_id = TokenId::new(0, 0, 1234);
+
+        let mut unpause = TokenUnpauseTransaction::new();
+        unpause.token_id(token_id);
+
+        let mut create = unpause.schedule()?;
+        create.node_account_ids(TEST_NODE_ACCOUNT_IDS).transaction_id(TEST_TX_ID).freeze()?;
+
+        let scheduled_transaction_body = assert_matches!(
+            transaction_body(create).data,
+            Some(services::transaction_body::Data::ScheduleCreate(it)) => it.scheduled_transaction_body
+        );
+
+        let scheduled_transaction_id = TEST_TX_ID.into_scheduled();
+
+        let client = MockScheduleService {
+            info: services::ScheduleInfo {
+                schedule_id: Some(ScheduleId::new(0, 0, 5005).to_protobuf()),
+                creator_account_id: Some(TEST_TX_ID.account_id.to_protobuf()),
+                payer_account_id: Some(TEST_TX_ID.account_id.to_protobuf()),
+                scheduled_transaction_id: Some(scheduled_transaction_id.to_protobuf()),
+                scheduled_transaction_body,
+                ..Default::default()
+            },
+        }
+        .serve()
+        .await;
+
+        client.set_operator(AccountId::new(0, 0, 1001), unused_private_key());
+
+        let info = ScheduleInfoQuery::new()
+            .schedule_id(ScheduleId::new(0, 0, 5005))
+            .node_account_ids([3.into()])
+            .payment_amount(Hbar::from_tinybars(100))
+            .execute(&client)
+            .await?;
+
+        let tx = info.scheduled_transaction()?;
+
+        let tx = assert_matches!(tx.downcast::<TokenUnpauseTransaction>(), Ok(it) => it);
+
+        assert_eq!(tx.get_token_id(), Some(token_id));
+        assert_eq!(tx.get_transaction_id(), Some(scheduled_transaction_id));
+
+        Ok(())
+    }
 }
@@ -18,8 +18,11 @@
  * ‍
  */
 
+use std::future::Future;
+
 use hedera_proto::services;
 use hedera_proto::services::schedule_service_client::ScheduleServiceClient;
+use time::OffsetDateTime;
 use tonic::transport::Channel;
 
 use crate::protobuf::{
@@ -29,15 +32,20 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
 };
 use crate::{
     BoxGrpcFuture,
+    Client,
     Error,
     ScheduleId,
+    ScheduleInfoQuery,
+    Status,
     Transaction,
+    TransactionReceipt,
     ValidateChecksums,
 };
 
@@ -57,10 +65,68 @@ impl ScheduleSignTransaction {
     }
 
     /// Sets the schedule to add signing keys to.
-    pub fn schedule_id(&mut self, id: ScheduleId) -> &mut Self {
-        self.data_mut().schedule_id = Some(id);
+    pub fn schedule_id(&mut self, id: impl Into<ScheduleId>) -> &mut Self {
+        self.data_mut().schedule_id = Some(id.into());
         self
     }
+
+    /// Execute this transaction against the provided client and wait for its receipt.
+    ///
+    /// Unlike [`execute`](Self::execute) followed by [`get_receipt`](crate::TransactionResponse::get_receipt),
+    /// signing a schedule that already executed is reported as [`Error::ScheduleAlreadyExecuted`]
+    /// (with the time it executed at, fetched with a [`ScheduleInfoQuery`]) rather than a generic status error.
+    ///
+    /// # Errors
+    /// - [`Error::ScheduleAlreadyExecuted`] if the schedule already executed.
+    /// - Any error [`execute`](Self::execute) or [`get_receipt`](crate::TransactionResponse::get_receipt) can return.
+    pub async fn execute_and_get_receipt(
+        &mut self,
+        client: &Client,
+    ) -> crate::Result<TransactionReceipt> {
+        let result = match self.execute(client).await {
+            Ok(response) => response.get_receipt(client).await,
+            Err(e) => Err(e),
+        };
+
+        map_schedule_already_executed(result, self.get_schedule_id(), |schedule_id| async move {
+            // this is purely informational, so failing to get it isn't an error.
+            ScheduleInfoQuery::new()
+                .schedule_id(schedule_id)
+                .execute(client)
+                .await
+                .ok()
+                .and_then(|info| info.executed_at)
+        })
+        .await
+    }
+}
+
+/// Maps a `SCHEDULE_ALREADY_EXECUTED` status (from either pre-check or the receipt) in `result`
+/// to [`Error::ScheduleAlreadyExecuted`], using `executed_at` to find out when it executed.
+async fn map_schedule_already_executed<F, Fut>(
+    result: crate::Result<TransactionReceipt>,
+    schedule_id: Option<ScheduleId>,
+    executed_at: F,
+) -> crate::Result<TransactionReceipt>
+where
+    F: FnOnce(ScheduleId) -> Fut,
+    Fut: Future<Output = Option<OffsetDateTime>>,
+{
+    match (result, schedule_id) {
+        (
+            Err(
+                Error::TransactionPreCheckStatus {
+                    status: Status::ScheduleAlreadyExecuted, ..
+                }
+                | Error::ReceiptStatus { status: Status::ScheduleAlreadyExecuted, .. },
+            ),
+            Some(schedule_id),
+        ) => Err(Error::ScheduleAlreadyExecuted {
+            schedule_id: Box::new(schedule_id),
+            executed_at: executed_at(schedule_id).await,
+        }),
+        (result, _) => result,
+    }
 }
 
 impl TransactionData for ScheduleSignTransactionData {}
@@ -71,7 +137,11 @@ impl TransactionExecute for ScheduleSignTransactionData {
         channel: Channel,
         request: services::Transaction,
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
-        Box::pin(async { ScheduleServiceClient::new(channel).delete_schedule(request).await })
+        Box::pin(async { ScheduleServiceClient::new(channel).sign_schedule(request).await })
+    }
+
+    fn service(&self) -> Service {
+        Service::Schedule
     }
 }
 
@@ -86,16 +156,14 @@ impl ToTransactionDataProtobuf for ScheduleSignTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
-        let schedule_id = self.schedule_id.to_protobuf();
-
-        services::transaction_body::Data::ScheduleSign(services::ScheduleSignTransactionBody {
-            schedule_id,
-        })
+        services::transaction_body::Data::ScheduleSign(self.to_protobuf())
     }
 }
 
+// note: there's no `ToSchedulableTransactionDataProtobuf` impl, a schedule can't be signed by another schedule.
+
 impl From<ScheduleSignTransactionData> for AnyTransactionData {
     fn from(transaction: ScheduleSignTransactionData) -> Self {
         Self::ScheduleSign(transaction)
@@ -108,11 +176,23 @@ impl FromProtobuf<services::ScheduleSignTransactionBody> for ScheduleSignTransac
     }
 }
 
+impl ToProtobuf for ScheduleSignTransactionData {
+    type Protobuf = services::ScheduleSignTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::ScheduleSignTransactionBody { schedule_id: self.schedule_id.to_protobuf() }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
+    use prost::Message;
+    use time::OffsetDateTime;
 
+    use super::map_schedule_already_executed;
     use crate::protobuf::{
         FromProtobuf,
         ToProtobuf,
@@ -121,11 +201,18 @@ mod tests {
     use crate::transaction::test_helpers::{
         check_body,
         transaction_body,
+        unused_private_key,
+        TEST_ACCOUNT_ID,
+        TEST_NODE_ACCOUNT_IDS,
+        TEST_TX_ID,
     };
     use crate::{
         AnyTransaction,
+        Client,
+        Error,
         ScheduleId,
         ScheduleSignTransaction,
+        Status,
     };
 
     const SCHEDULE_ID: ScheduleId = ScheduleId::new(0, 0, 444);
@@ -175,6 +262,36 @@ mod tests {
         assert_eq!(tx, tx2);
     }
 
+    #[tokio::test]
+    async fn sign_with_operator() {
+        let client = Client::for_testnet();
+        client.set_operator(TEST_ACCOUNT_ID, unused_private_key());
+
+        let mut tx = ScheduleSignTransaction::new();
+
+        tx.schedule_id(SCHEDULE_ID)
+            .node_account_ids(TEST_NODE_ACCOUNT_IDS)
+            .transaction_id(TEST_TX_ID)
+            .sign_with_operator(&client)
+            .unwrap();
+
+        let sources = tx.make_sources().unwrap();
+
+        for signed in sources.signed_transactions() {
+            let sig_pairs = &signed.sig_map.as_ref().unwrap().sig_pair;
+
+            assert_eq!(sig_pairs.len(), 1);
+            assert_eq!(
+                sig_pairs[0].pub_key_prefix,
+                unused_private_key().public_key().to_bytes_raw()
+            );
+
+            let body = services::TransactionBody::decode(&*signed.body_bytes).unwrap();
+
+            assert!(matches!(body.data, Some(services::transaction_body::Data::ScheduleSign(_))));
+        }
+    }
+
     #[test]
     fn from_proto_body() {
         let tx =
@@ -198,4 +315,73 @@ mod tests {
     fn get_set_schedule_id_frozen_panics() {
         make_transaction().schedule_id(SCHEDULE_ID);
     }
+
+    #[tokio::test]
+    async fn already_executed_pre_check() {
+        let executed_at = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+
+        let result = Err(Error::TransactionPreCheckStatus {
+            status: Status::ScheduleAlreadyExecuted,
+            transaction_id: Box::new(TEST_TX_ID),
+            cost: None,
+        });
+
+        let result =
+            map_schedule_already_executed(result, Some(ScheduleId::new(0, 0, 444)), |id| {
+                assert_eq!(id, ScheduleId::new(0, 0, 444));
+                async move { Some(executed_at) }
+            })
+            .await;
+
+        let (schedule_id, actual) = assert_matches!(
+            result,
+            Err(Error::ScheduleAlreadyExecuted { schedule_id, executed_at }) => (schedule_id, executed_at)
+        );
+
+        assert_eq!(*schedule_id, ScheduleId::new(0, 0, 444));
+        assert_eq!(actual, Some(executed_at));
+    }
+
+    #[tokio::test]
+    async fn already_executed_receipt() {
+        let result = Err(Error::ReceiptStatus {
+            status: Status::ScheduleAlreadyExecuted,
+            transaction_id: Some(Box::new(TEST_TX_ID)),
+        });
+
+        let result =
+            map_schedule_already_executed(result, Some(ScheduleId::new(0, 0, 444)), |_| async {
+                None::<OffsetDateTime>
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(Error::ScheduleAlreadyExecuted { schedule_id: _, executed_at: None })
+        );
+    }
+
+    #[tokio::test]
+    async fn other_status_is_unchanged() {
+        let result = Err(Error::ReceiptStatus {
+            status: Status::InvalidScheduleId,
+            transaction_id: Some(Box::new(TEST_TX_ID)),
+        });
+
+        let mut fetched = false;
+
+        let result =
+            map_schedule_already_executed(result, Some(ScheduleId::new(0, 0, 444)), |_| {
+                fetched = true;
+                async { None::<OffsetDateTime> }
+            })
+            .await;
+
+        assert!(!fetched);
+
+        assert_matches!(
+            result,
+            Err(Error::ReceiptStatus { status: Status::InvalidScheduleId, transaction_id: _ })
+        );
+    }
 }
@@ -84,6 +84,26 @@ pub struct ServiceEndpoint {
     pub domain_name: String,
 }
 
+impl ServiceEndpoint {
+    /// Parses an endpoint given to a node transaction.
+    ///
+    /// Unlike the endpoints in an address book, these are taken as-is:
+    /// the port isn't defaulted and the endpoint may have only a domain name.
+    pub(crate) fn from_endpoint_protobuf(pb: services::ServiceEndpoint) -> crate::Result<Self> {
+        let ip_address_v4 = if pb.ip_address_v4.is_empty() {
+            None
+        } else {
+            Some(*parse_socket_addr_v4(pb.ip_address_v4, pb.port)?.ip())
+        };
+
+        if !pb.domain_name.is_empty() {
+            validate_domain_name(pb.domain_name.clone())?;
+        }
+
+        Ok(Self { ip_address_v4, port: pb.port, domain_name: pb.domain_name })
+    }
+}
+
 impl FromProtobuf<services::ServiceEndpoint> for ServiceEndpoint {
     fn from_protobuf(pb: services::ServiceEndpoint) -> crate::Result<Self> {
         let mut port = pb.port;
@@ -110,7 +130,7 @@ impl ToProtobuf for ServiceEndpoint {
 
     fn to_protobuf(&self) -> Self::Protobuf {
         services::ServiceEndpoint {
-            ip_address_v4: self.ip_address_v4.unwrap().octets().to_vec(),
+            ip_address_v4: self.ip_address_v4.map(|it| it.octets().to_vec()).unwrap_or_default(),
             port: self.port,
             domain_name: self.domain_name.clone(),
         }
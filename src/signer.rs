@@ -19,7 +19,9 @@
  */
 
 use std::fmt;
+use std::future::Future;
 
+use futures_core::future::BoxFuture;
 use triomphe::Arc;
 use unsize::{
     CoerceUnsize,
@@ -27,6 +29,7 @@ use unsize::{
 };
 
 use crate::{
+    Error,
     PrivateKey,
     PublicKey,
 };
@@ -46,6 +49,9 @@ pub(crate) enum AnySigner {
     // but we can't do that because trait aliases don't exist.
     #[allow(clippy::type_complexity)]
     Arbitrary(Box<PublicKey>, Arc<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>),
+    // Can only sign in async contexts, see `sign_async`.
+    #[allow(clippy::type_complexity)]
+    Async(Box<PublicKey>, Arc<dyn Fn(&[u8]) -> BoxFuture<'static, Vec<u8>> + Send + Sync>),
 }
 
 impl AnySigner {
@@ -58,6 +64,21 @@ impl AnySigner {
             Arc::new(signer).unsize(Coercion!(to dyn Fn(&[u8]) -> Vec<u8> + Send + Sync)),
         )
     }
+
+    pub(crate) fn arbitrary_async<F, Fut>(public_key: Box<PublicKey>, signer: F) -> Self
+    where
+        F: Fn(&[u8]) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Vec<u8>> + Send + 'static,
+    {
+        let signer =
+            move |message: &[u8]| -> BoxFuture<'static, Vec<u8>> { Box::pin(signer(message)) };
+
+        Self::Async(
+            public_key,
+            Arc::new(signer)
+                .unsize(Coercion!(to dyn Fn(&[u8]) -> BoxFuture<'static, Vec<u8>> + Send + Sync)),
+        )
+    }
 }
 
 impl fmt::Debug for AnySigner {
@@ -67,6 +88,7 @@ impl fmt::Debug for AnySigner {
             Self::Arbitrary(arg0, _) => {
                 f.debug_tuple("Arbitrary").field(arg0).field(&"Fn").finish()
             }
+            Self::Async(arg0, _) => f.debug_tuple("Async").field(arg0).field(&"Fn").finish(),
         }
     }
 }
@@ -76,16 +98,36 @@ impl AnySigner {
     pub(crate) fn public_key(&self) -> PublicKey {
         match self {
             AnySigner::PrivateKey(it) => it.public_key(),
-            AnySigner::Arbitrary(it, _) => **it,
+            AnySigner::Arbitrary(it, _) | AnySigner::Async(it, _) => **it,
         }
     }
 
-    pub(crate) fn sign(&self, message: &[u8]) -> (PublicKey, Vec<u8>) {
+    /// Returns `true` if `self` can only sign with [`sign_async`](Self::sign_async).
+    pub(crate) fn is_async(&self) -> bool {
+        matches!(self, Self::Async(..))
+    }
+
+    /// # Errors
+    /// - [`Error::AsyncSignerRequiresExecute`] if `self` [`is_async`](Self::is_async).
+    pub(crate) fn sign(&self, message: &[u8]) -> crate::Result<(PublicKey, Vec<u8>)> {
         match self {
-            AnySigner::PrivateKey(it) => (it.public_key(), it.sign(message)),
+            AnySigner::PrivateKey(it) => Ok((it.public_key(), it.sign(message))),
             AnySigner::Arbitrary(public, signer) => {
                 let bytes = signer(message);
 
+                Ok((**public, bytes))
+            }
+            AnySigner::Async(..) => Err(Error::AsyncSignerRequiresExecute),
+        }
+    }
+
+    pub(crate) async fn sign_async(&self, message: &[u8]) -> (PublicKey, Vec<u8>) {
+        match self {
+            AnySigner::PrivateKey(it) => (it.public_key(), it.sign(message)),
+            AnySigner::Arbitrary(public, signer) => (**public, signer(message)),
+            AnySigner::Async(public, signer) => {
+                let bytes = signer(message).await;
+
                 (**public, bytes)
             }
         }
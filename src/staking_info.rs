@@ -94,3 +94,60 @@ impl ToProtobuf for StakingInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+    use prost::Message;
+    use time::OffsetDateTime;
+
+    use crate::protobuf::ToProtobuf;
+    use crate::{
+        AccountId,
+        Hbar,
+        StakingInfo,
+    };
+
+    #[test]
+    fn from_bytes() {
+        let pb = services::StakingInfo {
+            decline_reward: true,
+            stake_period_start: Some(services::Timestamp { seconds: 1_645_714_800, nanos: 0 }),
+            pending_reward: 5_000,
+            staked_to_me: 100_000_000,
+            staked_id: Some(services::staking_info::StakedId::StakedNodeId(3)),
+        };
+
+        let info = StakingInfo::from_bytes(&pb.encode_to_vec()).unwrap();
+
+        assert!(info.decline_staking_reward);
+        assert_eq!(
+            info.stake_period_start,
+            Some(OffsetDateTime::from_unix_timestamp(1_645_714_800).unwrap())
+        );
+        assert_eq!(info.pending_reward, Hbar::from_tinybars(5_000));
+        assert_eq!(info.staked_to_me, Hbar::new(1));
+        assert_eq!(info.staked_account_id, None);
+        assert_eq!(info.staked_node_id, Some(3));
+
+        assert_eq!(info.to_protobuf(), pb);
+    }
+
+    #[test]
+    fn staked_account_id() {
+        let pb = services::StakingInfo {
+            staked_id: Some(services::staking_info::StakedId::StakedAccountId(
+                AccountId::new(0, 0, 1001).to_protobuf(),
+            )),
+            ..Default::default()
+        };
+
+        let info = StakingInfo::from_bytes(&pb.encode_to_vec()).unwrap();
+
+        assert_eq!(info.staked_account_id, Some(AccountId::new(0, 0, 1001)));
+        assert_eq!(info.staked_node_id, None);
+        assert_eq!(info.stake_period_start, None);
+
+        assert_eq!(info.to_protobuf(), pb);
+    }
+}
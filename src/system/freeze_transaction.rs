@@ -27,6 +27,7 @@ use crate::protobuf::FromProtobuf;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -89,8 +90,8 @@ impl FreezeTransaction {
     }
 
     /// Sets the file ID.
-    pub fn file_id(&mut self, id: FileId) -> &mut Self {
-        self.data_mut().file_id = Some(id);
+    pub fn file_id(&mut self, id: impl Into<FileId>) -> &mut Self {
+        self.data_mut().file_id = Some(id.into());
         self
     }
 
@@ -117,6 +118,10 @@ impl TransactionExecute for FreezeTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { FreezeServiceClient::new(channel).freeze(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Freeze
+    }
 }
 
 impl ValidateChecksums for FreezeTransactionData {
@@ -130,7 +135,7 @@ impl ToTransactionDataProtobuf for FreezeTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::Freeze(self.to_protobuf())
     }
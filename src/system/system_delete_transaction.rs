@@ -31,6 +31,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -126,6 +127,14 @@ impl TransactionExecute for SystemDeleteTransactionData {
             }
         })
     }
+
+    fn service(&self) -> Service {
+        if self.file_id.is_some() {
+            Service::File
+        } else {
+            Service::Contract
+        }
+    }
 }
 
 impl ValidateChecksums for SystemDeleteTransactionData {
@@ -140,7 +149,7 @@ impl ToTransactionDataProtobuf for SystemDeleteTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::SystemDelete(self.to_protobuf())
     }
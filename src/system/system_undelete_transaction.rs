@@ -30,6 +30,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -100,6 +101,14 @@ impl TransactionExecute for SystemUndeleteTransactionData {
             }
         })
     }
+
+    fn service(&self) -> Service {
+        if self.file_id.is_some() {
+            Service::File
+        } else {
+            Service::Contract
+        }
+    }
 }
 
 impl ValidateChecksums for SystemUndeleteTransactionData {
@@ -114,7 +123,7 @@ impl ToTransactionDataProtobuf for SystemUndeleteTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::SystemUndelete(self.to_protobuf())
     }
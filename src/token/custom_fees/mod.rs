@@ -21,12 +21,14 @@
 use fraction::Fraction;
 use hedera_proto::services;
 
+use crate::ledger_id::RefLedgerId;
 use crate::{
     AccountId,
     FromProtobuf,
     Hbar,
     ToProtobuf,
     TokenId,
+    ValidateChecksums,
 };
 
 #[cfg(test)]
@@ -67,6 +69,13 @@ pub struct CustomFee<Fee> {
     pub all_collectors_are_exempt: bool,
 }
 
+impl<Fee: ValidateChecksums> ValidateChecksums for CustomFee<Fee> {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> crate::Result<()> {
+        self.fee_collector_account_id.validate_checksums(ledger_id)?;
+        self.fee.validate_checksums(ledger_id)
+    }
+}
+
 impl AnyCustomFee {
     /// Create `AnyCustomFee` from protobuf-encoded `bytes`.
     ///
@@ -192,6 +201,16 @@ impl ToProtobuf for Fee {
     }
 }
 
+impl ValidateChecksums for Fee {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> crate::Result<()> {
+        match self {
+            Self::Fixed(it) => it.validate_checksums(ledger_id),
+            Self::Fractional(it) => it.validate_checksums(ledger_id),
+            Self::Royalty(it) => it.validate_checksums(ledger_id),
+        }
+    }
+}
+
 impl From<FixedFeeData> for Fee {
     fn from(v: FixedFeeData) -> Self {
         Self::Fixed(v)
@@ -236,6 +255,12 @@ impl FixedFeeData {
     }
 }
 
+impl ValidateChecksums for FixedFeeData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> crate::Result<()> {
+        self.denominating_token_id.validate_checksums(ledger_id)
+    }
+}
+
 impl FromProtobuf<services::FixedFee> for FixedFeeData {
     fn from_protobuf(pb: services::FixedFee) -> crate::Result<Self> {
         Ok(Self {
@@ -283,6 +308,13 @@ pub struct FractionalFeeData {
     pub assessment_method: FeeAssessmentMethod,
 }
 
+impl ValidateChecksums for FractionalFeeData {
+    fn validate_checksums(&self, _ledger_id: &RefLedgerId) -> crate::Result<()> {
+        // always denominated in the token the fee is attached to.
+        Ok(())
+    }
+}
+
 impl FromProtobuf<services::FractionalFee> for FractionalFeeData {
     fn from_protobuf(pb: services::FractionalFee) -> crate::Result<Self> {
         let amount = pb.fractional_amount.map(Fraction::from).unwrap_or_default();
@@ -330,6 +362,12 @@ pub struct RoyaltyFeeData {
     pub fallback_fee: Option<FixedFeeData>,
 }
 
+impl ValidateChecksums for RoyaltyFeeData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> crate::Result<()> {
+        self.fallback_fee.validate_checksums(ledger_id)
+    }
+}
+
 impl FromProtobuf<services::RoyaltyFee> for RoyaltyFeeData {
     fn from_protobuf(pb: services::RoyaltyFee) -> crate::Result<Self> {
         let amount = pb.exchange_value_fraction.unwrap_or_default();
@@ -1,10 +1,13 @@
+use assert_matches::assert_matches;
 use fraction::Fraction;
 use hedera_proto::services;
 
+use crate::ledger_id::RefLedgerId;
 use crate::token::custom_fees::{
     AnyCustomFee,
     CustomFee,
     Fee,
+    FeeAssessmentMethod,
     FixedFeeData,
     FractionalFeeData,
     RoyaltyFeeData,
@@ -14,6 +17,7 @@ use crate::{
     FromProtobuf,
     ToProtobuf,
     TokenId,
+    ValidateChecksums,
 };
 
 #[test]
@@ -206,3 +210,72 @@ fn royalty_fee_can_be_created_from_protobuf() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn round_trip_each_fee() {
+    let fees: [AnyCustomFee; 3] = [
+        CustomFee {
+            fee: FixedFeeData { amount: 1000, denominating_token_id: Some(TokenId::from(2)) },
+            fee_collector_account_id: Some(AccountId::from(1)),
+            all_collectors_are_exempt: true,
+        }
+        .into(),
+        CustomFee {
+            fee: FractionalFeeData {
+                denominator: 7,
+                numerator: 3,
+                minimum_amount: 5,
+                maximum_amount: 50,
+                assessment_method: FeeAssessmentMethod::Exclusive,
+            },
+            fee_collector_account_id: Some(AccountId::from(4)),
+            all_collectors_are_exempt: false,
+        }
+        .into(),
+        CustomFee {
+            fee: RoyaltyFeeData {
+                denominator: 10,
+                numerator: 1,
+                fallback_fee: Some(FixedFeeData { amount: 5, denominating_token_id: None }),
+            },
+            fee_collector_account_id: Some(AccountId::from(5)),
+            all_collectors_are_exempt: true,
+        }
+        .into(),
+    ];
+
+    for fee in fees {
+        assert_eq!(AnyCustomFee::from_bytes(&fee.to_bytes()).unwrap(), fee);
+    }
+}
+
+#[test]
+fn validate_checksums() {
+    let fee = AnyCustomFee {
+        fee: RoyaltyFeeData {
+            denominator: 10,
+            numerator: 1,
+            fallback_fee: Some(FixedFeeData {
+                amount: 5,
+                denominating_token_id: Some("0.0.123-esxsf".parse().unwrap()),
+            }),
+        }
+        .into(),
+        fee_collector_account_id: Some("0.0.1001-eevit".parse().unwrap()),
+        all_collectors_are_exempt: false,
+    };
+
+    fee.validate_checksums(RefLedgerId::TESTNET).unwrap();
+
+    assert_matches!(
+        fee.validate_checksums(RefLedgerId::MAINNET),
+        Err(crate::Error::BadEntityId { shard: 0, realm: 0, num: 1001, .. })
+    );
+
+    let fee = AnyCustomFee { fee_collector_account_id: None, ..fee };
+
+    assert_matches!(
+        fee.validate_checksums(RefLedgerId::MAINNET),
+        Err(crate::Error::BadEntityId { shard: 0, realm: 0, num: 123, .. })
+    );
+}
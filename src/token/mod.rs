@@ -44,6 +44,7 @@ mod token_nft_transfer;
 mod token_pause_transaction;
 mod token_reject_flow;
 mod token_reject_transaction;
+mod token_relationship;
 mod token_revoke_kyc_transaction;
 mod token_supply_type;
 mod token_type;
@@ -138,6 +139,7 @@ pub use token_reject_transaction::{
     TokenRejectTransaction,
     TokenRejectTransactionData,
 };
+pub use token_relationship::TokenRelationship;
 pub use token_revoke_kyc_transaction::{
     TokenRevokeKycTransaction,
     TokenRevokeKycTransactionData,
@@ -65,9 +65,20 @@ impl NftId {
     }
 
     /// Convert `self` to a string with a valid checksum.
-    #[must_use]
-    pub fn to_string_with_checksum(&self, client: &Client) -> String {
-        format!("{}/{}", self.token_id.to_string_with_checksum(client), self.serial)
+    ///
+    /// # Errors
+    /// - [`Error::CannotCreateChecksumWithoutLedgerId`] if the client has no `ledger_id`.
+    pub fn to_string_with_checksum(&self, client: &Client) -> crate::Result<String> {
+        Ok(format!("{}/{}", self.token_id.to_string_with_checksum(client)?, self.serial))
+    }
+
+    /// Validates the checksum of `self.token_id` (if it exists) for `client`.
+    ///
+    /// # Errors
+    /// - [`Error::BadEntityId`] if there is a checksum, and the checksum is not valid for the client's `ledger_id`.
+    /// - [`Error::CannotValidateChecksumWithoutLedgerId`] if there is a checksum, and the client has no `ledger_id`.
+    pub fn validate_checksum(&self, client: &Client) -> crate::Result<()> {
+        self.token_id.validate_checksum(client)
     }
 }
 
@@ -37,6 +37,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -314,6 +315,10 @@ impl TransactionExecute for TokenAirdropTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { TokenServiceClient::new(channel).airdrop_tokens(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenAirdropTransactionData {
@@ -337,7 +342,7 @@ impl ToTransactionDataProtobuf for TokenAirdropTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenAirdrop(self.to_protobuf())
     }
@@ -423,7 +428,10 @@ impl FromProtobuf<services::TokenAirdropTransactionBody> for TokenAirdropTransac
 mod tests {
     use std::str::FromStr;
 
-    use expect_test::expect_file;
+    use expect_test::{
+        expect,
+        expect_file,
+    };
     use hedera_proto::services::{
         self,
         AccountAmount,
@@ -516,6 +524,78 @@ mod tests {
         expect_file!["./snapshots/token_airdrop_transaction/serialize.txt"].assert_debug_eq(&tx);
     }
 
+    #[test]
+    fn serialize_fungible() {
+        let mut tx = TokenAirdropTransaction::new_for_tests();
+
+        tx.token_transfer_with_decimals(TokenId::new(0, 0, 5), AccountId::new(0, 0, 5006), 800, 3)
+            .token_transfer_with_decimals(
+                TokenId::new(0, 0, 5),
+                AccountId::new(0, 0, 5005),
+                -800,
+                3,
+            )
+            .freeze()
+            .unwrap();
+
+        let tx = check_body(transaction_body(tx));
+
+        expect![[r#"
+            TokenAirdrop(
+                TokenAirdropTransactionBody {
+                    token_transfers: [
+                        TokenTransferList {
+                            token: Some(
+                                TokenId {
+                                    shard_num: 0,
+                                    realm_num: 0,
+                                    token_num: 5,
+                                },
+                            ),
+                            transfers: [
+                                AccountAmount {
+                                    account_id: Some(
+                                        AccountId {
+                                            shard_num: 0,
+                                            realm_num: 0,
+                                            account: Some(
+                                                AccountNum(
+                                                    5005,
+                                                ),
+                                            ),
+                                        },
+                                    ),
+                                    amount: -800,
+                                    is_approval: false,
+                                },
+                                AccountAmount {
+                                    account_id: Some(
+                                        AccountId {
+                                            shard_num: 0,
+                                            realm_num: 0,
+                                            account: Some(
+                                                AccountNum(
+                                                    5006,
+                                                ),
+                                            ),
+                                        },
+                                    ),
+                                    amount: 800,
+                                    is_approval: false,
+                                },
+                            ],
+                            nft_transfers: [],
+                            expected_decimals: Some(
+                                3,
+                            ),
+                        },
+                    ],
+                },
+            )
+        "#]]
+        .assert_debug_eq(&tx);
+    }
+
     #[test]
     fn to_from_bytes() {
         let tx = make_transaction();
@@ -27,6 +27,7 @@ use crate::protobuf::FromProtobuf;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -74,8 +75,8 @@ impl TokenAssociateTransaction {
     }
 
     /// Sets the account to be associated with the provided tokens.
-    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
-        self.data_mut().account_id = Some(account_id);
+    pub fn account_id(&mut self, account_id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().account_id = Some(account_id.into());
         self
     }
 
@@ -102,6 +103,10 @@ impl TransactionExecute for TokenAssociateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { TokenServiceClient::new(channel).associate_tokens(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenAssociateTransactionData {
@@ -119,7 +124,7 @@ impl ToTransactionDataProtobuf for TokenAssociateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenAssociate(self.to_protobuf())
     }
@@ -29,6 +29,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -127,6 +128,10 @@ impl TransactionExecute for TokenBurnTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { TokenServiceClient::new(channel).burn_token(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenBurnTransactionData {
@@ -140,7 +145,7 @@ impl ToTransactionDataProtobuf for TokenBurnTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenBurn(self.to_protobuf())
     }
@@ -27,6 +27,7 @@ use crate::pending_airdrop_id::PendingAirdropId;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -89,6 +90,10 @@ impl TransactionExecute for TokenCancelAirdropTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { TokenServiceClient::new(channel).cancel_airdrop(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenCancelAirdropTransactionData {
@@ -105,7 +110,7 @@ impl ToTransactionDataProtobuf for TokenCancelAirdropTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenCancelAirdrop(self.to_protobuf())
     }
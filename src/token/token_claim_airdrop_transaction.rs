@@ -31,6 +31,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -105,6 +106,10 @@ impl TransactionExecute for TokenClaimAirdropTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { TokenServiceClient::new(channel).claim_airdrop(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenClaimAirdropTransactionData {
@@ -121,7 +126,7 @@ impl ToTransactionDataProtobuf for TokenClaimAirdropTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenClaimAirdrop(self.to_protobuf())
     }
@@ -167,7 +172,10 @@ impl FromProtobuf<services::TokenClaimAirdropTransactionBody> for TokenClaimAird
 
 #[cfg(test)]
 mod tests {
-    use expect_test::expect_file;
+    use expect_test::{
+        expect,
+        expect_file,
+    };
     use hedera_proto::services;
 
     use crate::pending_airdrop_id::PendingAirdropId;
@@ -221,6 +229,64 @@ mod tests {
             .assert_debug_eq(&tx);
     }
 
+    #[test]
+    fn serialize_single() {
+        let mut tx = TokenClaimAirdropTransaction::new_for_tests();
+
+        tx.add_pending_airdrop_id(PendingAirdropId::new_token_id(
+            AccountId::new(0, 0, 5005),
+            AccountId::new(0, 0, 5006),
+            TokenId::new(0, 0, 312),
+        ))
+        .freeze()
+        .unwrap();
+
+        let tx = check_body(transaction_body(tx));
+
+        expect![[r#"
+            TokenClaimAirdrop(
+                TokenClaimAirdropTransactionBody {
+                    pending_airdrops: [
+                        PendingAirdropId {
+                            sender_id: Some(
+                                AccountId {
+                                    shard_num: 0,
+                                    realm_num: 0,
+                                    account: Some(
+                                        AccountNum(
+                                            5005,
+                                        ),
+                                    ),
+                                },
+                            ),
+                            receiver_id: Some(
+                                AccountId {
+                                    shard_num: 0,
+                                    realm_num: 0,
+                                    account: Some(
+                                        AccountNum(
+                                            5006,
+                                        ),
+                                    ),
+                                },
+                            ),
+                            token_reference: Some(
+                                FungibleTokenType(
+                                    TokenId {
+                                        shard_num: 0,
+                                        realm_num: 0,
+                                        token_num: 312,
+                                    },
+                                ),
+                            ),
+                        },
+                    ],
+                },
+            )
+        "#]]
+        .assert_debug_eq(&tx);
+    }
+
     #[test]
     fn to_from_bytes() {
         let tx = make_transaction();
@@ -37,6 +37,7 @@ use crate::token::token_type::TokenType;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -240,8 +241,8 @@ impl TokenCreateTransaction {
     }
 
     /// Sets the account which will act as a treasury for the token.
-    pub fn treasury_account_id(&mut self, treasury_account_id: AccountId) -> &mut Self {
-        self.data_mut().treasury_account_id = Some(treasury_account_id);
+    pub fn treasury_account_id(&mut self, treasury_account_id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().treasury_account_id = Some(treasury_account_id.into());
         self
     }
 
@@ -341,8 +342,11 @@ impl TokenCreateTransaction {
     }
 
     /// Sets the account which will be automatically charged to renew the token's expiration.
-    pub fn auto_renew_account_id(&mut self, auto_renew_account_id: AccountId) -> &mut Self {
-        self.data_mut().auto_renew_account_id = Some(auto_renew_account_id);
+    pub fn auto_renew_account_id(
+        &mut self,
+        auto_renew_account_id: impl Into<AccountId>,
+    ) -> &mut Self {
+        self.data_mut().auto_renew_account_id = Some(auto_renew_account_id.into());
         self
     }
 
@@ -488,11 +492,18 @@ impl TransactionExecute for TokenCreateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { TokenServiceClient::new(channel).create_token(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenCreateTransactionData {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
-        // TODO: validate custom fees.
+        for fee in &self.custom_fees {
+            fee.validate_checksums(ledger_id)?;
+        }
+
         self.treasury_account_id.validate_checksums(ledger_id)?;
         self.auto_renew_account_id.validate_checksums(ledger_id)
     }
@@ -503,7 +514,7 @@ impl ToTransactionDataProtobuf for TokenCreateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenCreation(self.to_protobuf())
     }
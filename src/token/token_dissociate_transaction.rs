@@ -30,6 +30,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -80,8 +81,8 @@ impl TokenDissociateTransaction {
     }
 
     /// Sets the account to be dissociated with the provided tokens.
-    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
-        self.data_mut().account_id = Some(account_id);
+    pub fn account_id(&mut self, account_id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().account_id = Some(account_id.into());
         self
     }
 
@@ -108,6 +109,10 @@ impl TransactionExecute for TokenDissociateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { TokenServiceClient::new(channel).dissociate_tokens(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenDissociateTransactionData {
@@ -125,7 +130,7 @@ impl ToTransactionDataProtobuf for TokenDissociateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenDissociate(self.to_protobuf())
     }
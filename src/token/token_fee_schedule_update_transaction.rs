@@ -30,6 +30,7 @@ use crate::token::custom_fees::AnyCustomFee;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -104,11 +105,18 @@ impl TransactionExecute for TokenFeeScheduleUpdateTransactionData {
             TokenServiceClient::new(channel).update_token_fee_schedule(request).await
         })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenFeeScheduleUpdateTransactionData {
     fn validate_checksums(&self, ledger_id: &crate::ledger_id::RefLedgerId) -> Result<(), Error> {
-        // TODO: validate custom fees (they need an impl)
+        for fee in &self.custom_fees {
+            fee.validate_checksums(ledger_id)?;
+        }
+
         self.token_id.validate_checksums(ledger_id)
     }
 }
@@ -118,7 +126,7 @@ impl ToTransactionDataProtobuf for TokenFeeScheduleUpdateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenFeeScheduleUpdate(self.to_protobuf())
     }
@@ -30,6 +30,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -75,8 +76,8 @@ impl TokenFreezeTransaction {
     }
 
     /// Sets the account to be frozen.
-    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
-        self.data_mut().account_id = Some(account_id);
+    pub fn account_id(&mut self, account_id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().account_id = Some(account_id.into());
         self
     }
 
@@ -103,6 +104,10 @@ impl TransactionExecute for TokenFreezeTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { TokenServiceClient::new(channel).freeze_token_account(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenFreezeTransactionData {
@@ -117,7 +122,7 @@ impl ToTransactionDataProtobuf for TokenFreezeTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenFreeze(self.to_protobuf())
     }
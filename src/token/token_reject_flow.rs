@@ -105,8 +105,8 @@ impl TokenRejectFlow {
     }
 
     /// Adds a token ID to the list of token IDs.
-    pub fn add_token_id(&mut self, token_id: TokenId) -> &mut Self {
-        self.token_reject_data.token_ids.push(token_id);
+    pub fn add_token_id(&mut self, token_id: impl Into<TokenId>) -> &mut Self {
+        self.token_reject_data.token_ids.push(token_id.into());
 
         self
     }
@@ -125,8 +125,8 @@ impl TokenRejectFlow {
     }
 
     /// Adds an NFT ID to the list of NFT IDs.
-    pub fn add_nft_id(&mut self, nft_id: NftId) -> &mut Self {
-        self.token_reject_data.nft_ids.push(nft_id);
+    pub fn add_nft_id(&mut self, nft_id: impl Into<NftId>) -> &mut Self {
+        self.token_reject_data.nft_ids.push(nft_id.into());
 
         self
     }
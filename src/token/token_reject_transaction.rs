@@ -30,6 +30,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -95,8 +96,8 @@ impl TokenRejectTransaction {
     }
 
     /// Appends a Fungible token to the list of rejected tokens.
-    pub fn add_token_id(&mut self, token_id: TokenId) -> &mut Self {
-        self.data_mut().token_ids.push(token_id);
+    pub fn add_token_id(&mut self, token_id: impl Into<TokenId>) -> &mut Self {
+        self.data_mut().token_ids.push(token_id.into());
         self
     }
 
@@ -113,13 +114,23 @@ impl TokenRejectTransaction {
     }
 
     /// Appends a Non-Fungible token to the list of rejected nfts.
-    pub fn add_nft_id(&mut self, nft_id: NftId) -> &mut Self {
-        self.data_mut().nft_ids.push(nft_id);
+    pub fn add_nft_id(&mut self, nft_id: impl Into<NftId>) -> &mut Self {
+        self.data_mut().nft_ids.push(nft_id.into());
         self
     }
 }
 
-impl TransactionData for TokenRejectTransactionData {}
+impl TransactionData for TokenRejectTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        if self.token_ids.is_empty() && self.nft_ids.is_empty() {
+            return Err(Error::invalid_transaction(
+                "a token reject needs at least one token or NFT to reject",
+            ));
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenRejectTransactionData {
     fn execute(
@@ -129,11 +140,25 @@ impl TransactionExecute for TokenRejectTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { TokenServiceClient::new(channel).reject_token(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenRejectTransactionData {
     fn validate_checksums(&self, ledger_id: &crate::ledger_id::RefLedgerId) -> Result<(), Error> {
-        self.owner.validate_checksums(ledger_id)
+        self.owner.validate_checksums(ledger_id)?;
+
+        for token_id in &self.token_ids {
+            token_id.validate_checksums(ledger_id)?;
+        }
+
+        for nft_id in &self.nft_ids {
+            nft_id.validate_checksums(ledger_id)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -142,7 +167,7 @@ impl ToTransactionDataProtobuf for TokenRejectTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenReject(self.to_protobuf())
     }
@@ -214,8 +239,11 @@ impl ToProtobuf for TokenRejectTransactionData {
 
 #[cfg(test)]
 mod tests {
-
-    use expect_test::expect_file;
+    use assert_matches::assert_matches;
+    use expect_test::{
+        expect,
+        expect_file,
+    };
     use hedera_proto::services::{
         token_reference,
         TokenReference,
@@ -235,7 +263,12 @@ mod tests {
         TEST_NFT_IDS,
         TEST_TOKEN_IDS,
     };
-    use crate::AnyTransaction;
+    use crate::{
+        AnyTransaction,
+        Client,
+        Error,
+        TokenId,
+    };
 
     fn make_transaction() -> TokenRejectTransaction {
         let mut tx = TokenRejectTransaction::new_for_tests();
@@ -255,6 +288,118 @@ mod tests {
         expect_file!["./snapshots/token_reject_transaction/serialize.txt"].assert_debug_eq(&tx);
     }
 
+    #[test]
+    fn serialize_fungible() {
+        let mut tx = TokenRejectTransaction::new_for_tests();
+        tx.owner(TEST_ACCOUNT_ID).add_token_id(TEST_TOKEN_IDS[0]).freeze().unwrap();
+
+        let tx = check_body(transaction_body(tx));
+
+        expect![[r#"
+            TokenReject(
+                TokenRejectTransactionBody {
+                    owner: Some(
+                        AccountId {
+                            shard_num: 0,
+                            realm_num: 0,
+                            account: Some(
+                                AccountNum(
+                                    5006,
+                                ),
+                            ),
+                        },
+                    ),
+                    rejections: [
+                        TokenReference {
+                            token_identifier: Some(
+                                FungibleToken(
+                                    TokenId {
+                                        shard_num: 1,
+                                        realm_num: 2,
+                                        token_num: 3,
+                                    },
+                                ),
+                            ),
+                        },
+                    ],
+                },
+            )
+        "#]]
+        .assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn serialize_nft() {
+        let mut tx = TokenRejectTransaction::new_for_tests();
+        tx.owner(TEST_ACCOUNT_ID).add_nft_id(TEST_NFT_IDS[0]).freeze().unwrap();
+
+        let tx = check_body(transaction_body(tx));
+
+        expect![[r#"
+            TokenReject(
+                TokenRejectTransactionBody {
+                    owner: Some(
+                        AccountId {
+                            shard_num: 0,
+                            realm_num: 0,
+                            account: Some(
+                                AccountNum(
+                                    5006,
+                                ),
+                            ),
+                        },
+                    ),
+                    rejections: [
+                        TokenReference {
+                            token_identifier: Some(
+                                Nft(
+                                    NftId {
+                                        token_id: Some(
+                                            TokenId {
+                                                shard_num: 4,
+                                                realm_num: 2,
+                                                token_num: 3,
+                                            },
+                                        ),
+                                        serial_number: 1,
+                                    },
+                                ),
+                            ),
+                        },
+                    ],
+                },
+            )
+        "#]]
+        .assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn freeze_requires_rejections() {
+        let mut tx = TokenRejectTransaction::new_for_tests();
+        tx.owner(TEST_ACCOUNT_ID);
+
+        assert_matches!(tx.freeze(), Err(Error::InvalidTransaction(_)));
+    }
+
+    #[test]
+    fn freeze_validates_rejection_checksums() {
+        let client = Client::for_mainnet();
+        client.set_auto_validate_checksums(true);
+
+        // a testnet checksum, which is wrong for mainnet.
+        let token_id: TokenId = "0.0.123-esxsf".parse().unwrap();
+
+        let mut tx = TokenRejectTransaction::new_for_tests();
+        tx.owner(TEST_ACCOUNT_ID).add_token_id(token_id);
+
+        assert_matches!(tx.freeze_with(&client), Err(Error::BadEntityId { num: 123, .. }));
+
+        let mut tx = TokenRejectTransaction::new_for_tests();
+        tx.owner(TEST_ACCOUNT_ID).add_nft_id(token_id.nft(1));
+
+        assert_matches!(tx.freeze_with(&client), Err(Error::BadEntityId { num: 123, .. }));
+    }
+
     #[test]
     fn to_from_bytes() {
         let tx = make_transaction();
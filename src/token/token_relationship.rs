@@ -0,0 +1,143 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::{
+    TokenFreezeStatus,
+    TokenKycStatus,
+};
+
+use crate::protobuf::ToProtobuf;
+use crate::{
+    FromProtobuf,
+    TokenId,
+};
+
+/// Token's information related to the given account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenRelationship {
+    /// The ID of the token.
+    pub token_id: TokenId,
+
+    /// The symbol of the token.
+    pub symbol: String,
+
+    /// The balance that the account holds in the smallest denomination.
+    pub balance: u64,
+
+    /// The KYC status of the account (`None` = not applicable).
+    pub kyc_status: Option<bool>,
+
+    /// The freeze status of the account (`None` = not applicable).
+    pub freeze_status: Option<bool>,
+
+    /// The number of decimal places a token is divisible by.
+    pub decimals: u32,
+
+    /// Whether the relationship was created implicitly.
+    pub automatic_association: bool,
+}
+
+impl FromProtobuf<services::TokenRelationship> for TokenRelationship {
+    fn from_protobuf(pb: services::TokenRelationship) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        let kyc_status = match pb.kyc_status() {
+            TokenKycStatus::KycNotApplicable => None,
+            TokenKycStatus::Granted => Some(true),
+            TokenKycStatus::Revoked => Some(false),
+        };
+
+        let freeze_status = match pb.freeze_status() {
+            TokenFreezeStatus::FreezeNotApplicable => None,
+            TokenFreezeStatus::Frozen => Some(true),
+            TokenFreezeStatus::Unfrozen => Some(false),
+        };
+
+        let token_id = pb_getf!(pb, token_id)?;
+
+        Ok(Self {
+            token_id: TokenId::from_protobuf(token_id)?,
+            symbol: pb.symbol,
+            balance: pb.balance,
+            kyc_status,
+            freeze_status,
+            decimals: pb.decimals,
+            automatic_association: pb.automatic_association,
+        })
+    }
+}
+
+impl ToProtobuf for TokenRelationship {
+    type Protobuf = services::TokenRelationship;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        let kyc_status = match self.kyc_status {
+            Some(true) => TokenKycStatus::Granted as i32,
+            Some(false) => TokenKycStatus::Revoked as i32,
+            None => TokenKycStatus::KycNotApplicable as i32,
+        };
+
+        let freeze_status = match self.freeze_status {
+            Some(true) => TokenFreezeStatus::Frozen as i32,
+            Some(false) => TokenFreezeStatus::Unfrozen as i32,
+            None => TokenFreezeStatus::FreezeNotApplicable as i32,
+        };
+
+        services::TokenRelationship {
+            token_id: Some(self.token_id.to_protobuf()),
+            symbol: self.symbol.clone(),
+            balance: self.balance,
+            kyc_status,
+            freeze_status,
+            decimals: self.decimals,
+            automatic_association: self.automatic_association,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::transaction::test_helpers::TEST_TOKEN_ID;
+    use crate::TokenRelationship;
+
+    #[test]
+    fn to_from_protobuf() {
+        let relationship = TokenRelationship {
+            token_id: TEST_TOKEN_ID,
+            symbol: "F".to_owned(),
+            balance: 15,
+            kyc_status: Some(true),
+            freeze_status: None,
+            decimals: 2,
+            automatic_association: true,
+        };
+
+        assert_eq!(
+            TokenRelationship::from_protobuf(relationship.to_protobuf()).unwrap(),
+            relationship
+        );
+    }
+}
@@ -30,6 +30,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -74,8 +75,8 @@ impl TokenRevokeKycTransaction {
         self.data().account_id
     }
     /// Sets the account to have their KYC revoked.
-    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
-        self.data_mut().account_id = Some(account_id);
+    pub fn account_id(&mut self, account_id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().account_id = Some(account_id.into());
         self
     }
 
@@ -104,6 +105,10 @@ impl TransactionExecute for TokenRevokeKycTransactionData {
             TokenServiceClient::new(channel).revoke_kyc_from_token_account(request).await
         })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenRevokeKycTransactionData {
@@ -118,7 +123,7 @@ impl ToTransactionDataProtobuf for TokenRevokeKycTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenRevokeKyc(self.to_protobuf())
     }
@@ -35,6 +35,7 @@ use crate::token::token_key_validation_type::TokenKeyValidation;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -186,8 +187,8 @@ impl TokenUpdateTransaction {
     ///
     /// If successful, the token balance held in the previous treasury account is transferred to the
     /// new one.
-    pub fn treasury_account_id(&mut self, treasury_account_id: AccountId) -> &mut Self {
-        self.data_mut().treasury_account_id = Some(treasury_account_id);
+    pub fn treasury_account_id(&mut self, treasury_account_id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().treasury_account_id = Some(treasury_account_id.into());
         self
     }
 
@@ -268,8 +269,11 @@ impl TokenUpdateTransaction {
     }
 
     /// Sets the new account which will be automatically charged to renew the token's expiration.
-    pub fn auto_renew_account_id(&mut self, auto_renew_account_id: AccountId) -> &mut Self {
-        self.data_mut().auto_renew_account_id = Some(auto_renew_account_id);
+    pub fn auto_renew_account_id(
+        &mut self,
+        auto_renew_account_id: impl Into<AccountId>,
+    ) -> &mut Self {
+        self.data_mut().auto_renew_account_id = Some(auto_renew_account_id.into());
         self
     }
 
@@ -397,6 +401,10 @@ impl TransactionExecute for TokenUpdateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { TokenServiceClient::new(channel).update_token(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Token
+    }
 }
 
 impl ValidateChecksums for TokenUpdateTransactionData {
@@ -412,7 +420,7 @@ impl ToTransactionDataProtobuf for TokenUpdateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::TokenUpdate(self.to_protobuf())
     }
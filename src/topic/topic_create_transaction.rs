@@ -31,6 +31,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -149,8 +150,8 @@ impl TopicCreateTransaction {
     }
 
     /// Sets the account to be used at the topic's expiration time to extend the life of the topic.
-    pub fn auto_renew_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().auto_renew_account_id = Some(id);
+    pub fn auto_renew_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().auto_renew_account_id = Some(id.into());
         self
     }
 }
@@ -165,6 +166,10 @@ impl TransactionExecute for TopicCreateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { ConsensusServiceClient::new(channel).create_topic(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Consensus
+    }
 }
 
 impl ValidateChecksums for TopicCreateTransactionData {
@@ -178,7 +183,7 @@ impl ToTransactionDataProtobuf for TopicCreateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::ConsensusCreateTopic(self.to_protobuf())
     }
@@ -30,6 +30,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -82,6 +83,10 @@ impl TransactionExecute for TopicDeleteTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { ConsensusServiceClient::new(channel).delete_topic(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Consensus
+    }
 }
 
 impl ValidateChecksums for TopicDeleteTransactionData {
@@ -95,7 +100,7 @@ impl ToTransactionDataProtobuf for TopicDeleteTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::ConsensusDeleteTopic(self.to_protobuf())
     }
@@ -98,15 +98,18 @@ impl TopicId {
     }
 
     /// Convert `self` to a string with a valid checksum.
-    #[must_use]
-    pub fn to_string_with_checksum(&self, client: &Client) -> String {
-        EntityId::to_string_with_checksum(self.to_string(), client)
+    ///
+    /// # Errors
+    /// - [`Error::CannotCreateChecksumWithoutLedgerId`] if the client has no `ledger_id`.
+    pub fn to_string_with_checksum(&self, client: &Client) -> crate::Result<String> {
+        EntityId::to_string_with_checksum(self.shard, self.realm, self.num, client)
     }
 
     /// Validates `self.checksum` (if it exists) for `client`.
     ///
     /// # Errors
     /// - [`Error::BadEntityId`] if there is a checksum, and the checksum is not valid for the client's `ledger_id`.
+    /// - [`Error::CannotValidateChecksumWithoutLedgerId`] if there is a checksum, and the client has no `ledger_id`.
     pub fn validate_checksum(&self, client: &Client) -> crate::Result<()> {
         EntityId::validate_checksum(self.shard, self.realm, self.num, self.checksum, client)
     }
@@ -215,4 +218,9 @@ mod tests {
         expect!["000000000000000000000000000000000000138d"]
             .assert_eq(&TopicId::new(0, 0, 5005).to_solidity_address().unwrap());
     }
+
+    #[test]
+    fn from_u64() {
+        assert_eq!(TopicId::from(5005), TopicId::new(0, 0, 5005));
+    }
 }
@@ -107,7 +107,10 @@ impl FromProtobuf<services::ConsensusGetTopicInfoResponse> for TopicInfo {
         let submit_key = Option::from_protobuf(info.submit_key)?;
         let expiration_time = info.expiration_time.map(Into::into);
         let auto_renew_period = info.auto_renew_period.map(Into::into);
-        let auto_renew_account_id = Option::from_protobuf(info.auto_renew_account)?;
+        // topics without an auto renew account may come back with an empty (or `0.0.0`) account instead of none at all.
+        let auto_renew_account_id = Option::from_protobuf(info.auto_renew_account.filter(|it| {
+            !matches!(it.account, None | Some(services::account_id::Account::AccountNum(0)))
+        }))?;
         let ledger_id = LedgerId::from_bytes(info.ledger_id);
 
         Ok(Self {
@@ -152,6 +155,10 @@ mod tests {
     use expect_test::expect;
     use hedera_proto::services;
     use prost::Message;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
 
     use crate::protobuf::{
         FromProtobuf,
@@ -160,6 +167,7 @@ mod tests {
     use crate::transaction::test_helpers::unused_private_key;
     use crate::{
         LedgerId,
+        TopicId,
         TopicInfo,
     };
 
@@ -223,6 +231,30 @@ mod tests {
         .assert_debug_eq(&TopicInfo::from_protobuf(make_info()).unwrap())
     }
 
+    #[test]
+    fn from_bytes_without_auto_renew_account() {
+        let mut info = make_info();
+        let topic_info = info.topic_info.as_mut().unwrap();
+
+        topic_info.auto_renew_account = None;
+        let info_none = TopicInfo::from_bytes(&info.encode_to_vec()).unwrap();
+
+        info.topic_info.as_mut().unwrap().auto_renew_account = Some(services::AccountId::default());
+        let info_empty = TopicInfo::from_bytes(&info.encode_to_vec()).unwrap();
+
+        for info in [info_none, info_empty] {
+            assert_eq!(info.auto_renew_account_id, None);
+            assert_eq!(info.topic_id, TopicId::new(1, 2, 3));
+            assert_eq!(info.running_hash, [2]);
+            assert_eq!(info.sequence_number, 3);
+            assert_eq!(info.auto_renew_period, Some(Duration::days(5)));
+            assert_eq!(
+                info.expiration_time,
+                Some(OffsetDateTime::UNIX_EPOCH + Duration::milliseconds(4))
+            );
+        }
+    }
+
     #[test]
     fn to_protobuf() {
         expect![[r#"
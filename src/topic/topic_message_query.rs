@@ -27,7 +27,10 @@ use std::{
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_core::Stream;
-use futures_util::TryStreamExt;
+use futures_util::{
+    StreamExt,
+    TryStreamExt,
+};
 use hedera_proto::mirror;
 use hedera_proto::mirror::consensus_service_client::ConsensusServiceClient;
 use hedera_proto::mirror::ConsensusTopicQuery;
@@ -65,6 +68,7 @@ use crate::{
 #[derive(Default)]
 pub struct TopicMessageQueryContext {
     start_time: Option<OffsetDateTime>,
+    received: u64,
 }
 
 /// Query a stream of Hedera Consensus Service (HCS)
@@ -157,7 +161,7 @@ impl From<TopicMessageQueryData> for AnyMirrorQueryData {
 impl MirrorRequest for TopicMessageQueryData {
     type GrpcItem = mirror::ConsensusTopicResponse;
 
-    type ConnectStream = tonic::Streaming<Self::GrpcItem>;
+    type ConnectStream = BoxStream<'static, tonic::Result<Self::GrpcItem>>;
 
     type Context = TopicMessageQueryContext;
 
@@ -176,26 +180,32 @@ impl MirrorRequest for TopicMessageQueryData {
 
         let consensus_end_time = self.end_time.map(Into::into);
 
-        // If we had to reconnect, we want to start 1ns after the last message we recieved.
-        // We don't want to start *at* the last message we recieved because that'd give us that message again.
+        // If we had to reconnect, we want to start 1ns after the last message we received.
+        // We don't want to start *at* the last message we received because that'd give us that message again.
         let consensus_start_time = context
             .start_time
             .map(|it| it.checked_add(Duration::nanoseconds(1)).unwrap())
             .or(self.start_time)
             .map(Into::into);
 
-        let request = ConsensusTopicQuery {
-            consensus_end_time,
-            consensus_start_time,
-            topic_id,
-            limit: self.limit,
+        // The messages we've already received count towards the limit,
+        // and a limit of `0` means "unlimited", so, if we've already hit the limit there's nothing left to ask for.
+        let limit = match self.limit {
+            0 => 0,
+            limit if context.received >= limit => {
+                return Box::pin(async { Ok(futures_util::stream::empty().boxed()) })
+            }
+            limit => limit - context.received,
         };
 
+        let request =
+            ConsensusTopicQuery { consensus_end_time, consensus_start_time, topic_id, limit };
+
         Box::pin(async {
             ConsensusServiceClient::new(channel)
                 .subscribe_topic(request)
                 .await
-                .map(Response::into_inner)
+                .map(|it| Response::into_inner(it).boxed())
         })
     }
 
@@ -217,6 +227,7 @@ impl MirrorRequest for TopicMessageQueryData {
     fn update_context(context: &mut Self::Context, item: &Self::GrpcItem) {
         context.start_time =
             item.consensus_timestamp.map(OffsetDateTime::from).or(context.start_time);
+        context.received += 1;
     }
 }
 
@@ -351,13 +362,140 @@ fn filter_map(
 
 #[cfg(test)]
 mod tests {
-    use time::OffsetDateTime;
+    use futures_util::TryStreamExt;
+    use hedera_proto::{
+        mirror,
+        services,
+    };
+    use parking_lot::Mutex;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+    use triomphe::Arc;
 
+    use crate::mock::{
+        self,
+        MockServerStreaming,
+        ServerStreaming,
+    };
     use crate::{
+        AccountId,
+        Client,
+        ToProtobuf,
         TopicId,
         TopicMessageQuery,
+        TransactionId,
     };
 
+    /// A mirror `ConsensusService` that sends the first chunk of a two chunk message and then drops the connection,
+    /// sending the second chunk once the subscriber reconnects.
+    #[derive(Clone, Default)]
+    struct MockConsensusService {
+        requests: Arc<Mutex<Vec<mirror::ConsensusTopicQuery>>>,
+    }
+
+    impl MockConsensusService {
+        /// Serves `self` on a random local port, returning its address.
+        async fn serve(self) -> String {
+            mock::serve(ServerStreaming(self)).await
+        }
+    }
+
+    impl MockServerStreaming for MockConsensusService {
+        const SERVICE: &'static str = "com.hedera.mirror.api.proto.ConsensusService";
+        const METHOD: &'static str = "subscribeTopic";
+
+        type Request = mirror::ConsensusTopicQuery;
+        type Response = mirror::ConsensusTopicResponse;
+
+        fn call(
+            &self,
+            request: mirror::ConsensusTopicQuery,
+        ) -> Vec<Result<mirror::ConsensusTopicResponse, tonic::Status>> {
+            let mut requests = self.requests.lock();
+            requests.push(request);
+
+            match requests.len() {
+                1 => vec![Ok(chunk(1, b"hello, ")), Err(tonic::Status::unavailable("mock"))],
+                _ => vec![Ok(chunk(2, b"world"))],
+            }
+        }
+    }
+
+    fn initial_transaction_id() -> TransactionId {
+        TransactionId {
+            account_id: AccountId::new(0, 0, 5006),
+            valid_start: OffsetDateTime::from_unix_timestamp(1_554_158_542).unwrap(),
+            nonce: None,
+            scheduled: false,
+        }
+    }
+
+    fn consensus_timestamp(number: i32) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1_554_158_600 + i64::from(number)).unwrap()
+    }
+
+    fn chunk(number: i32, message: &[u8]) -> mirror::ConsensusTopicResponse {
+        mirror::ConsensusTopicResponse {
+            consensus_timestamp: Some(consensus_timestamp(number).into()),
+            message: message.to_vec(),
+            running_hash: vec![number as u8; 48],
+            sequence_number: number as u64,
+            running_hash_version: 3,
+            chunk_info: Some(services::ConsensusMessageChunkInfo {
+                initial_transaction_id: Some(initial_transaction_id().to_protobuf()),
+                total: 2,
+                number,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn subscribe_reassembles_chunks_across_reconnect() {
+        let service = MockConsensusService::default();
+        let address = service.clone().serve().await;
+
+        let client = Client::for_testnet();
+        client.set_mirror_network([address]);
+
+        let messages: Vec<_> = TopicMessageQuery::new()
+            .topic_id(TopicId::new(0, 0, 5005))
+            .limit(2)
+            .subscribe(&client)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(messages.len(), 1);
+
+        let message = &messages[0];
+
+        assert_eq!(message.contents, b"hello, world");
+        assert_eq!(message.consensus_timestamp, consensus_timestamp(2));
+        assert_eq!(message.running_hash, vec![2; 48]);
+        assert_eq!(message.sequence_number, 2);
+        assert_eq!(message.transaction, Some(initial_transaction_id()));
+
+        let chunks = message.chunks.as_deref().unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].sequence_number, 1);
+        assert_eq!(chunks[0].content_size, 7);
+
+        let requests = service.requests.lock();
+
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].limit, 2);
+
+        // the reconnect resumes right after the last message received, only asking for what's left.
+        assert_eq!(requests[1].limit, 1);
+        assert_eq!(
+            requests[1].consensus_start_time,
+            Some((consensus_timestamp(1) + Duration::nanoseconds(1)).into())
+        );
+        assert_eq!(requests[1].topic_id, requests[0].topic_id);
+    }
+
     #[test]
     fn get_set_topic_id() {
         let mut query = TopicMessageQuery::new();
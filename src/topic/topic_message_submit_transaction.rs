@@ -35,6 +35,7 @@ use crate::transaction::{
     ChunkData,
     ChunkInfo,
     ChunkedTransactionData,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -122,6 +123,14 @@ impl TransactionExecute for TopicMessageSubmitTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { ConsensusServiceClient::new(channel).submit_message(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Consensus
+    }
+
+    fn max_chunks(&self) -> usize {
+        self.chunk_data.max_chunks
+    }
 }
 
 impl TransactionExecuteChunked for TopicMessageSubmitTransactionData {}
@@ -132,6 +141,7 @@ impl ValidateChecksums for TopicMessageSubmitTransactionData {
     }
 }
 
+// note: there's no `ToProtobuf` impl, the body depends on which chunk is being sent.
 impl ToTransactionDataProtobuf for TopicMessageSubmitTransactionData {
     fn to_transaction_data_protobuf(
         &self,
@@ -34,6 +34,7 @@ use crate::protobuf::{
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -176,8 +177,8 @@ impl TopicUpdateTransaction {
     }
 
     /// Sets the account to be used at the topic's expiration time to extend the life of the topic.
-    pub fn auto_renew_account_id(&mut self, id: AccountId) -> &mut Self {
-        self.data_mut().auto_renew_account_id = Some(id);
+    pub fn auto_renew_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.data_mut().auto_renew_account_id = Some(id.into());
         self
     }
 
@@ -204,6 +205,10 @@ impl TransactionExecute for TopicUpdateTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { ConsensusServiceClient::new(channel).update_topic(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Consensus
+    }
 }
 
 impl ValidateChecksums for TopicUpdateTransactionData {
@@ -218,7 +223,7 @@ impl ToTransactionDataProtobuf for TopicUpdateTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::ConsensusUpdateTopic(self.to_protobuf())
     }
@@ -287,6 +292,7 @@ mod tests {
         VALID_START,
     };
     use crate::{
+        AccountId,
         AnyTransaction,
         TopicId,
         TopicUpdateTransaction,
@@ -386,7 +392,7 @@ mod tests {
 
         tx.topic_id("0.0.5007".parse::<TopicId>().unwrap())
             .admin_key(unused_private_key().public_key())
-            .auto_renew_account_id("0.0.5009".parse().unwrap())
+            .auto_renew_account_id("0.0.5009".parse::<AccountId>().unwrap())
             .auto_renew_period(Duration::days(1))
             .submit_key(unused_private_key().public_key())
             .topic_memo("Hello memo")
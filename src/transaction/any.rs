@@ -31,6 +31,7 @@ use crate::entity_id::ValidateChecksums;
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
+    Service,
     ToTransactionDataProtobuf,
     TransactionBody,
     TransactionExecute,
@@ -375,6 +376,60 @@ impl TransactionData for AnyTransactionData {
         }
     }
 
+    fn validate(&self) -> crate::Result<()> {
+        match self {
+            Self::Transfer(transaction) => transaction.validate(),
+            Self::AccountCreate(transaction) => transaction.validate(),
+            Self::AccountUpdate(transaction) => transaction.validate(),
+            Self::AccountDelete(transaction) => transaction.validate(),
+            Self::AccountAllowanceApprove(transaction) => transaction.validate(),
+            Self::AccountAllowanceDelete(transaction) => transaction.validate(),
+            Self::ContractCreate(transaction) => transaction.validate(),
+            Self::ContractUpdate(transaction) => transaction.validate(),
+            Self::ContractDelete(transaction) => transaction.validate(),
+            Self::ContractExecute(transaction) => transaction.validate(),
+            Self::FileAppend(transaction) => transaction.validate(),
+            Self::FileCreate(transaction) => transaction.validate(),
+            Self::FileUpdate(transaction) => transaction.validate(),
+            Self::FileDelete(transaction) => transaction.validate(),
+            Self::Prng(transaction) => transaction.validate(),
+            Self::TokenAssociate(transaction) => transaction.validate(),
+            Self::TokenBurn(transaction) => transaction.validate(),
+            Self::TokenCreate(transaction) => transaction.validate(),
+            Self::TokenDelete(transaction) => transaction.validate(),
+            Self::TokenDissociate(transaction) => transaction.validate(),
+            Self::TokenFeeScheduleUpdate(transaction) => transaction.validate(),
+            Self::TokenFreeze(transaction) => transaction.validate(),
+            Self::TokenGrantKyc(transaction) => transaction.validate(),
+            Self::TokenMint(transaction) => transaction.validate(),
+            Self::TokenPause(transaction) => transaction.validate(),
+            Self::TokenRevokeKyc(transaction) => transaction.validate(),
+            Self::TokenUnfreeze(transaction) => transaction.validate(),
+            Self::TokenUnpause(transaction) => transaction.validate(),
+            Self::TokenUpdate(transaction) => transaction.validate(),
+            Self::TokenWipe(transaction) => transaction.validate(),
+            Self::TopicCreate(transaction) => transaction.validate(),
+            Self::TopicUpdate(transaction) => transaction.validate(),
+            Self::TopicDelete(transaction) => transaction.validate(),
+            Self::TopicMessageSubmit(transaction) => transaction.validate(),
+            Self::SystemDelete(transaction) => transaction.validate(),
+            Self::SystemUndelete(transaction) => transaction.validate(),
+            Self::Freeze(transaction) => transaction.validate(),
+            Self::ScheduleCreate(transaction) => transaction.validate(),
+            Self::ScheduleSign(transaction) => transaction.validate(),
+            Self::ScheduleDelete(transaction) => transaction.validate(),
+            Self::Ethereum(transaction) => transaction.validate(),
+            Self::TokenUpdateNfts(transaction) => transaction.validate(),
+            Self::NodeCreate(transaction) => transaction.validate(),
+            Self::NodeUpdate(transaction) => transaction.validate(),
+            Self::NodeDelete(transaction) => transaction.validate(),
+            Self::TokenReject(transaction) => transaction.validate(),
+            Self::TokenAirdrop(transaction) => transaction.validate(),
+            Self::TokenClaimAirdrop(transaction) => transaction.validate(),
+            Self::TokenCancelAirdrop(transaction) => transaction.validate(),
+        }
+    }
+
     fn maybe_chunk_data(&self) -> Option<&super::ChunkData> {
         match self {
             Self::AccountCreate(it) => it.maybe_chunk_data(),
@@ -542,6 +597,77 @@ impl TransactionExecute for AnyTransactionData {
             Self::TokenCancelAirdrop(transaction) => transaction.execute(channel, request),
         }
     }
+
+    fn service(&self) -> Service {
+        match self {
+            Self::Transfer(transaction) => transaction.service(),
+            Self::AccountCreate(transaction) => transaction.service(),
+            Self::AccountUpdate(transaction) => transaction.service(),
+            Self::AccountDelete(transaction) => transaction.service(),
+            Self::AccountAllowanceApprove(transaction) => transaction.service(),
+            Self::AccountAllowanceDelete(transaction) => transaction.service(),
+            Self::ContractCreate(transaction) => transaction.service(),
+            Self::ContractUpdate(transaction) => transaction.service(),
+            Self::ContractDelete(transaction) => transaction.service(),
+            Self::ContractExecute(transaction) => transaction.service(),
+            Self::FileAppend(transaction) => transaction.service(),
+            Self::FileCreate(transaction) => transaction.service(),
+            Self::FileUpdate(transaction) => transaction.service(),
+            Self::FileDelete(transaction) => transaction.service(),
+            Self::Prng(transaction) => transaction.service(),
+            Self::TokenAssociate(transaction) => transaction.service(),
+            Self::TokenBurn(transaction) => transaction.service(),
+            Self::TokenCreate(transaction) => transaction.service(),
+            Self::TokenDelete(transaction) => transaction.service(),
+            Self::TokenDissociate(transaction) => transaction.service(),
+            Self::TokenFeeScheduleUpdate(transaction) => transaction.service(),
+            Self::TokenFreeze(transaction) => transaction.service(),
+            Self::TokenGrantKyc(transaction) => transaction.service(),
+            Self::TokenMint(transaction) => transaction.service(),
+            Self::TokenPause(transaction) => transaction.service(),
+            Self::TokenRevokeKyc(transaction) => transaction.service(),
+            Self::TokenUnfreeze(transaction) => transaction.service(),
+            Self::TokenUnpause(transaction) => transaction.service(),
+            Self::TokenUpdate(transaction) => transaction.service(),
+            Self::TokenWipe(transaction) => transaction.service(),
+            Self::TopicCreate(transaction) => transaction.service(),
+            Self::TopicUpdate(transaction) => transaction.service(),
+            Self::TopicDelete(transaction) => transaction.service(),
+            Self::TopicMessageSubmit(transaction) => transaction.service(),
+            Self::SystemDelete(transaction) => transaction.service(),
+            Self::SystemUndelete(transaction) => transaction.service(),
+            Self::Freeze(transaction) => transaction.service(),
+            Self::ScheduleCreate(transaction) => transaction.service(),
+            Self::ScheduleSign(transaction) => transaction.service(),
+            Self::ScheduleDelete(transaction) => transaction.service(),
+            Self::Ethereum(transaction) => transaction.service(),
+            Self::TokenUpdateNfts(transaction) => transaction.service(),
+            Self::NodeCreate(transaction) => transaction.service(),
+            Self::NodeUpdate(transaction) => transaction.service(),
+            Self::NodeDelete(transaction) => transaction.service(),
+            Self::TokenReject(transaction) => transaction.service(),
+            Self::TokenAirdrop(transaction) => transaction.service(),
+            Self::TokenClaimAirdrop(transaction) => transaction.service(),
+            Self::TokenCancelAirdrop(transaction) => transaction.service(),
+        }
+    }
+
+    fn scheduled_transaction_id(&self, transaction_id: &TransactionId) -> Option<TransactionId> {
+        match self {
+            Self::ScheduleCreate(transaction) => {
+                transaction.scheduled_transaction_id(transaction_id)
+            }
+            _ => None,
+        }
+    }
+
+    fn max_chunks(&self) -> usize {
+        match self {
+            Self::FileAppend(transaction) => transaction.max_chunks(),
+            Self::TopicMessageSubmit(transaction) => transaction.max_chunks(),
+            _ => 1,
+        }
+    }
 }
 
 impl TransactionExecuteChunked for AnyTransactionData {}
@@ -607,8 +733,26 @@ impl FromProtobuf<services::transaction_body::Data> for AnyTransactionData {
     where
         Self: Sized,
     {
+        Self::from_transaction_body_data(pb)
+    }
+}
+
+/// Creates the error for a transaction body variant that the SDK doesn't support.
+fn unsupported_transaction_kind(name: &str) -> Error {
+    Error::from_protobuf(format!("unsupported transaction kind `{name}`"))
+}
+
+impl AnyTransactionData {
+    /// Converts the `data` of a single transaction body into the matching transaction data.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if `data` is a transaction kind the SDK doesn't support,
+    ///   or if it fails to parse.
+    pub(crate) fn from_transaction_body_data(
+        data: services::transaction_body::Data,
+    ) -> crate::Result<Self> {
         use services::transaction_body::Data;
-        let data = match pb {
+        let data = match data {
             Data::ContractCall(pb) => data::ContractExecute::from_protobuf(pb)?.into(),
             Data::ContractCreateInstance(pb) => data::ContractCreate::from_protobuf(pb)?.into(),
             Data::ContractUpdateInstance(pb) => data::ContractUpdate::from_protobuf(pb)?.into(),
@@ -665,24 +809,16 @@ impl FromProtobuf<services::transaction_body::Data> for AnyTransactionData {
             Data::TokenClaimAirdrop(pb) => data::TokenClaimAirdrop::from_protobuf(pb)?.into(),
             Data::TokenCancelAirdrop(pb) => data::TokenCancelAirdrop::from_protobuf(pb)?.into(),
             Data::CryptoAddLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `AddLiveHashTransaction`",
-                ))
+                return Err(unsupported_transaction_kind("AddLiveHashTransaction"))
             }
             Data::CryptoDeleteLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `DeleteLiveHashTransaction`",
-                ))
+                return Err(unsupported_transaction_kind("DeleteLiveHashTransaction"))
             }
             Data::UncheckedSubmit(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `UncheckedSubmitTransaction`",
-                ))
+                return Err(unsupported_transaction_kind("UncheckedSubmitTransaction"))
             }
             Data::NodeStakeUpdate(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `NodeStakeUpdateTransaction`",
-                ))
+                return Err(unsupported_transaction_kind("NodeStakeUpdateTransaction"))
             }
         };
 
@@ -873,6 +1009,8 @@ impl AnyTransaction {
                     first_body,
                     transaction_id
                 )?)?),
+                generated_transaction_id: None,
+                payer_account_id: None,
                 operator: None,
                 is_frozen: true,
                 regenerate_transaction_id: Some(false),
@@ -1007,25 +1145,17 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
             Data::TokenClaimAirdrop(it) => Self::TokenClaimAirdrop(make_vec(it, len)),
             Data::TokenCancelAirdrop(it) => Self::TokenCancelAirdrop(make_vec(it, len)),
             Data::CryptoAddLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `AddLiveHashTransaction`",
-                ))
+                return Err(unsupported_transaction_kind("AddLiveHashTransaction"))
             }
             Data::CryptoDeleteLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `DeleteLiveHashTransaction`",
-                ))
+                return Err(unsupported_transaction_kind("DeleteLiveHashTransaction"))
             }
 
             Data::UncheckedSubmit(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `UncheckedSubmitTransaction`",
-                ))
+                return Err(unsupported_transaction_kind("UncheckedSubmitTransaction"))
             }
             Data::NodeStakeUpdate(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `NodeStakeUpdateTransaction`",
-                ))
+                return Err(unsupported_transaction_kind("NodeStakeUpdateTransaction"))
             }
         };
 
@@ -1132,6 +1262,8 @@ macro_rules! impl_cast_any {
                             max_transaction_fee: transaction.body.max_transaction_fee,
                             transaction_memo: transaction.body.transaction_memo,
                             transaction_id: transaction.body.transaction_id,
+                            generated_transaction_id: transaction.body.generated_transaction_id,
+                            payer_account_id: transaction.body.payer_account_id,
                             operator: transaction.body.operator,
                             is_frozen: transaction.body.is_frozen,
                             regenerate_transaction_id: transaction.body.regenerate_transaction_id,
@@ -55,7 +55,7 @@ impl ChunkData {
         }
 
         // div ceil algorithm, fun fact: the intrinsic `div_ceil` can't get rid of the panic (it's unstable anyway)
-        (self.data.len() + self.chunk_size.get()) / self.chunk_size
+        (self.data.len() + self.chunk_size.get() - 1) / self.chunk_size
     }
 
     pub(crate) fn message_chunk(&self, chunk_info: &ChunkInfo) -> &[u8] {
@@ -66,10 +66,6 @@ impl ChunkData {
 
         &self.data[start..end]
     }
-
-    pub(crate) fn max_message_len(&self) -> usize {
-        self.max_chunks * self.chunk_size.get()
-    }
 }
 
 pub struct ChunkInfo {
@@ -91,9 +87,21 @@ pub struct ChunkInfo {
 }
 
 impl ChunkInfo {
+    /// Returns the transaction ID and node account ID of the only chunk of a non-chunked transaction `D`.
+    ///
+    /// # Panics
+    /// - If `self` is part of a transaction split into several chunks, since `D` doesn't support chunking.
     #[must_use]
-    pub(crate) fn assert_single_transaction(&self) -> (TransactionId, AccountId) {
-        assert!(self.current == 0 && self.total == 1);
+    #[track_caller]
+    pub(crate) fn assert_single_transaction<D: ?Sized>(&self) -> (TransactionId, AccountId) {
+        assert!(
+            self.current == 0 && self.total == 1,
+            "BUG: `{}` doesn't support chunking, but was split into {} chunks (building chunk {})",
+            std::any::type_name::<D>().rsplit("::").next().unwrap_or_default(),
+            self.total,
+            self.current,
+        );
+
         (self.current_transaction_id, self.node_account_id)
     }
 
@@ -144,6 +152,10 @@ where
     }
 
     fn transaction_id(&self) -> Option<TransactionId> {
+        self.transaction.body.transaction_id
+    }
+
+    fn initial_transaction_id(&self) -> Option<TransactionId> {
         self.transaction.get_transaction_id()
     }
 
@@ -166,11 +178,11 @@ where
     ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo::initial(
+        self.transaction.make_request_inner(&ChunkInfo::initial(
             self.total_chunks,
             *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
             node_account_id,
-        )))
+        ))
     }
 
     fn execute(
@@ -192,13 +204,14 @@ where
             node_account_id,
             transaction_id: *transaction_id.unwrap(),
             transaction_hash: context,
+            scheduled_transaction_id: None,
             validate_status: true,
         })
     }
 
     fn make_error_pre_check(
         &self,
-        status: services::ResponseCodeEnum,
+        status: crate::Status,
         transaction_id: Option<&TransactionId>,
         response: Self::GrpcResponse,
     ) -> crate::Error {
@@ -268,13 +281,13 @@ where
     ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo {
+        self.transaction.make_request_inner(&ChunkInfo {
             total: self.total_chunks,
             current: self.current_chunk,
             initial_transaction_id: self.initial_transaction_id,
             node_account_id,
             current_transaction_id: *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-        }))
+        })
     }
 
     fn execute(
@@ -296,13 +309,14 @@ where
             node_account_id,
             transaction_id: *transaction_id.unwrap(),
             transaction_hash: context,
+            scheduled_transaction_id: None,
             validate_status: true,
         })
     }
 
     fn make_error_pre_check(
         &self,
-        status: services::ResponseCodeEnum,
+        status: crate::Status,
         transaction_id: Option<&TransactionId>,
         response: Self::GrpcResponse,
     ) -> crate::Error {
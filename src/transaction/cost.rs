@@ -34,6 +34,8 @@ impl<D: Clone> CostTransaction<D> {
                 max_transaction_fee: transaction.body.max_transaction_fee,
                 transaction_memo: transaction.body.transaction_memo,
                 transaction_id: transaction.body.transaction_id,
+                generated_transaction_id: transaction.body.generated_transaction_id,
+                payer_account_id: transaction.body.payer_account_id,
                 operator: transaction.body.operator,
                 is_frozen: transaction.body.is_frozen,
                 regenerate_transaction_id: transaction.body.regenerate_transaction_id,
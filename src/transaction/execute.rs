@@ -21,6 +21,8 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+use futures_util::stream::FuturesUnordered;
+use futures_util::StreamExt;
 use hedera_proto::services;
 use prost::Message;
 use tonic::transport::Channel;
@@ -29,10 +31,12 @@ use super::chunked::ChunkInfo;
 use super::source::SourceChunk;
 use super::{
     ChunkData,
+    Service,
     TransactionSources,
 };
 use crate::execute::Execute;
 use crate::ledger_id::RefLedgerId;
+use crate::signer::AnySigner;
 use crate::transaction::any::AnyTransactionData;
 use crate::transaction::protobuf::ToTransactionDataProtobuf;
 use crate::transaction::DEFAULT_TRANSACTION_VALID_DURATION;
@@ -81,36 +85,69 @@ impl From<(PublicKey, Vec<u8>)> for SignaturePair {
     }
 }
 
+/// Returns `true` if `signatures` already has a signature by `signer`.
+fn has_signature(signatures: &[services::SignaturePair], signer: &AnySigner) -> bool {
+    let public_key = signer.public_key().to_bytes();
+
+    signatures.iter().any(|it| public_key.starts_with(&it.pub_key_prefix))
+}
+
 impl<D> Transaction<D>
 where
     D: TransactionData + ToTransactionDataProtobuf,
 {
+    /// # Errors
+    /// - [`Error::AsyncSignerRequiresExecute`] if the operator or any signer signs asynchronously.
     pub(crate) fn make_request_inner(
         &self,
         chunk_info: &ChunkInfo,
-    ) -> (services::Transaction, TransactionHash) {
+    ) -> crate::Result<(services::Transaction, TransactionHash)> {
         assert!(self.is_frozen());
 
-        let transaction_body = self.to_transaction_body_protobuf(chunk_info);
-
-        let body_bytes = transaction_body.encode_to_vec();
+        let body_bytes = self.to_transaction_body_protobuf(chunk_info).encode_to_vec();
 
         let mut signatures = Vec::with_capacity(1 + self.signers.len());
 
-        if let Some(operator) = &self.body.operator {
-            let operator_signature = operator.sign(&body_bytes);
-
-            signatures.push(SignaturePair::from(operator_signature).into_protobuf());
+        for signer in self.request_signers() {
+            if !has_signature(&signatures, signer) {
+                signatures.push(SignaturePair::from(signer.sign(&body_bytes)?).into_protobuf());
+            }
         }
 
-        for signer in &self.signers {
-            let public_key = signer.public_key().to_bytes();
-            if !signatures.iter().any(|it| public_key.starts_with(&it.pub_key_prefix)) {
-                let signature = signer.sign(&body_bytes);
+        Ok(self.make_signed_request(body_bytes, signatures))
+    }
+
+    /// Like [`make_request_inner`](Self::make_request_inner), but supports async signers.
+    pub(crate) async fn make_request_inner_async(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> (services::Transaction, TransactionHash) {
+        assert!(self.is_frozen());
+
+        let body_bytes = self.to_transaction_body_protobuf(chunk_info).encode_to_vec();
+
+        let mut signatures = Vec::with_capacity(1 + self.signers.len());
+
+        for signer in self.request_signers() {
+            if !has_signature(&signatures, signer) {
+                let signature = signer.sign_async(&body_bytes).await;
                 signatures.push(SignaturePair::from(signature).into_protobuf());
             }
         }
 
+        self.make_signed_request(body_bytes, signatures)
+    }
+
+    /// Returns everything that signs each request: the operator, followed by the explicit signers.
+    fn request_signers(&self) -> impl Iterator<Item = &AnySigner> {
+        self.body.operator.iter().map(|it| &it.signer).chain(&self.signers)
+    }
+
+    fn make_signed_request(
+        &self,
+        body_bytes: Vec<u8>,
+        signatures: Vec<services::SignaturePair>,
+    ) -> (services::Transaction, TransactionHash) {
         let signed_transaction = services::SignedTransaction {
             body_bytes,
             sig_map: Some(services::SignatureMap { sig_pair: signatures }),
@@ -153,6 +190,14 @@ pub trait TransactionData: Clone + Into<AnyTransactionData> {
     fn wait_for_receipt(&self) -> bool {
         false
     }
+
+    /// Checks that `self` has every field the network requires, this is done when the transaction is frozen.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidTransaction`](crate::Error::InvalidTransaction) describing the first problem found.
+    fn validate(&self) -> crate::Result<()> {
+        Ok(())
+    }
 }
 
 pub trait TransactionExecute:
@@ -163,6 +208,23 @@ pub trait TransactionExecute:
         channel: Channel,
         request: services::Transaction,
     ) -> BoxGrpcFuture<'_, services::TransactionResponse>;
+
+    /// The service this transaction is submitted to.
+    fn service(&self) -> Service;
+
+    /// The ID of the transaction scheduled by this one (submitted with `transaction_id`), if any.
+    fn scheduled_transaction_id(&self, transaction_id: &TransactionId) -> Option<TransactionId> {
+        let _ = transaction_id;
+        None
+    }
+
+    /// The maximum number of chunks this transaction can be split into.
+    ///
+    /// Transactions that return more than `1` are executed chunk by chunk,
+    /// all others must be a single transaction (building a second chunk of those panics).
+    fn max_chunks(&self) -> usize {
+        1
+    }
 }
 
 impl<D> Execute for Transaction<D>
@@ -185,12 +247,19 @@ where
         self.body.transaction_id
     }
 
+    fn initial_transaction_id(&self) -> Option<TransactionId> {
+        self.get_transaction_id()
+    }
+
     fn requires_transaction_id(&self) -> bool {
         true
     }
 
     fn operator_account_id(&self) -> Option<&AccountId> {
-        self.body.operator.as_deref().map(|it| &it.account_id)
+        self.body
+            .payer_account_id
+            .as_ref()
+            .or_else(|| self.body.operator.as_deref().map(|it| &it.account_id))
     }
 
     fn regenerate_transaction_id(&self) -> Option<bool> {
@@ -204,10 +273,10 @@ where
     ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
         assert!(self.is_frozen());
 
-        Ok(self.make_request_inner(&ChunkInfo::single(
+        self.make_request_inner(&ChunkInfo::single(
             *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
             node_account_id,
-        )))
+        ))
     }
 
     fn execute(
@@ -225,10 +294,13 @@ where
         node_account_id: AccountId,
         transaction_id: Option<&TransactionId>,
     ) -> crate::Result<Self::Response> {
+        let transaction_id = *transaction_id.unwrap();
+
         Ok(TransactionResponse {
             node_account_id,
-            transaction_id: *transaction_id.unwrap(),
+            transaction_id,
             transaction_hash,
+            scheduled_transaction_id: self.body.data.scheduled_transaction_id(&transaction_id),
             validate_status: true,
         })
     }
@@ -310,11 +382,14 @@ pub(crate) struct SourceTransaction<'a, D> {
 }
 
 impl<'a, D> SourceTransaction<'a, D> {
-    pub(crate) fn new(transaction: &'a Transaction<D>, sources: &'a TransactionSources) -> Self {
+    pub(crate) fn new(
+        transaction: &'a Transaction<D>,
+        sources: &'a TransactionSources,
+    ) -> crate::Result<Self> {
         // fixme: be way more lazy.
-        let sources = sources.sign_with(&transaction.signers);
+        let sources = sources.sign_with(&transaction.signers)?;
 
-        Self { inner: transaction, sources }
+        Ok(Self { inner: transaction, sources })
     }
 
     pub(crate) async fn execute(
@@ -354,12 +429,62 @@ impl<'a, D> SourceTransaction<'a, D> {
 
         Ok(responses)
     }
+
+    /// Submits the (single chunk) transaction to up to `max_nodes` of its nodes at once,
+    /// returning the first success and dropping (cancelling) the other submissions.
+    pub(crate) async fn execute_parallel(
+        &self,
+        client: &Client,
+        max_nodes: usize,
+    ) -> crate::Result<TransactionResponse>
+    where
+        D: TransactionExecute,
+    {
+        if self.sources.chunks_len() != 1 {
+            return Err(Error::invalid_transaction(
+                "cannot execute a chunked transaction with multiple chunks in parallel",
+            ));
+        }
+
+        let chunk = self.sources.chunks().next().unwrap();
+
+        let views: Vec<_> = chunk
+            .node_ids()
+            .iter()
+            .take(max_nodes)
+            .map(|node_id| {
+                SourceTransactionExecuteView::new(self.inner, self.sources.chunks().next().unwrap())
+                    .with_node_ids(std::slice::from_ref(node_id))
+            })
+            .collect();
+
+        let mut submissions: FuturesUnordered<_> =
+            views.iter().map(|view| crate::execute::execute(client, view, None)).collect();
+
+        let mut last_error = None;
+
+        while let Some(result) = submissions.next().await {
+            match result {
+                Ok(response) => return Ok(response),
+
+                // the transaction itself is the problem, every other node will say the same.
+                Err(error @ Error::TransactionPreCheckStatus { .. }) => return Err(error),
+
+                Err(error) => last_error = Some(error),
+            }
+        }
+
+        // no error at all means there were no nodes to submit to.
+        Err(last_error.unwrap_or(Error::FreezeUnsetNodeAccountIds))
+    }
 }
 
 // fixme: better name.
 struct SourceTransactionExecuteView<'a, D> {
     transaction: &'a Transaction<D>,
     chunk: SourceChunk<'a>,
+    // the nodes to submit to, all of the chunk's nodes unless restricted with `with_node_ids`.
+    node_ids: &'a [AccountId],
     indecies_by_node_id: HashMap<AccountId, usize>,
 }
 
@@ -367,7 +492,14 @@ impl<'a, D> SourceTransactionExecuteView<'a, D> {
     fn new(transaction: &'a Transaction<D>, chunk: SourceChunk<'a>) -> Self {
         let indecies_by_node_id =
             chunk.node_ids().iter().copied().enumerate().map(|it| (it.1, it.0)).collect();
-        Self { transaction, chunk, indecies_by_node_id }
+        Self { transaction, node_ids: chunk.node_ids(), chunk, indecies_by_node_id }
+    }
+
+    /// Restricts the submission to `node_ids`, which must all be nodes of the chunk.
+    fn with_node_ids(self, node_ids: &'a [AccountId]) -> Self {
+        debug_assert!(node_ids.iter().all(|it| self.indecies_by_node_id.contains_key(it)));
+
+        Self { node_ids, ..self }
     }
 }
 
@@ -387,7 +519,7 @@ impl<'a, D: TransactionExecute> Execute for SourceTransactionExecuteView<'a, D>
     type Response = <Transaction<D> as Execute>::Response;
 
     fn node_account_ids(&self) -> Option<&[AccountId]> {
-        Some(self.chunk.node_ids())
+        Some(self.node_ids)
     }
 
     fn transaction_id(&self) -> Option<TransactionId> {
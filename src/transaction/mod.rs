@@ -39,11 +39,15 @@ use crate::{
     AccountId,
     Client,
     Error,
+    ExchangeRate,
+    FeeSchedule,
     Hbar,
     Operator,
     PrivateKey,
     PublicKey,
+    RequestType,
     ScheduleCreateTransaction,
+    Status,
     TransactionHash,
     TransactionId,
     TransactionResponse,
@@ -55,6 +59,7 @@ mod chunked;
 mod cost;
 mod execute;
 mod protobuf;
+mod service;
 mod source;
 #[cfg(test)]
 mod tests;
@@ -76,10 +81,15 @@ pub(crate) use protobuf::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
 };
+pub use service::Service;
 pub(crate) use source::TransactionSources;
 
 const DEFAULT_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(120);
 
+/// The network rejects transactions valid for durations outside of this range.
+const MIN_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(1);
+const MAX_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(180);
+
 /// A transaction that can be executed on the Hedera network.
 #[derive(Clone)]
 pub struct Transaction<D> {
@@ -104,6 +114,11 @@ pub(crate) struct TransactionBody<D> {
 
     pub(crate) transaction_id: Option<TransactionId>,
 
+    /// The transaction ID generated when freezing, if there was no explicit one.
+    pub(crate) generated_transaction_id: Option<TransactionId>,
+
+    pub(crate) payer_account_id: Option<AccountId>,
+
     pub(crate) operator: Option<Arc<Operator>>,
 
     pub(crate) is_frozen: bool,
@@ -124,6 +139,8 @@ where
                 max_transaction_fee: None,
                 transaction_memo: String::new(),
                 transaction_id: None,
+                generated_transaction_id: None,
+                payer_account_id: None,
                 operator: None,
                 is_frozen: false,
                 regenerate_transaction_id: None,
@@ -174,8 +191,87 @@ impl<D> Transaction<D> {
         self.sources.as_ref()
     }
 
-    fn signed_sources(&self) -> Option<Cow<'_, TransactionSources>> {
-        self.sources().map(|it| it.sign_with(&self.signers))
+    fn signed_sources(&self) -> crate::Result<Option<Cow<'_, TransactionSources>>> {
+        self.sources().map(|it| it.sign_with(&self.signers)).transpose()
+    }
+
+    /// Returns the account to generate transaction IDs for: the payer if one is set, otherwise the operator.
+    fn default_payer_account_id(&self) -> crate::Result<AccountId> {
+        self.body
+            .payer_account_id
+            .or_else(|| self.body.operator.as_ref().map(|it| it.account_id))
+            .ok_or(Error::NoPayerAccountOrTransactionId)
+    }
+
+    /// Generates a new transaction ID for the [`default_payer_account_id`](Self::default_payer_account_id).
+    fn generate_transaction_id(&self) -> crate::Result<TransactionId> {
+        let account_id = self.default_payer_account_id()?;
+        let generator =
+            self.body.operator.as_ref().and_then(|it| it.transaction_id_generator.as_ref());
+
+        Ok(TransactionId::generate_with(account_id, generator))
+    }
+
+    /// Like [`validate_signatures`](Self::validate_signatures), but with the operator `self` is (or will be) frozen with.
+    fn validate_payer_signature(&self, operator: Option<&Operator>) -> crate::Result<()> {
+        let payer = self
+            .get_transaction_id()
+            .map(|it| it.account_id)
+            .or(self.body.payer_account_id)
+            .or_else(|| operator.map(|it| it.account_id))
+            .ok_or(Error::NoPayerAccountOrTransactionId)?;
+
+        // the operator signs every request, so if it's the payer there's nothing to check.
+        if operator.is_some_and(|it| it.account_id == payer) {
+            return Ok(());
+        }
+
+        let has_source_signatures = self.sources().is_some_and(|sources| {
+            sources
+                .signed_transactions()
+                .iter()
+                .any(|it| it.sig_map.as_ref().is_some_and(|it| !it.sig_pair.is_empty()))
+        });
+
+        if self.signers.is_empty() && !has_source_signatures {
+            return Err(Error::signature_verify(format!(
+                "no signature for the payer account `{payer}`, sign the transaction with its key \
+                 (this check is best-effort: only the operator's key is known, so any other signature \
+                 is assumed to be the payer's)"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the generated transaction ID if `error` is a [`Status::TransactionExpired`]
+    /// and the transaction ID may be regenerated, returning whether it was replaced.
+    ///
+    /// Requests signed up front (by async signers) can't have their transaction ID regenerated while executing,
+    /// so the sources need to be rebuilt (and re-signed) when this returns `true`.
+    fn regenerate_expired_transaction_id(&mut self, client: &Client, error: &Error) -> bool {
+        let expired = matches!(
+            error,
+            Error::TransactionPreCheckStatus { status: Status::TransactionExpired, .. }
+        );
+
+        if !expired
+            || self.body.transaction_id.is_some()
+            || !self
+                .body
+                .regenerate_transaction_id
+                .unwrap_or_else(|| client.default_regenerate_transaction_id())
+        {
+            return false;
+        }
+
+        match self.generate_transaction_id() {
+            Ok(transaction_id) => {
+                self.body.generated_transaction_id = Some(transaction_id);
+                true
+            }
+            Err(_) => false,
+        }
     }
 
     /// # Panics
@@ -214,6 +310,7 @@ impl<D> Transaction<D> {
     /// Returns the account IDs of the nodes that this transaction may be submitted to.
     ///
     /// `None` means any node configured on the client.
+    /// Once frozen with a client, this is always the concrete set of nodes selected.
     #[must_use]
     pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
         self.body.node_account_ids.as_deref()
@@ -241,14 +338,35 @@ impl<D> Transaction<D> {
         self.body.transaction_valid_duration
     }
 
+    /// Returns the duration that this transaction will be valid for, once finalized and signed.
+    ///
+    /// Unlike [`get_transaction_valid_duration`](Self::get_transaction_valid_duration),
+    /// this returns the default of 120 seconds when no duration was set.
+    #[must_use]
+    pub fn effective_transaction_valid_duration(&self) -> Duration {
+        self.body.transaction_valid_duration.unwrap_or(DEFAULT_TRANSACTION_VALID_DURATION)
+    }
+
     /// Sets the duration that this transaction is valid for, once finalized and signed.
     ///
     /// Defaults to 120 seconds (or two minutes).
+    ///
+    /// The network only accepts durations between 1 and 180 seconds,
+    /// freezing the transaction fails for any other duration.
     pub fn transaction_valid_duration(&mut self, duration: Duration) -> &mut Self {
         self.body_mut().transaction_valid_duration = Some(duration);
         self
     }
 
+    /// Sets the duration that this transaction is valid for, once finalized and signed.
+    ///
+    /// This is the same as [`transaction_valid_duration`](Self::transaction_valid_duration),
+    /// but takes a [`std::time::Duration`].
+    /// Durations too large to be represented are saturated.
+    pub fn transaction_valid_duration_std(&mut self, duration: std::time::Duration) -> &mut Self {
+        self.transaction_valid_duration(Duration::try_from(duration).unwrap_or(Duration::MAX))
+    }
+
     /// Returns the maximum transaction fee the paying account is willing to pay.
     #[must_use]
     pub fn get_max_transaction_fee(&self) -> Option<Hbar> {
@@ -277,12 +395,13 @@ impl<D> Transaction<D> {
         self
     }
 
-    /// Returns the explicit transaction ID to use to identify this transaction.
+    /// Returns the transaction ID to use to identify this transaction.
     ///
-    /// Overrides the payer account defined on this transaction or on the client.
+    /// This is the explicit transaction ID if one was set,
+    /// otherwise the ID generated for the payer account (or the operator) when the transaction was frozen.
     #[must_use]
     pub fn get_transaction_id(&self) -> Option<TransactionId> {
-        self.body.transaction_id
+        self.body.transaction_id.or(self.body.generated_transaction_id)
     }
 
     /// Sets an explicit transaction ID to use to identify this transaction.
@@ -293,6 +412,24 @@ impl<D> Transaction<D> {
         self
     }
 
+    /// Returns the account that pays for this transaction, in place of the client's operator.
+    #[must_use]
+    pub fn get_transaction_payer_account_id(&self) -> Option<AccountId> {
+        self.body.payer_account_id
+    }
+
+    /// Sets the account that pays for this transaction, in place of the client's operator.
+    ///
+    /// Transaction IDs are generated for this account, but the client's operator still signs
+    /// the transaction, so the caller must sign with the payer's key.
+    /// This lets one client serve transactions paid for by many accounts.
+    ///
+    /// An explicit [`transaction_id`](Self::transaction_id) takes precedence over this.
+    pub fn transaction_payer_account_id(&mut self, id: impl Into<AccountId>) -> &mut Self {
+        self.body_mut().payer_account_id = Some(id.into());
+        self
+    }
+
     /// Sign the transaction.
     pub fn sign(&mut self, private_key: PrivateKey) -> &mut Self {
         self.sign_signer(AnySigner::PrivateKey(private_key))
@@ -318,6 +455,19 @@ impl<D> Transaction<D> {
         self.signers.push(signer);
         self
     }
+
+    /// Removes every signer that was added to this transaction.
+    ///
+    /// This is useful for re-signing a transaction after changing its body.
+    ///
+    /// # Panics
+    /// If `self` is frozen, since the signed transaction may already have been distributed.
+    #[track_caller]
+    pub fn clear_signatures(&mut self) -> &mut Self {
+        self.require_not_frozen();
+        self.signers.clear();
+        self
+    }
 }
 
 impl<D: ChunkedTransactionData> Transaction<D> {
@@ -364,6 +514,10 @@ impl<D: ChunkedTransactionData> Transaction<D> {
 
     /// Sets whether or not the transaction ID should be refreshed if a [`Status::TransactionExpired`](crate::Status::TransactionExpired) occurs.
     ///
+    /// When refreshed, a new transaction ID is generated for the operator, and the transaction is signed again before being resubmitted.
+    /// A transaction ID set explicitly with [`transaction_id`](Self::transaction_id) is never refreshed,
+    /// instead the expiry is returned as an [`Error::TransactionPreCheckStatus`].
+    ///
     /// Various operations such as [`add_signature`](Self::add_signature) can forcibly disable transaction ID regeneration.
     pub fn regenerate_transaction_id(&mut self, regenerate_transaction_id: bool) -> &mut Self {
         self.body_mut().regenerate_transaction_id = Some(regenerate_transaction_id);
@@ -372,11 +526,14 @@ impl<D: ChunkedTransactionData> Transaction<D> {
     }
 }
 
-impl<D: ValidateChecksums> Transaction<D> {
+impl<D: TransactionData + ValidateChecksums> Transaction<D> {
     /// Freeze the transaction so that no further modifications can be made.
     ///
     /// # Errors
     /// - [`Error::FreezeUnsetNodeAccountIds`] if no [`node_account_ids`](Self::node_account_ids) were set.
+    /// - [`Error::MaxChunksExceeded`] if the contents of a chunked transaction need more than `max_chunks` chunks.
+    /// - [`Error::InvalidTransaction`] if the transaction is missing a field the network requires.
+    /// - [`Error::InvalidTransaction`] if the transaction valid duration isn't between 1 and 180 seconds.
     ///
     /// # Panics
     /// - If `node_account_ids` is explicitly set to empty (IE: `tx.node_account_ids([]).freeze_with(None)`).
@@ -388,6 +545,14 @@ impl<D: ValidateChecksums> Transaction<D> {
     ///
     /// # Errors
     /// - [`Error::FreezeUnsetNodeAccountIds`] if no [`node_account_ids`](Self::node_account_ids) were set and `client.is_none()`.
+    /// - [`Error::NodeAccountUnknown`] if any of the [`node_account_ids`](Self::node_account_ids) aren't in `client`'s network.
+    /// - [`Error::MaxChunksExceeded`] if the contents of a chunked transaction need more than `max_chunks` chunks.
+    /// - [`Error::InvalidTransaction`] if the transaction is missing a field the network requires.
+    /// - [`Error::InvalidTransaction`] if the transaction valid duration isn't between 1 and 180 seconds.
+    /// - [`Error::CannotValidateChecksumWithoutLedgerId`] if `client` validates checksums but has no ledger ID.
+    /// - [`Error::BadEntityId`] if `client` validates checksums and an entity ID has the wrong checksum.
+    /// - [`Error::SignatureVerify`] if `client` verifies signatures and nothing signs on behalf of the payer,
+    ///   see [`validate_signatures`](Self::validate_signatures).
     ///
     /// # Panics
     /// - If `node_account_ids` is explicitly set to empty (IE: `tx.node_account_ids([]).freeze_with(None)`).
@@ -400,20 +565,56 @@ impl<D: ValidateChecksums> Transaction<D> {
         }
         let client: Option<&Client> = client.into();
 
+        self.data().validate()?;
+
+        if let Some(duration) = self.body.transaction_valid_duration {
+            if !(MIN_TRANSACTION_VALID_DURATION..=MAX_TRANSACTION_VALID_DURATION)
+                .contains(&duration)
+            {
+                return Err(Error::invalid_transaction(format!(
+                    "transaction valid duration of {duration} is outside of the allowed range of \
+                     {MIN_TRANSACTION_VALID_DURATION} to {MAX_TRANSACTION_VALID_DURATION}"
+                )));
+            }
+        }
+
+        if let Some(client) = client {
+            if client.auto_validate_checksums() {
+                let ledger_id = client.ledger_id_internal();
+                let ledger_id =
+                    ledger_id.as_deref().ok_or(Error::CannotValidateChecksumWithoutLedgerId)?;
+
+                self.validate_checksums(ledger_id.as_ref_ledger_id())?;
+            }
+        }
+
+        // the chunk count is fixed once frozen, so catch contents that don't fit now rather than mid-execution.
+        if let Some(chunk_data) = self.data().maybe_chunk_data() {
+            let used_chunks = chunk_data.used_chunks();
+
+            if used_chunks > chunk_data.max_chunks {
+                return Err(Error::MaxChunksExceeded {
+                    used_chunks,
+                    max_chunks: chunk_data.max_chunks,
+                });
+            }
+        }
+
         let node_account_ids = match &self.body.node_account_ids {
             // the clone here is the lesser of two evils.
             Some(it) => {
                 assert!(!it.is_empty());
+
+                // catch nodes the client can't connect to now, rather than when executing.
+                if let Some(client) = client {
+                    client.net().0.load().node_indexes_for_ids(it)?;
+                }
+
                 it.clone()
             }
             #[allow(clippy::missing_panics_doc)]
             None => {
-                let nodes = client
-                    .ok_or(Error::FreezeUnsetNodeAccountIds)?
-                    .net()
-                    .0
-                    .load()
-                    .random_node_ids();
+                let nodes = client.ok_or(Error::FreezeUnsetNodeAccountIds)?.random_node_ids();
                 assert!(!nodes.is_empty(), "BUG: Client didn't give any nodes (all unhealthy)");
 
                 nodes
@@ -429,21 +630,19 @@ impl<D: ValidateChecksums> Transaction<D> {
 
         let operator = client.and_then(Client::full_load_operator);
 
+        // checked before anything is changed, so that the transaction can be signed and frozen again.
+        if client.is_some_and(Client::verify_signatures_before_submit) {
+            self.validate_payer_signature(operator.as_deref())?;
+        }
+
         // note: yes, there's an `Some(opt.unwrap())`, this is INTENTIONAL.
         self.body.node_account_ids = Some(node_account_ids);
         self.body.max_transaction_fee = max_transaction_fee;
         self.body.operator = operator;
         self.body.is_frozen = true;
 
-        if let Some(client) = client {
-            if client.auto_validate_checksums() {
-                let ledger_id = client.ledger_id_internal();
-                let ledger_id = ledger_id
-                    .as_ref()
-                    .expect("Client had auto_validate_checksums enabled but no ledger ID");
-
-                self.validate_checksums(ledger_id.as_ref_ledger_id())?;
-            }
+        if self.body.transaction_id.is_none() {
+            self.body.generated_transaction_id = self.generate_transaction_id().ok();
         }
 
         Ok(self)
@@ -467,29 +666,110 @@ impl<D: ValidateChecksums> Transaction<D> {
 
         Ok(self)
     }
+
+    /// Freeze the transaction with every value supplied up front, without a client.
+    ///
+    /// This allows building and signing a transaction fully offline:
+    /// the result only depends on the arguments (and the transaction's own fields),
+    /// so [`to_bytes`](Self::to_bytes) gives the same bytes every time.
+    ///
+    /// When `operator_key` is set, it's used like a client's operator:
+    /// it signs the transaction on behalf of the transaction ID's account.
+    ///
+    /// # Errors
+    /// - [`Error::FreezeUnsetNodeAccountIds`] if `node_account_ids` is empty.
+    /// - Any other error [`freeze_with`](Self::freeze_with) would return for this transaction without a client.
+    ///
+    /// # Panics
+    /// - If the transaction is already frozen.
+    pub fn freeze_manually(
+        &mut self,
+        node_account_ids: Vec<AccountId>,
+        transaction_id: TransactionId,
+        max_transaction_fee: Hbar,
+        operator_key: Option<PrivateKey>,
+    ) -> crate::Result<&mut Self> {
+        if node_account_ids.is_empty() {
+            return Err(Error::FreezeUnsetNodeAccountIds);
+        }
+
+        self.node_account_ids(node_account_ids)
+            .transaction_id(transaction_id)
+            .max_transaction_fee(max_transaction_fee);
+
+        self.freeze_with(None)?;
+
+        self.body.operator = operator_key.map(|key| {
+            Arc::new(Operator {
+                account_id: transaction_id.account_id,
+                signer: AnySigner::PrivateKey(key),
+                transaction_id_generator: None,
+            })
+        });
+
+        Ok(self)
+    }
 }
 
 impl<D: TransactionExecute> Transaction<D> {
+    /// Returns the service this transaction is submitted to.
+    #[must_use]
+    pub fn service(&self) -> Service {
+        self.data().service()
+    }
+
     /// # Errors
-    /// - If the transaction needs multiple chunks, or has no explicit transaction ID *and* `self.operator` is not set.
+    /// - If the transaction needs multiple chunks, or has no explicit transaction ID *and* neither a payer account nor `self.operator` is set.
     ///
     /// # Panics
     /// - If `!self.is_frozen()`
     fn make_transaction_list(&self) -> crate::Result<Vec<services::Transaction>> {
-        assert!(self.is_frozen());
+        if self.has_async_signer() {
+            return Err(Error::AsyncSignerRequiresExecute);
+        }
+
+        self.chunk_infos()?.iter().map(|it| Ok(self.make_request_inner(it)?.0)).collect()
+    }
 
-        let operator = || self.body.operator.as_ref().ok_or(Error::NoPayerAccountOrTransactionId);
+    /// Like [`make_transaction_list`](Self::make_transaction_list), but supports async signers.
+    async fn make_transaction_list_async(&self) -> crate::Result<Vec<services::Transaction>> {
+        let chunk_infos = self.chunk_infos()?;
+
+        let mut transaction_list = Vec::with_capacity(chunk_infos.len());
+
+        for chunk_info in &chunk_infos {
+            transaction_list.push(self.make_request_inner_async(chunk_info).await.0);
+        }
+
+        Ok(transaction_list)
+    }
+
+    /// Returns `true` if the operator or any signer can only sign asynchronously.
+    pub(crate) fn has_async_signer(&self) -> bool {
+        self.body.operator.as_ref().map_or(false, |it| it.signer.is_async())
+            || self.signers.iter().any(AnySigner::is_async)
+    }
+
+    /// Returns the [`ChunkInfo`] of every request in the transaction list.
+    ///
+    /// # Errors
+    /// - If the transaction has no explicit transaction ID *and* neither a payer account nor `self.operator` is set.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`
+    fn chunk_infos(&self) -> crate::Result<Vec<ChunkInfo>> {
+        assert!(self.is_frozen());
 
         // todo: fix this with chunked transactions.
         let initial_transaction_id = match self.get_transaction_id() {
             Some(id) => id,
-            None => operator()?.generate_transaction_id(),
+            None => self.generate_transaction_id()?,
         };
 
         let used_chunks = self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks);
         let node_account_ids = self.body.node_account_ids.as_deref().unwrap();
 
-        let mut transaction_list = Vec::with_capacity(used_chunks * node_account_ids.len());
+        let mut chunk_infos = Vec::with_capacity(used_chunks * node_account_ids.len());
 
         // Note: This ordering is *important*,
         // there's no documentation for it but `TransactionList` is sorted by chunk number,
@@ -497,29 +777,27 @@ impl<D: TransactionExecute> Transaction<D> {
         for chunk in 0..used_chunks {
             let current_transaction_id = match chunk {
                 0 => initial_transaction_id,
-                _ => operator()?.generate_transaction_id(),
+                _ => self.generate_transaction_id()?,
             };
 
             for node_account_id in node_account_ids.iter().copied() {
-                let chunk_info = ChunkInfo {
+                chunk_infos.push(ChunkInfo {
                     current: chunk,
                     total: used_chunks,
                     initial_transaction_id,
                     current_transaction_id,
                     node_account_id,
-                };
-
-                transaction_list.push(self.make_request_inner(&chunk_info).0);
+                });
             }
         }
 
-        Ok(transaction_list)
+        Ok(chunk_infos)
     }
 
     pub(crate) fn make_sources(&self) -> crate::Result<Cow<'_, TransactionSources>> {
         assert!(self.is_frozen());
 
-        if let Some(sources) = self.signed_sources() {
+        if let Some(sources) = self.signed_sources()? {
             return Ok(sources);
         }
 
@@ -530,6 +808,7 @@ impl<D: TransactionExecute> Transaction<D> {
     ///
     /// # Errors
     /// - If `freeze_with` wasn't called with an operator.
+    /// - [`Error::AsyncSignerRequiresExecute`] if the operator or any other signer signs asynchronously.
     ///
     /// # Panics
     /// - If `!self.is_frozen()`.
@@ -537,13 +816,105 @@ impl<D: TransactionExecute> Transaction<D> {
         assert!(self.is_frozen(), "Transaction must be frozen to call `to_bytes`");
 
         let transaction_list = self
-            .signed_sources()
+            .signed_sources()?
             .map_or_else(|| self.make_transaction_list(), |it| Ok(it.transactions().to_vec()))?;
 
         Ok(hedera_proto::sdk::TransactionList { transaction_list }.encode_to_vec())
     }
 
-    pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> Vec<u8> {
+    /// Estimates the fee the network will charge for `self` using the given `fee_schedule` and `exchange_rate`.
+    ///
+    /// The estimate is based on the size of the transaction, the number of signatures it has,
+    /// and the node, network, and service prices for its type.
+    ///
+    /// This is only an approximation, the actual fee may differ because:
+    /// - The network also charges for storage and other resources which depend on what the transaction does.
+    /// - Signatures added after the estimate (or the operator's, if the transaction was frozen without one) aren't counted.
+    /// - The network converts fees using its own exchange rate, which changes over time.
+    ///
+    /// # Errors
+    /// - [`Error::FeeScheduleMissing`] if `fee_schedule` has no prices for the type of `self`.
+    /// - If `freeze_with` wasn't called with an operator.
+    /// - [`Error::AsyncSignerRequiresExecute`] if the operator or any other signer signs asynchronously.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn estimate_cost(
+        &self,
+        fee_schedule: &FeeSchedule,
+        exchange_rate: &ExchangeRate,
+    ) -> crate::Result<Hbar> {
+        assert!(self.is_frozen(), "Transaction must be frozen to call `estimate_cost`");
+
+        let sources = self.make_sources()?;
+
+        let mut tinycents: u64 = 0;
+
+        for chunk in sources.chunks() {
+            // every node is sent the same body, so the first node's request stands in for the rest.
+            let transaction = &chunk.transactions()[0];
+            let signed = &chunk.signed_transactions()[0];
+
+            let data = services::TransactionBody::decode(&*signed.body_bytes)
+                .map_err(Error::from_protobuf)?
+                .data
+                .ok_or_else(|| Error::from_protobuf("unexpected missing `data`"))?;
+
+            let request_type = RequestType::from_transaction_data(&data);
+
+            let fee_data = fee_schedule
+                .fee_data(&request_type)
+                .ok_or(Error::FeeScheduleMissing { request_type })?;
+
+            let signatures = signed.sig_map.as_ref().map_or(0, |it| it.sig_pair.len());
+
+            tinycents = tinycents.saturating_add(
+                fee_data.transaction_fee(transaction.encoded_len() as u64, signatures as u64),
+            );
+        }
+
+        let tinybars = exchange_rate.tinycents_to_tinybars(tinycents);
+
+        Ok(Hbar::from_tinybars(i64::try_from(tinybars).unwrap_or(i64::MAX)))
+    }
+
+    /// Returns the bytes of the `TransactionBody` sent to `node_account_id`, which are what gets signed.
+    ///
+    /// This is meant for external signers (such as hardware wallets),
+    /// the resulting signature can then be given to [`add_signature`](Self::add_signature).
+    ///
+    /// For a chunked transaction, this is the body of the first chunk.
+    ///
+    /// # Errors
+    /// - [`Error::NodeAccountUnknown`] if `node_account_id` isn't one of the transaction's node account IDs.
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID or payer account was set.
+    /// - [`Error::AsyncSignerRequiresExecute`] if the operator or any other signer signs asynchronously.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn signed_transaction_body_bytes(
+        &self,
+        node_account_id: AccountId,
+    ) -> crate::Result<Vec<u8>> {
+        assert!(
+            self.is_frozen(),
+            "Transaction must be frozen to call `signed_transaction_body_bytes`"
+        );
+
+        let sources = self.make_sources()?;
+
+        // the first chunk's requests are in the same order as the node account IDs.
+        let index = sources
+            .node_ids()
+            .iter()
+            .position(|it| *it == node_account_id)
+            .ok_or_else(|| Error::NodeAccountUnknown(Box::new(node_account_id)))?;
+
+        Ok(sources.signed_transactions()[index].body_bytes.clone())
+    }
+
+    pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> crate::Result<Vec<u8>> {
         assert!(self.is_frozen());
 
         // note: the following pair of cheecks are for more detailed panic messages
@@ -563,54 +934,75 @@ impl<D: TransactionExecute> Transaction<D> {
             );
         }
 
-        let sources = self.make_sources().unwrap();
+        let sources = self.make_sources()?;
 
         // this is the only check that is for correctness rather than debugability.
         assert!(sources.transactions().len() == 1);
 
-        let sources = sources.sign_with(std::slice::from_ref(signer));
+        let sources = sources.sign_with(std::slice::from_ref(signer))?;
 
         // hack: I don't care about perf here.
-        let ret = signer.sign(&sources.signed_transactions()[0].body_bytes);
+        let ret = signer.sign(&sources.signed_transactions()[0].body_bytes)?;
 
         // if we have a `Cow::Borrowed` that'd mean there was no modification
         if let Cow::Owned(sources) = sources {
             self.sources = Some(sources);
         }
 
-        ret.1
+        Ok(ret.1)
     }
 
-    // todo: should this return `Result<&mut Self>`?
     /// Adds a signature directly to `self`.
     ///
-    /// Only use this as a last resort.
+    /// This is meant for signatures computed externally, such as by a hardware wallet,
+    /// over the body bytes of the transaction's only request.
     ///
     /// This forcibly disables transaction ID regeneration.
-    pub fn add_signature(&mut self, pk: PublicKey, signature: Vec<u8>) -> &mut Self {
-        self.add_signature_signer(&AnySigner::arbitrary(Box::new(pk), move |_| signature.clone()));
-
-        self
-    }
+    ///
+    /// # Errors
+    /// - [`Error::AddSignatureNotFrozen`] if `!self.is_frozen()`.
+    /// - [`Error::AddSignatureMultipleRequests`] if the transaction targets multiple nodes or has multiple chunks.
+    /// - [`Error::SignatureVerify`] if `signature` isn't a valid signature of the transaction by `public_key`.
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID or payer account was set.
+    pub fn add_signature(
+        &mut self,
+        public_key: PublicKey,
+        signature: Vec<u8>,
+    ) -> crate::Result<&mut Self> {
+        if !self.is_frozen() {
+            return Err(Error::AddSignatureNotFrozen);
+        }
 
-    /// # Panics
-    /// panics if the transaction is not schedulable, a transaction can be non-schedulable due to:
-    /// - if `self.is_frozen`
-    /// - being a transaction kind that's non-schedulable, IE, `EthereumTransaction`, or
-    /// - being a chunked transaction with multiple chunks.
-    pub fn schedule(self) -> ScheduleCreateTransaction {
-        self.require_not_frozen();
-        assert!(self.get_node_account_ids().is_none(), "The underlying transaction for a scheduled transaction cannot have node account IDs set");
+        {
+            let sources = self.make_sources()?;
 
-        let mut transaction = ScheduleCreateTransaction::new();
+            let requests = sources.transactions().len();
+            if requests != 1 {
+                return Err(Error::AddSignatureMultipleRequests { requests });
+            }
 
-        if let Some(transaction_id) = self.get_transaction_id() {
-            transaction.transaction_id(transaction_id);
+            public_key.verify(&sources.signed_transactions()[0].body_bytes, &signature)?;
         }
 
-        transaction.scheduled_transaction(self);
+        self.add_signature_signer(&AnySigner::arbitrary(Box::new(public_key), move |_| {
+            signature.clone()
+        }))?;
 
-        transaction
+        Ok(self)
+    }
+
+    /// Checks, as far as the SDK can tell locally, that the payer of `self` signs it.
+    ///
+    /// The payer is the account of the transaction ID (or the payer account, or the operator's account).
+    /// The SDK only knows the key of the operator, so this is best-effort:
+    /// when the payer isn't the operator, any signature is assumed to be the payer's.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if there's no transaction ID, no payer account, and no operator.
+    /// - [`Error::SignatureVerify`] if nothing will sign on behalf of the payer.
+    pub fn validate_signatures(&self) -> crate::Result<()> {
+        self.validate_payer_signature(self.body.operator.as_deref())
     }
 
     /// Get the hash for this transaction.
@@ -619,7 +1011,7 @@ impl<D: TransactionExecute> Transaction<D> {
     ///
     /// # Errors
     /// - [`Error::NoPayerAccountOrTransactionId`]
-    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID or payer account was set.
     ///
     /// # Panics
     /// - If `!self.is_frozen()`.
@@ -646,7 +1038,7 @@ impl<D: TransactionExecute> Transaction<D> {
     ///
     /// # Errors
     /// - [`Error::NoPayerAccountOrTransactionId`]
-    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID or payer account was set.
     ///
     /// # Panics
     /// - If `!self.is_frozen()`.
@@ -656,11 +1048,16 @@ impl<D: TransactionExecute> Transaction<D> {
         // todo: error not frozen
         assert!(
             self.is_frozen(),
-            "Transaction must be frozen before calling `get_transaction_hash`"
+            "Transaction must be frozen before calling `get_transaction_hash_per_node`"
         );
 
         let sources = self.make_sources()?;
 
+        let sources = match sources {
+            Cow::Borrowed(it) => it,
+            Cow::Owned(it) => &*self.sources.insert(it),
+        };
+
         let chunk = sources.chunks().next().unwrap();
 
         let iter = chunk
@@ -687,6 +1084,67 @@ where
     pub fn default_max_transaction_fee(&self) -> Hbar {
         self.data().default_max_transaction_fee()
     }
+
+    /// Returns the maximum allowed transaction fee if none is specified.
+    ///
+    /// Same as [`default_max_transaction_fee`](Self::default_max_transaction_fee).
+    #[deprecated = "use `default_max_transaction_fee` instead"]
+    #[must_use]
+    pub fn get_default_max_transaction_fee(&self) -> Hbar {
+        self.default_max_transaction_fee()
+    }
+
+    /// Returns the maximum transaction fee this transaction will be submitted with.
+    ///
+    /// Unlike [`get_max_transaction_fee`](Self::get_max_transaction_fee),
+    /// this resolves the fee the way [`freeze_with`](Self::freeze_with) does:
+    /// the transaction's own fee, then the client's default, and finally [`default_max_transaction_fee`](Self::default_max_transaction_fee).
+    ///
+    /// # Panics
+    /// - If the transaction is not frozen.
+    #[must_use]
+    pub fn effective_max_transaction_fee(&self) -> Hbar {
+        assert!(
+            self.is_frozen(),
+            "Transaction must be frozen before calling `effective_max_transaction_fee`"
+        );
+
+        self.body.max_transaction_fee.unwrap_or_else(|| self.default_max_transaction_fee())
+    }
+}
+
+impl<D> Transaction<D>
+where
+    D: TransactionExecute + ToSchedulableTransactionDataProtobuf,
+{
+    /// Wraps `self` in a [`ScheduleCreateTransaction`].
+    ///
+    /// # Errors
+    /// - [`Error::InvalidTransaction`] if `self` isn't schedulable, a transaction can be non-schedulable due to:
+    ///   - `self` being frozen,
+    ///   - `self` having node account IDs set, scheduled transactions don't target any nodes,
+    ///     the `ScheduleCreateTransaction` picks its own nodes when it's frozen, or
+    ///   - being a chunked transaction with multiple chunks.
+    pub fn schedule(self) -> crate::Result<ScheduleCreateTransaction> {
+        self.schedule_inner()
+    }
+}
+
+impl<D> Transaction<D>
+where
+    D: TransactionExecute,
+{
+    fn schedule_inner(self) -> crate::Result<ScheduleCreateTransaction> {
+        let mut transaction = ScheduleCreateTransaction::new();
+
+        if let Some(transaction_id) = self.get_transaction_id() {
+            transaction.transaction_id(transaction_id);
+        }
+
+        transaction.scheduled_transaction(self)?;
+
+        Ok(transaction)
+    }
 }
 
 impl<D> Transaction<D>
@@ -702,13 +1160,13 @@ where
                 // unexpected response from Hedera, expecting to receive an `InsufficientTxFee` but received `Ok`
                 return Err(Error::TransactionPreCheckStatus {
                     cost: None,
-                    status: services::ResponseCodeEnum::Ok,
+                    status: Status::Ok,
                     transaction_id: Box::new(response.transaction_id),
                 });
             }
 
             Err(Error::TransactionPreCheckStatus { status, cost: Some(cost), .. })
-                if status == services::ResponseCodeEnum::InsufficientTxFee =>
+                if status == Status::InsufficientTxFee =>
             {
                 return Ok(cost);
             }
@@ -731,12 +1189,16 @@ where
         self.freeze_with(Some(client))?;
 
         if let Some(sources) = self.sources() {
-            return self::execute::SourceTransaction::new(self, sources)
+            return self::execute::SourceTransaction::new(self, sources)?
                 .execute(client, timeout)
                 .await;
         }
 
-        if let Some(chunk_data) = self.data().maybe_chunk_data() {
+        if self.has_async_signer() {
+            return self.execute_with_async_signer(client, timeout).await;
+        }
+
+        if let Some(chunk_data) = self.chunked_execution_data() {
             // todo: log a warning: user actually wanted `execute_all`.
             // instead of `panic`king we just pretend we were `execute_all` and
             // return the first result (*after* executing all the transactions).
@@ -746,9 +1208,61 @@ where
                 .map(|mut it| it.swap_remove(0));
         }
 
+        // no explicit max transaction fee is set on either the transaction or the client,
+        // so the fee is only a default, which may be bumped if the client allows it.
+        if let Some(ceiling) =
+            client.auto_bump_transaction_fee().filter(|_| self.body.max_transaction_fee.is_none())
+        {
+            return execute_with_fee_bump(self.default_max_transaction_fee(), ceiling, |fee| {
+                let mut transaction = self.clone();
+                transaction.body.max_transaction_fee = Some(fee);
+
+                async move { execute(client, &transaction, timeout).await }
+            })
+            .await;
+        }
+
         execute(client, self, timeout).await
     }
 
+    /// Executes a transaction with an async signer.
+    ///
+    /// An async signer can't sign each request as it's made, so all of them are signed up front,
+    /// and signed again whenever the transaction ID is regenerated.
+    async fn execute_with_async_signer(
+        &mut self,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<TransactionResponse> {
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+
+            let sources = TransactionSources::new(self.make_transaction_list_async().await?)?;
+
+            let result = self::execute::SourceTransaction::new(self, &sources)?
+                .execute(client, timeout)
+                .await;
+
+            match result {
+                Err(error)
+                    if attempt < client.max_attempts()
+                        && self.regenerate_expired_transaction_id(client, &error) =>
+                {
+                    continue
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Returns the chunk data if `self` should take the chunked execution path,
+    /// which is only for transactions that can be split into more than one chunk.
+    fn chunked_execution_data(&self) -> Option<&ChunkData> {
+        self.data().maybe_chunk_data().filter(|_| self.data().max_chunks() > 1)
+    }
+
     // this is in *this* impl block rather than the `: TransactionExecuteChunked` impl block
     //because there's the off chance that someone calls `execute` on a Transaction that wants `execute_all`...
     async fn execute_all_inner(
@@ -761,14 +1275,15 @@ where
 
         let wait_for_receipts = self.data().wait_for_receipt();
 
-        // fixme: error with an actual error.
-        #[allow(clippy::manual_assert)]
-        if chunk_data.data.len() > chunk_data.max_message_len() {
-            todo!("error: message too big")
-        }
-
         let used_chunks = chunk_data.used_chunks();
 
+        if used_chunks > chunk_data.max_chunks {
+            return Err(Error::MaxChunksExceeded {
+                used_chunks,
+                max_chunks: chunk_data.max_chunks,
+            });
+        }
+
         let mut responses = Vec::with_capacity(chunk_data.used_chunks());
 
         let initial_transaction_id = {
@@ -827,6 +1342,47 @@ where
     ) -> crate::Result<TransactionResponse> {
         self.execute_with_optional_timeout(client, Some(timeout)).await
     }
+
+    /// Execute this transaction by submitting it to several of its nodes at once,
+    /// returning the first successful response.
+    ///
+    /// The transaction is submitted to up to [`Client::max_parallel_submissions`] nodes,
+    /// every submission is the exact same transaction (with the same transaction ID), so the network only executes it once.
+    ///
+    /// Note: Calling this function _disables_ transaction ID regeneration.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionPreCheckStatus`] as soon as any node rejects the transaction with a non-retryable status.
+    /// - [`Error::InvalidTransaction`] if this is a chunked transaction with multiple chunks.
+    /// - The last error seen if no node accepted the transaction.
+    pub async fn execute_parallel(
+        &mut self,
+        client: &Client,
+    ) -> crate::Result<TransactionResponse> {
+        // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
+        self.freeze_with(Some(client))?;
+
+        let max_nodes = client.max_parallel_submissions();
+
+        if self.sources.is_none() && self.has_async_signer() {
+            let sources = TransactionSources::new(self.make_transaction_list_async().await?)?;
+
+            return self::execute::SourceTransaction::new(self, &sources)?
+                .execute_parallel(client, max_nodes)
+                .await;
+        }
+
+        // the submissions need to be byte-for-byte the same, so build them once (and keep them).
+        if self.sources.is_none() {
+            self.sources = Some(self.make_sources()?.into_owned());
+        }
+
+        let sources = self.sources.as_ref().unwrap();
+
+        self::execute::SourceTransaction::new(self, sources)?
+            .execute_parallel(client, max_nodes)
+            .await
+    }
 }
 
 impl<D> Transaction<D>
@@ -851,14 +1407,38 @@ where
 
         // fixme: dedup this with `execute_with_optional_timeout`
         if let Some(sources) = self.sources() {
-            return self::execute::SourceTransaction::new(self, sources)
+            return self::execute::SourceTransaction::new(self, sources)?
                 .execute_all(client, timeout_per_chunk)
                 .await;
         }
 
+        if self.has_async_signer() {
+            let mut attempt = 0;
+
+            return loop {
+                attempt += 1;
+
+                let sources = TransactionSources::new(self.make_transaction_list_async().await?)?;
+
+                let result = self::execute::SourceTransaction::new(self, &sources)?
+                    .execute_all(client, timeout_per_chunk)
+                    .await;
+
+                match result {
+                    Err(error)
+                        if attempt < client.max_attempts()
+                            && self.regenerate_expired_transaction_id(client, &error) =>
+                    {
+                        continue
+                    }
+                    result => break result,
+                }
+            };
+        }
+
         // sorry for the mess: this can technically infinite loop
         // (it won't, the loop condition would be dependent on chunk_data somehow being `Some` and `None` at the same time).
-        let Some(chunk_data) = self.data().maybe_chunk_data() else {
+        let Some(chunk_data) = self.chunked_execution_data() else {
             return Ok(Vec::from([self
                 .execute_with_optional_timeout(client, timeout_per_chunk)
                 .await?]));
@@ -868,8 +1448,63 @@ where
     }
 }
 
+/// Runs `attempt` with an increasing max transaction fee for as long as it fails with
+/// [`Status::InsufficientTxFee`](crate::Status::InsufficientTxFee) and the fee can be bumped without going past `ceiling`.
+async fn execute_with_fee_bump<F, Fut>(
+    initial_fee: Hbar,
+    ceiling: Hbar,
+    mut attempt: F,
+) -> crate::Result<TransactionResponse>
+where
+    F: FnMut(Hbar) -> Fut,
+    Fut: std::future::Future<Output = crate::Result<TransactionResponse>>,
+{
+    let mut fee = initial_fee;
+
+    loop {
+        match attempt(fee).await {
+            Err(Error::TransactionPreCheckStatus {
+                status: Status::InsufficientTxFee,
+                cost,
+                transaction_id,
+            }) => {
+                // prefer the fee the node told us it wanted, otherwise double what we had.
+                let bumped = cost
+                    .unwrap_or_else(|| Hbar::from_tinybars(fee.to_tinybars().saturating_mul(2)))
+                    .min(ceiling);
+
+                if bumped <= fee {
+                    return Err(Error::TransactionPreCheckStatus {
+                        status: Status::InsufficientTxFee,
+                        cost,
+                        transaction_id,
+                    });
+                }
+
+                log::debug!("insufficient transaction fee of {fee}, retrying with {bumped}");
+
+                fee = bumped;
+            }
+            result => return result,
+        }
+    }
+}
+
 // these impls are on `AnyTransaction`, but they're here instead of in `any` because actually implementing them is only possible here.
 impl AnyTransaction {
+    /// Wraps `self` in a [`ScheduleCreateTransaction`].
+    ///
+    /// # Errors
+    /// - [`Error::InvalidTransaction`] if `self` isn't schedulable, a transaction can be non-schedulable due to:
+    ///   - `self` being frozen,
+    ///   - `self` having node account IDs set, scheduled transactions don't target any nodes,
+    ///     the `ScheduleCreateTransaction` picks its own nodes when it's frozen,
+    ///   - being a transaction kind that's non-schedulable, IE, `EthereumTransaction`, or
+    ///   - being a chunked transaction with multiple chunks.
+    pub fn schedule(self) -> crate::Result<ScheduleCreateTransaction> {
+        self.schedule_inner()
+    }
+
     /// # Examples
     /// ```
     /// # fn main() -> hedera::Result<()> {
@@ -889,6 +1524,7 @@ impl AnyTransaction {
     /// ```
     /// # Errors
     /// - [`Error::FromProtobuf`] if a valid transaction cannot be parsed from the bytes.
+    /// - [`Error::FromProtobuf`] if the bytes contain a kind of transaction the SDK doesn't support.
     #[allow(deprecated)]
     pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
         let list =
@@ -1052,6 +1688,8 @@ where
             max_transaction_fee,
             transaction_memo,
             transaction_id,
+            generated_transaction_id,
+            payer_account_id,
             operator,
             is_frozen,
             regenerate_transaction_id,
@@ -1067,6 +1705,8 @@ where
                     max_transaction_fee,
                     transaction_memo,
                     transaction_id,
+                    generated_transaction_id,
+                    payer_account_id,
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
@@ -1083,6 +1723,8 @@ where
                     max_transaction_fee,
                     transaction_memo,
                     transaction_id,
+                    generated_transaction_id,
+                    payer_account_id,
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
@@ -1113,6 +1755,7 @@ pub(crate) mod test_helpers {
         TokenId,
         Transaction,
         TransactionId,
+        TransferTransaction,
     };
 
     impl<D: Default> Transaction<D> {
@@ -1185,6 +1828,27 @@ pub(crate) mod test_helpers {
         data.unwrap()
     }
 
+    /// Returns a frozen, unsigned, transfer of 2 ℏ from `0.0.101` to `0.0.2`, submitted to `node_account_ids`.
+    pub(crate) fn frozen_transfer(
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+
+        tx.hbar_transfer(2.into(), Hbar::new(2))
+            .hbar_transfer(101.into(), Hbar::new(-2))
+            .transaction_id(TransactionId {
+                account_id: 101.into(),
+                valid_start: OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap(),
+                nonce: None,
+                scheduled: false,
+            })
+            .node_account_ids(node_account_ids)
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
     pub(crate) fn unused_private_key() -> PrivateKey {
         "302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e10".parse().unwrap()
     }
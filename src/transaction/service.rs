@@ -0,0 +1,57 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+/// The Hedera service a transaction is submitted to.
+///
+/// Useful for grouping transactions (for metrics, routing, etc.) without matching on every
+/// transaction type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Service {
+    /// The crypto service (accounts, allowances, and transfers).
+    Crypto,
+
+    /// The token service.
+    Token,
+
+    /// The consensus service (topics).
+    Consensus,
+
+    /// The file service.
+    File,
+
+    /// The smart contract service (contracts and ethereum transactions).
+    Contract,
+
+    /// The schedule service.
+    Schedule,
+
+    /// The network service.
+    Network,
+
+    /// The util service (pseudorandom number generation).
+    Util,
+
+    /// The freeze service.
+    Freeze,
+
+    /// The address book service (nodes).
+    AddressBook,
+}
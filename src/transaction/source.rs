@@ -227,9 +227,12 @@ impl TransactionSources {
         })
     }
 
-    pub(crate) fn sign_with(&self, signers: &[AnySigner]) -> Cow<'_, Self> {
+    /// # Errors
+    /// - [`Error::AsyncSignerRequiresExecute`](crate::Error::AsyncSignerRequiresExecute) if a signer that isn't already
+    ///   signing signs asynchronously.
+    pub(crate) fn sign_with(&self, signers: &[AnySigner]) -> crate::Result<Cow<'_, Self>> {
         if signers.is_empty() {
-            return Cow::Borrowed(self);
+            return Ok(Cow::Borrowed(self));
         }
 
         let mut signed_transactions = Cow::Borrowed(&self.signed_transactions);
@@ -250,13 +253,13 @@ impl TransactionSources {
             for tx in signed_transactions.to_mut().iter_mut() {
                 let sig_map = tx.sig_map.get_or_insert_with(services::SignatureMap::default);
                 // todo: reuse `pk_bytes` instead of re-serializing them.
-                let sig_pair = super::execute::SignaturePair::from(signer.sign(&tx.body_bytes));
+                let sig_pair = super::execute::SignaturePair::from(signer.sign(&tx.body_bytes)?);
 
                 sig_map.sig_pair.push(sig_pair.into_protobuf());
             }
         }
 
-        match signed_transactions {
+        let sources = match signed_transactions {
             // if it's still borrowed then no signatures have been added (all signers are duplicates).
             Cow::Borrowed(_) => Cow::Borrowed(self),
             Cow::Owned(signed_transactions) => Cow::Owned(Self {
@@ -265,9 +268,12 @@ impl TransactionSources {
                 chunks: self.chunks.clone(),
                 transaction_ids: self.transaction_ids.clone(),
                 node_ids: self.node_ids.clone(),
-                transaction_hashes: self.transaction_hashes.clone(),
+                // the signed transaction bytes changed, so the hashes did too.
+                transaction_hashes: OnceCell::new(),
             }),
-        }
+        };
+
+        Ok(sources)
     }
 
     pub(crate) fn transactions(&self) -> &[services::Transaction] {
@@ -302,9 +308,13 @@ impl TransactionSources {
         &self.node_ids
     }
 
+    /// Returns the hash of every transaction, which is over the `SignedTransaction` bytes (not just the body).
     fn transaction_hashes(&self) -> &[TransactionHash] {
         self.transaction_hashes.get_or_init(|| {
-            self.signed_transactions.iter().map(|it| TransactionHash::new(&it.body_bytes)).collect()
+            self.transactions()
+                .iter()
+                .map(|it| TransactionHash::new(&it.signed_transaction_bytes))
+                .collect()
         })
     }
 }
@@ -1,11 +1,29 @@
-use std::collections::HashSet;
+use std::collections::{
+    HashMap,
+    HashSet,
+    VecDeque,
+};
+use std::time::Duration;
 
 use assert_matches::assert_matches;
+use hedera_proto::services;
 use hex_literal::hex;
+use parking_lot::Mutex;
+use prost::Message;
 use time::OffsetDateTime;
+use tonic::codegen::BoxFuture;
+use triomphe::Arc;
 
+use crate::mock::{
+    self,
+    MockUnary,
+    Unary,
+};
+use crate::protobuf::FromProtobuf;
+use crate::transaction::test_helpers::frozen_transfer;
 use crate::transaction::AnyTransactionData;
 use crate::{
+    AccountId,
     AnyTransaction,
     Client,
     Hbar,
@@ -119,3 +137,1228 @@ async fn chunked_to_from_bytes() -> crate::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn transaction_valid_duration_std() {
+    let mut tx = TransferTransaction::new();
+
+    tx.transaction_valid_duration_std(Duration::from_secs(119));
+
+    assert_eq!(tx.get_transaction_valid_duration(), Some(time::Duration::seconds(119)));
+}
+
+#[test]
+fn transaction_valid_duration_default() {
+    let mut tx = TransferTransaction::new();
+
+    assert_eq!(tx.get_transaction_valid_duration(), None);
+    assert_eq!(tx.effective_transaction_valid_duration(), time::Duration::seconds(120));
+
+    tx.transaction_valid_duration(time::Duration::seconds(30));
+
+    assert_eq!(tx.effective_transaction_valid_duration(), time::Duration::seconds(30));
+}
+
+#[test]
+fn transaction_valid_duration_out_of_range() {
+    let mut tx = TransferTransaction::new();
+    tx.node_account_ids([3.into()]).transaction_valid_duration(time::Duration::seconds(200));
+
+    assert_matches!(tx.freeze(), Err(crate::Error::InvalidTransaction(_)));
+    assert!(!tx.is_frozen());
+
+    tx.transaction_valid_duration(time::Duration::seconds(180));
+
+    tx.freeze().unwrap();
+}
+
+#[test]
+fn max_chunks() {
+    use crate::token::TokenUnpauseTransactionData;
+    use crate::topic::TopicMessageSubmitTransactionData;
+    use crate::transaction::TransactionExecute;
+
+    assert_eq!(TokenUnpauseTransactionData::default().max_chunks(), 1);
+    assert_eq!(TopicMessageSubmitTransactionData::default().max_chunks(), 20);
+
+    let data = AnyTransactionData::from(TopicMessageSubmitTransactionData::default());
+    assert_eq!(data.max_chunks(), 20);
+}
+
+#[test]
+#[should_panic(
+    expected = "`TokenUnpauseTransactionData` doesn't support chunking, but was split into 2 chunks (building chunk 1)"
+)]
+fn chunked_request_for_non_chunked_transaction() {
+    use crate::token::TokenUnpauseTransactionData;
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::transaction::{
+        ChunkInfo,
+        ToTransactionDataProtobuf,
+    };
+
+    let chunk_info = ChunkInfo {
+        current: 1,
+        total: 2,
+        initial_transaction_id: TEST_TX_ID,
+        current_transaction_id: TEST_TX_ID,
+        node_account_id: 3.into(),
+    };
+
+    let _ = TokenUnpauseTransactionData::default().to_transaction_data_protobuf(&chunk_info);
+}
+
+#[test]
+fn freeze_with_unknown_node_account_id() {
+    let client = Client::for_testnet();
+
+    let mut tx = TransferTransaction::new();
+    tx.node_account_ids([3.into(), AccountId::new(0, 0, 99999)]);
+
+    assert_matches!(
+        tx.freeze_with(&client),
+        Err(crate::Error::NodeAccountUnknown(it)) if *it == AccountId::new(0, 0, 99999)
+    );
+
+    // without a client there's no network to check against.
+    tx.freeze().unwrap();
+}
+
+#[test]
+fn clear_signatures() {
+    let mut tx = TransferTransaction::new();
+
+    tx.sign(PrivateKey::generate_ed25519()).sign(PrivateKey::generate_ed25519());
+
+    assert_eq!(tx.signers().count(), 2);
+
+    tx.clear_signatures();
+
+    assert_eq!(tx.signers().count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn clear_signatures_frozen_panics() {
+    let mut tx = TransferTransaction::new();
+
+    tx.node_account_ids([6.into()])
+        .sign(PrivateKey::generate_ed25519())
+        .freeze()
+        .unwrap()
+        .clear_signatures();
+}
+
+#[tokio::test]
+async fn freeze_with_cached_node_selection() -> crate::Result<()> {
+    let client = Client::for_testnet();
+
+    assert_eq!(client.node_selection_cache_duration(), None);
+
+    client.set_node_selection_cache_duration(Some(Duration::from_secs(60)));
+
+    let mut tx1 = TransferTransaction::new();
+    tx1.freeze_with(&client)?;
+
+    let mut tx2 = TransferTransaction::new();
+    tx2.freeze_with(&client)?;
+
+    assert!(tx1.get_node_account_ids().is_some());
+    assert_eq!(tx1.get_node_account_ids(), tx2.get_node_account_ids());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn freeze_with_resolves_nodes_and_transaction_id() -> crate::Result<()> {
+    let client = Client::for_testnet();
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+
+    let mut tx = TransferTransaction::new();
+
+    assert_eq!(tx.get_node_account_ids(), None);
+    assert_eq!(tx.get_transaction_id(), None);
+
+    tx.freeze_with(&client)?;
+
+    assert!(!tx.get_node_account_ids().unwrap().is_empty());
+
+    let transaction_id = tx.get_transaction_id().unwrap();
+    assert_eq!(transaction_id.account_id, AccountId::new(0, 0, 1001));
+
+    // the ID resolved when freezing is the one that gets serialized.
+    let bytes = tx.to_bytes()?;
+    assert_eq!(AnyTransaction::from_bytes(&bytes)?.get_transaction_id(), Some(transaction_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn freeze_with_payer_account_id() -> crate::Result<()> {
+    use crate::transaction::test_helpers::transaction_body;
+
+    let client = Client::for_testnet();
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+
+    let payer = AccountId::new(0, 0, 2002);
+
+    let mut tx = TransferTransaction::new();
+    tx.node_account_ids([3.into()]).transaction_payer_account_id(payer).freeze_with(&client)?;
+
+    assert_eq!(tx.get_transaction_payer_account_id(), Some(payer));
+
+    // the client's operator can't sign for the payer.
+    assert_matches!(tx.validate_signatures(), Err(crate::Error::SignatureVerify(_)));
+
+    tx.sign(PrivateKey::generate_ed25519());
+    tx.validate_signatures()?;
+
+    let transaction_id =
+        TransactionId::from_protobuf(transaction_body(tx).transaction_id.unwrap())?;
+
+    assert_eq!(transaction_id.account_id, payer);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn default_max_transaction_fee_per_type() -> crate::Result<()> {
+    use crate::transaction::test_helpers::transaction_body;
+    use crate::{
+        ContractCreateTransaction,
+        FileId,
+        TokenId,
+        TokenUnpauseTransaction,
+    };
+
+    let client = Client::for_testnet();
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+
+    let contract_create = || {
+        let mut tx = ContractCreateTransaction::new();
+        tx.bytecode_file_id(FileId::new(0, 0, 1234)).gas(100_000).node_account_ids([3.into()]);
+        tx
+    };
+
+    let token_unpause = || {
+        let mut tx = TokenUnpauseTransaction::new();
+        tx.token_id(TokenId::new(0, 0, 1234)).node_account_ids([3.into()]);
+        tx
+    };
+
+    let mut tx = contract_create();
+    tx.freeze_with(&client)?;
+    let contract_create_fee = transaction_body(tx).transaction_fee;
+
+    let mut tx = token_unpause();
+    tx.freeze_with(&client)?;
+    let token_unpause_fee = transaction_body(tx).transaction_fee;
+
+    assert_eq!(contract_create_fee, Hbar::new(20).to_tinybars() as u64);
+    assert_eq!(token_unpause_fee, Hbar::new(2).to_tinybars() as u64);
+
+    // the client's default takes priority over the per-type defaults.
+    client.set_default_max_transaction_fee(Hbar::new(5));
+
+    let mut tx = contract_create();
+    tx.freeze_with(&client)?;
+    assert_eq!(transaction_body(tx).transaction_fee, Hbar::new(5).to_tinybars() as u64);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn effective_max_transaction_fee() -> crate::Result<()> {
+    use crate::{
+        ContractCreateTransaction,
+        FileId,
+    };
+
+    let contract_create = || {
+        let mut tx = ContractCreateTransaction::new();
+        tx.bytecode_file_id(FileId::new(0, 0, 1234)).gas(100_000).node_account_ids([3.into()]);
+        tx
+    };
+
+    // without a fee on the transaction or client, the per-type default is used.
+    let mut tx = contract_create();
+    tx.freeze()?;
+
+    assert_eq!(tx.get_max_transaction_fee(), None);
+    assert_eq!(tx.default_max_transaction_fee(), Hbar::new(20));
+    #[allow(deprecated)]
+    let default_max_transaction_fee = tx.get_default_max_transaction_fee();
+    assert_eq!(default_max_transaction_fee, Hbar::new(20));
+    assert_eq!(tx.effective_max_transaction_fee(), Hbar::new(20));
+
+    let client = Client::for_testnet();
+    client.set_default_max_transaction_fee(Hbar::new(5));
+
+    let mut tx = contract_create();
+    tx.freeze_with(&client)?;
+    assert_eq!(tx.effective_max_transaction_fee(), Hbar::new(5));
+
+    let mut tx = contract_create();
+    tx.max_transaction_fee(Hbar::new(3)).freeze_with(&client)?;
+    assert_eq!(tx.effective_max_transaction_fee(), Hbar::new(3));
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "Transaction must be frozen")]
+fn effective_max_transaction_fee_requires_frozen() {
+    let _ = TransferTransaction::new().effective_max_transaction_fee();
+}
+
+#[test]
+fn freeze_manually_is_deterministic() -> crate::Result<()> {
+    use hedera_proto::{
+        sdk,
+        services,
+    };
+
+    use crate::transaction::test_helpers::{
+        unused_private_key,
+        TEST_TX_ID,
+    };
+
+    let build = || -> crate::Result<Vec<u8>> {
+        TransferTransaction::new()
+            .hbar_transfer(AccountId::new(0, 0, 5008), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 5009), Hbar::new(1))
+            .freeze_manually(
+                Vec::from([AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)]),
+                TEST_TX_ID,
+                Hbar::new(2),
+                Some(unused_private_key()),
+            )?
+            .to_bytes()
+    };
+
+    let bytes = build()?;
+    assert_eq!(bytes, build()?);
+
+    let tx = AnyTransaction::from_bytes(&bytes)?;
+
+    assert_eq!(tx.get_transaction_id(), Some(TEST_TX_ID));
+    assert_eq!(tx.get_node_account_ids(), Some(&[3.into(), 4.into()][..]));
+    assert_eq!(tx.get_max_transaction_fee(), Some(Hbar::new(2)));
+
+    // the operator key signed the transaction for every node.
+    let public_key = unused_private_key().public_key().to_bytes_raw();
+    let list = sdk::TransactionList::decode(&*bytes).unwrap();
+
+    assert_eq!(list.transaction_list.len(), 2);
+
+    for transaction in list.transaction_list {
+        let signed =
+            services::SignedTransaction::decode(&*transaction.signed_transaction_bytes).unwrap();
+
+        assert_eq!(signed.sig_map.unwrap().sig_pair[0].pub_key_prefix, public_key);
+    }
+
+    assert_matches!(
+        TransferTransaction::new().freeze_manually(Vec::new(), TEST_TX_ID, Hbar::new(2), None),
+        Err(crate::Error::FreezeUnsetNodeAccountIds)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fixed_clock_generates_deterministic_transaction_id() -> crate::Result<()> {
+    let valid_start = OffsetDateTime::from_unix_timestamp(1_554_158_542).unwrap();
+
+    let client = Client::for_testnet();
+    client.set_operator(101.into(), PrivateKey::generate_ed25519());
+    client.set_clock(move || valid_start);
+
+    let bytes =
+        TransferTransaction::new().node_account_ids([6.into()]).freeze_with(&client)?.to_bytes()?;
+
+    let tx = AnyTransaction::from_bytes(&bytes)?;
+
+    assert_eq!(
+        tx.get_transaction_id(),
+        Some(TransactionId { account_id: 101.into(), valid_start, nonce: None, scheduled: false })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transaction_id_generator() -> crate::Result<()> {
+    use crate::transaction::test_helpers::VALID_START;
+
+    let client = Client::for_testnet();
+    client.set_transaction_id_generator(|account_id| TransactionId {
+        account_id,
+        valid_start: VALID_START,
+        nonce: None,
+        scheduled: false,
+    });
+
+    // the generator applies to operators set after it, as well as before.
+    client.set_operator(101.into(), PrivateKey::generate_ed25519());
+
+    let transaction_id = || -> crate::Result<_> {
+        let bytes = TransferTransaction::new()
+            .node_account_ids([6.into()])
+            .freeze_with(&client)?
+            .to_bytes()?;
+
+        Ok(AnyTransaction::from_bytes(&bytes)?.get_transaction_id())
+    };
+
+    let expected = TransactionId {
+        account_id: 101.into(),
+        valid_start: VALID_START,
+        nonce: None,
+        scheduled: false,
+    };
+
+    assert_eq!(transaction_id()?, Some(expected));
+    assert_eq!(transaction_id()?, Some(expected));
+
+    // an explicit transaction ID is used as-is.
+    let explicit = TransactionId::generate(202.into());
+
+    let bytes = TransferTransaction::new()
+        .node_account_ids([6.into()])
+        .transaction_id(explicit)
+        .freeze_with(&client)?
+        .to_bytes()?;
+
+    assert_eq!(AnyTransaction::from_bytes(&bytes)?.get_transaction_id(), Some(explicit));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fee_bump_after_insufficient_tx_fee() {
+    use crate::{
+        Status,
+        TransactionHash,
+        TransactionResponse,
+    };
+
+    let transaction_id = TransactionId {
+        account_id: 101.into(),
+        valid_start: OffsetDateTime::now_utc(),
+        nonce: None,
+        scheduled: false,
+    };
+
+    let mut attempts = Vec::new();
+
+    let response = super::execute_with_fee_bump(Hbar::new(2), Hbar::new(5), |fee| {
+        attempts.push(fee);
+
+        let result = match fee < Hbar::new(3) {
+            true => Err(crate::Error::TransactionPreCheckStatus {
+                status: Status::InsufficientTxFee,
+                cost: Some(Hbar::new(3)),
+                transaction_id: Box::new(transaction_id),
+            }),
+            false => Ok(TransactionResponse {
+                node_account_id: 3.into(),
+                transaction_id,
+                transaction_hash: TransactionHash::new(b"hello"),
+                scheduled_transaction_id: None,
+                validate_status: true,
+            }),
+        };
+
+        async move { result }
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(response.transaction_id, transaction_id);
+    assert_eq!(attempts, [Hbar::new(2), Hbar::new(3)]);
+}
+
+#[tokio::test]
+async fn fee_bump_never_exceeds_ceiling() {
+    use crate::Status;
+
+    let mut attempts = Vec::new();
+
+    let result = super::execute_with_fee_bump(Hbar::new(2), Hbar::new(4), |fee| {
+        attempts.push(fee);
+
+        async move {
+            Err(crate::Error::TransactionPreCheckStatus {
+                status: Status::InsufficientTxFee,
+                cost: Some(Hbar::new(10)),
+                transaction_id: Box::new(TransactionId::generate(101.into())),
+            })
+        }
+    })
+    .await;
+
+    assert_matches!(
+        result,
+        Err(crate::Error::TransactionPreCheckStatus { status: Status::InsufficientTxFee, .. })
+    );
+    assert_eq!(attempts, [Hbar::new(2), Hbar::new(4)]);
+}
+
+/// A `UtilService` that answers each `prng` request with the next of `statuses` (then `OK`),
+/// after the next of `delays` (then immediately), recording the transaction ID of every request.
+#[derive(Clone, Default)]
+struct MockUtilService {
+    statuses: Arc<Mutex<VecDeque<crate::Status>>>,
+    delays: Arc<Mutex<VecDeque<Duration>>>,
+    transaction_ids: Arc<Mutex<Vec<TransactionId>>>,
+}
+
+impl MockUtilService {
+    fn with_statuses(statuses: impl IntoIterator<Item = crate::Status>) -> Self {
+        Self { statuses: Arc::new(Mutex::new(statuses.into_iter().collect())), ..Self::default() }
+    }
+
+    fn with_delays(delays: impl IntoIterator<Item = Duration>) -> Self {
+        Self { delays: Arc::new(Mutex::new(delays.into_iter().collect())), ..Self::default() }
+    }
+
+    /// Serves `self` on a random local port, returning a client whose only node (`0.0.3`) is that port.
+    async fn serve(&self) -> Client {
+        mock::client_for(mock::serve(Unary(self.clone())).await)
+    }
+}
+
+impl MockUnary for MockUtilService {
+    const SERVICE: &'static str = "proto.UtilService";
+    const METHOD: &'static str = "prng";
+
+    type Request = services::Transaction;
+    type Response = services::TransactionResponse;
+
+    fn call(
+        &self,
+        request: services::Transaction,
+    ) -> BoxFuture<services::TransactionResponse, tonic::Status> {
+        let signed =
+            services::SignedTransaction::decode(&*request.signed_transaction_bytes).unwrap();
+        let body = services::TransactionBody::decode(&*signed.body_bytes).unwrap();
+
+        self.transaction_ids
+            .lock()
+            .push(TransactionId::from_protobuf(body.transaction_id.unwrap()).unwrap());
+
+        let status = self.statuses.lock().pop_front().unwrap_or(crate::Status::Ok);
+        let delay = self.delays.lock().pop_front();
+
+        Box::pin(async move {
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+
+            Ok(services::TransactionResponse {
+                node_transaction_precheck_code: status.into(),
+                cost: 0,
+            })
+        })
+    }
+}
+
+#[tokio::test]
+async fn transaction_expired_regenerates_transaction_id() -> crate::Result<()> {
+    let service = MockUtilService::with_statuses([crate::Status::TransactionExpired]);
+
+    let client = service.serve().await;
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+
+    let response =
+        crate::PrngTransaction::new().node_account_ids([3.into()]).execute(&client).await?;
+
+    let transaction_ids = service.transaction_ids.lock().clone();
+
+    assert_eq!(transaction_ids.len(), 2);
+    assert_ne!(transaction_ids[0], transaction_ids[1]);
+    assert_eq!(response.transaction_id, transaction_ids[1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn busy_node_is_rotated_away_from_within_an_execute() -> crate::Result<()> {
+    let busy = MockUtilService::with_statuses(std::iter::repeat(crate::Status::Busy).take(100));
+
+    // answers each of the first few requests with a status that's retried, but not by backing off from this node.
+    let healthy = MockUtilService::with_statuses(
+        std::iter::repeat(crate::Status::TransactionExpired).take(4),
+    );
+
+    let client = Client::for_network(HashMap::from([
+        (mock::serve(Unary(busy.clone())).await, 3.into()),
+        (mock::serve(Unary(healthy.clone())).await, 4.into()),
+    ]))
+    .unwrap();
+
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+    client.set_max_attempts(10);
+    client.set_min_backoff(Duration::from_millis(10));
+    client.set_min_node_backoff(Duration::from_secs(60));
+    client.set_max_node_busy_responses(2);
+
+    crate::PrngTransaction::new().node_account_ids([3.into(), 4.into()]).execute(&client).await?;
+
+    // the healthy node needed five requests, but after its second `BUSY` the busy node wasn't tried again.
+    assert_eq!(healthy.transaction_ids.lock().len(), 5);
+    assert_eq!(busy.transaction_ids.lock().len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transaction_expired_regenerates_transaction_id_for_payer() -> crate::Result<()> {
+    let service = MockUtilService::with_statuses([crate::Status::TransactionExpired]);
+
+    let client = service.serve().await;
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+
+    let payer = AccountId::new(0, 0, 2002);
+
+    crate::PrngTransaction::new()
+        .node_account_ids([3.into()])
+        .transaction_payer_account_id(payer)
+        .execute(&client)
+        .await?;
+
+    let transaction_ids = service.transaction_ids.lock().clone();
+
+    assert_eq!(transaction_ids.len(), 2);
+    assert!(transaction_ids.iter().all(|it| it.account_id == payer));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transaction_expired_explicit_transaction_id() {
+    let service = MockUtilService::with_statuses([crate::Status::TransactionExpired]);
+
+    let client = service.serve().await;
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+
+    let transaction_id = TransactionId::generate(1001.into());
+
+    let result = crate::PrngTransaction::new()
+        .node_account_ids([3.into()])
+        .transaction_id(transaction_id)
+        .execute(&client)
+        .await;
+
+    assert_matches!(
+        result,
+        Err(crate::Error::TransactionPreCheckStatus {
+            status: crate::Status::TransactionExpired,
+            ..
+        })
+    );
+
+    assert_eq!(*service.transaction_ids.lock(), [transaction_id]);
+}
+
+#[tokio::test]
+async fn unrecognized_status_is_kept() {
+    let service = MockUtilService::with_statuses([crate::Status::Unrecognized(9999)]);
+
+    let client = service.serve().await;
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+
+    let result = crate::PrngTransaction::new().node_account_ids([3.into()]).execute(&client).await;
+
+    assert_matches!(
+        result,
+        Err(crate::Error::TransactionPreCheckStatus {
+            status: crate::Status::Unrecognized(9999),
+            ..
+        })
+    );
+}
+
+#[tokio::test]
+async fn request_timeout_bounds_busy_retries() {
+    let service = MockUtilService::with_statuses(std::iter::repeat(crate::Status::Busy).take(1000));
+
+    let client = service.serve().await;
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+    client.set_request_timeout(Some(Duration::from_millis(200)));
+
+    // without the request timeout, backing off through every attempt would take far longer than this.
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        crate::PrngTransaction::new().node_account_ids([3.into()]).execute(&client),
+    )
+    .await
+    .expect("the request timeout should end the request");
+
+    // every node answered `BUSY`, so the transaction was never accepted.
+    let error = assert_matches!(
+        result,
+        Err(crate::Error::TimedOut { submitted: false, source }) => source
+    );
+    assert_matches!(
+        *error,
+        crate::Error::TransactionPreCheckStatus { status: crate::Status::Busy, .. }
+    );
+}
+
+#[tokio::test]
+async fn request_timeout_after_submission() {
+    let service = MockUtilService::with_delays([Duration::from_secs(10)]);
+
+    let client = service.serve().await;
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+    client.set_request_timeout(Some(Duration::from_millis(200)));
+
+    let result = crate::PrngTransaction::new().node_account_ids([3.into()]).execute(&client).await;
+
+    // the node never answered, so the transaction may still be processed.
+    assert_matches!(result, Err(crate::Error::TimedOut { submitted: true, .. }));
+    assert_eq!(service.transaction_ids.lock().len(), 1);
+}
+
+#[tokio::test]
+async fn request_timeout_after_refused_connection() {
+    // bind and immediately close a listener to get a port that refuses connections.
+    let address = {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        listener.local_addr().unwrap().to_string()
+    };
+
+    let client = mock::client_for(address);
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+    client.set_max_attempts(1000);
+    client.set_request_timeout(Some(Duration::from_millis(500)));
+
+    let result = crate::PrngTransaction::new().node_account_ids([3.into()]).execute(&client).await;
+
+    // the connection was refused, so the transaction never reached a node.
+    let error = assert_matches!(
+        result,
+        Err(crate::Error::TimedOut { submitted: false, source }) => source
+    );
+    assert_matches!(*error, crate::Error::GrpcStatus(it) if it.code() == tonic::Code::Unavailable);
+}
+
+#[tokio::test]
+async fn cancelled_execute_leaves_client_usable() -> crate::Result<()> {
+    let service = MockUtilService::with_delays([Duration::from_secs(10)]);
+
+    let client = service.serve().await;
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+
+    // drop the first execution while its request is in flight.
+    let cancelled = tokio::time::timeout(
+        Duration::from_millis(200),
+        crate::PrngTransaction::new().node_account_ids([3.into()]).execute(&client),
+    )
+    .await;
+
+    assert!(cancelled.is_err());
+
+    let response =
+        crate::PrngTransaction::new().node_account_ids([3.into()]).execute(&client).await?;
+
+    let transaction_ids = service.transaction_ids.lock().clone();
+
+    assert_eq!(transaction_ids.len(), 2);
+    assert_eq!(response.transaction_id, transaction_ids[1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing_test::traced_test]
+async fn retried_transaction_traces_each_attempt() -> crate::Result<()> {
+    let service = MockUtilService::with_statuses([crate::Status::Busy, crate::Status::Busy]);
+
+    let client = service.serve().await;
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+    client.set_min_backoff(Duration::from_millis(1));
+    client.set_max_backoff(Duration::from_millis(10));
+
+    crate::PrngTransaction::new().node_account_ids([3.into()]).execute(&client).await?;
+
+    logs_assert(|lines: &[&str]| {
+        let send_attempts: Vec<_> = lines
+            .iter()
+            .filter(|line| line.contains("node responded"))
+            .filter_map(|line| {
+                line.split_whitespace().find_map(|it| it.strip_prefix("send_attempt="))
+            })
+            .collect();
+
+        match send_attempts == ["1", "2", "3"] {
+            true => Ok(()),
+            false => Err(format!("expected send attempts 1, 2 and 3, got {send_attempts:?}")),
+        }
+    });
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn async_operator() -> crate::Result<()> {
+    let service = MockUtilService::default();
+
+    let client = service.serve().await;
+
+    let key = PrivateKey::generate_ed25519();
+    let signatures = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+    client.set_operator_with_async(1001.into(), key.public_key(), {
+        let signatures = signatures.clone();
+        move |message| {
+            let signature = key.sign(message);
+            signatures.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            async move {
+                tokio::task::yield_now().await;
+                signature
+            }
+        }
+    });
+
+    let mut tx = crate::PrngTransaction::new();
+    tx.node_account_ids([3.into()]).freeze_with(&client)?;
+
+    assert_matches!(tx.to_bytes(), Err(crate::Error::AsyncSignerRequiresExecute));
+    assert_eq!(signatures.load(std::sync::atomic::Ordering::Relaxed), 0);
+
+    let response = tx.execute(&client).await?;
+
+    assert_eq!(*service.transaction_ids.lock(), [response.transaction_id]);
+    assert_eq!(signatures.load(std::sync::atomic::Ordering::Relaxed), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn async_operator_transaction_expired_regenerates_transaction_id() -> crate::Result<()> {
+    let service = MockUtilService::with_statuses([crate::Status::TransactionExpired]);
+
+    let client = service.serve().await;
+
+    let key = PrivateKey::generate_ed25519();
+
+    client.set_operator_with_async(1001.into(), key.public_key(), move |message| {
+        let signature = key.sign(message);
+        async move { signature }
+    });
+
+    let response =
+        crate::PrngTransaction::new().node_account_ids([3.into()]).execute(&client).await?;
+
+    let transaction_ids = service.transaction_ids.lock().clone();
+
+    assert_eq!(transaction_ids.len(), 2);
+    assert_ne!(transaction_ids[0], transaction_ids[1]);
+    assert_eq!(response.transaction_id, transaction_ids[1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn async_operator_sign_with_operator_requires_execute() -> crate::Result<()> {
+    let client = Client::for_testnet();
+
+    let key = PrivateKey::generate_ed25519();
+
+    client.set_operator_with_async(1001.into(), key.public_key(), move |message| {
+        let signature = key.sign(message);
+        async move { signature }
+    });
+
+    let mut tx = crate::PrngTransaction::new();
+    tx.node_account_ids([3.into()]).sign_with_operator(&client)?;
+
+    assert_matches!(tx.to_bytes(), Err(crate::Error::AsyncSignerRequiresExecute));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn response_transaction_hash() -> crate::Result<()> {
+    let service = MockUtilService::default();
+
+    let client = service.serve().await;
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+
+    let mut tx = crate::PrngTransaction::new();
+    tx.node_account_ids([3.into()]).freeze_with(&client)?;
+
+    let transaction_hash = tx.get_transaction_hash()?;
+
+    let response = tx.execute(&client).await?;
+
+    assert_eq!(response.node_account_id, 3.into());
+    assert_eq!(response.transaction_hash(), transaction_hash.0);
+    assert_eq!(response.scheduled_transaction_id(), None);
+    assert!(response.validate_status);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute_parallel_returns_fastest_node() -> crate::Result<()> {
+    let fast_service = MockUtilService::default();
+
+    let slow = mock::serve(Unary(MockUtilService::with_delays([Duration::from_secs(10)]))).await;
+    let fast = mock::serve(Unary(fast_service.clone())).await;
+
+    let client = Client::for_network(HashMap::from([(slow, 3.into()), (fast, 4.into())])).unwrap();
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+    client.set_max_parallel_submissions(2);
+
+    let mut tx = crate::PrngTransaction::new();
+    let response = tx.node_account_ids([3.into(), 4.into()]).execute_parallel(&client).await?;
+
+    assert_eq!(response.node_account_id, 4.into());
+
+    // both nodes were sent the very same transaction.
+    assert_eq!(Some(response.transaction_id), tx.get_transaction_id());
+
+    // the response came from the fast node, which saw exactly one submission.
+    assert_eq!(*fast_service.transaction_ids.lock(), [response.transaction_id]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute_parallel_rejects_multiple_chunks() -> crate::Result<()> {
+    let service = MockUtilService::default();
+    let client = service.serve().await;
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+
+    let res = TopicMessageSubmitTransaction::new()
+        .topic_id(314)
+        .message(b"Hello, world!".to_vec())
+        .chunk_size(8)
+        .max_chunks(2)
+        .execute_parallel(&client)
+        .await;
+
+    assert_matches!(res, Err(crate::Error::InvalidTransaction(_)));
+
+    Ok(())
+}
+
+fn crypto_transfer_fee_schedule(node_bandwidth_byte: u64) -> crate::FeeSchedule {
+    let components = |constant, verification| crate::FeeComponents {
+        min: 0,
+        max: 1_000_000_000_000_000,
+        constant,
+        bandwidth_byte: 0,
+        verification,
+        storage_byte_hour: 0,
+        ram_byte_hour: 0,
+        contract_transaction_gas: 0,
+        transfer_volume_hbar: 0,
+        response_memory_byte: 0,
+        response_disk_byte: 0,
+    };
+
+    #[allow(deprecated)]
+    crate::FeeSchedule {
+        transaction_fee_schedules: Vec::from([crate::TransactionFeeSchedule {
+            request_type: crate::RequestType::CryptoTransfer,
+            fee_data: None,
+            fees: Vec::from([crate::FeeData {
+                node: crate::FeeComponents {
+                    bandwidth_byte: node_bandwidth_byte,
+                    ..components(10_000_000, 5_000_000)
+                },
+                network: components(100_000_000, 50_000_000),
+                service: components(100_000_000, 0),
+                kind: crate::FeeDataType::Default,
+            }]),
+        }]),
+        expiration_time: OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap(),
+    }
+}
+
+#[test]
+fn estimate_cost() -> crate::Result<()> {
+    // 1 ℏ = 12 ¢
+    let exchange_rate = crate::ExchangeRate {
+        hbars: 1,
+        cents: 12,
+        expiration_time: OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap(),
+    };
+
+    let mut tx = frozen_transfer([3.into()]);
+    tx.sign(PrivateKey::generate_ed25519());
+
+    // 15,000 (node) + 150,000 (network) + 100,000 (service) tinycents.
+    assert_eq!(
+        tx.estimate_cost(&crypto_transfer_fee_schedule(0), &exchange_rate)?,
+        Hbar::from_tinybars(265_000 / 12)
+    );
+
+    // each signature costs another 5,000 (node) + 50,000 (network) tinycents.
+    tx.sign(PrivateKey::generate_ed25519());
+
+    assert_eq!(
+        tx.estimate_cost(&crypto_transfer_fee_schedule(0), &exchange_rate)?,
+        Hbar::from_tinybars(320_000 / 12)
+    );
+
+    // and the node charges 1 tinycent per byte.
+    let bytes =
+        hedera_proto::sdk::TransactionList::decode(&*tx.to_bytes()?).unwrap().transaction_list[0]
+            .encoded_len() as i64;
+
+    assert_eq!(
+        tx.estimate_cost(&crypto_transfer_fee_schedule(1000), &exchange_rate)?,
+        Hbar::from_tinybars((320_000 + bytes) / 12)
+    );
+
+    let other_schedule = crate::FeeSchedule {
+        transaction_fee_schedules: Vec::new(),
+        expiration_time: OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap(),
+    };
+
+    assert_matches!(
+        tx.estimate_cost(&other_schedule, &exchange_rate),
+        Err(crate::Error::FeeScheduleMissing { request_type: crate::RequestType::CryptoTransfer })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn add_signature_matches_sign() -> crate::Result<()> {
+    use hedera_proto::{
+        sdk,
+        services,
+    };
+
+    let key = PrivateKey::generate_ed25519();
+
+    let signed_bytes = frozen_transfer([3.into()]).sign(key.clone()).to_bytes()?;
+
+    let mut tx = frozen_transfer([3.into()]);
+
+    // sign the body bytes "externally", as a hardware wallet would.
+    let unsigned = sdk::TransactionList::decode(&*tx.to_bytes()?).unwrap();
+    let signed_transaction = services::SignedTransaction::decode(
+        &*unsigned.transaction_list[0].signed_transaction_bytes,
+    )
+    .unwrap();
+    let signature = key.sign(&signed_transaction.body_bytes);
+
+    tx.add_signature(key.public_key(), signature)?;
+
+    assert_eq!(tx.to_bytes()?, signed_bytes);
+
+    Ok(())
+}
+
+#[test]
+fn signed_transaction_body_bytes() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+
+    let signed_bytes = frozen_transfer([3.into()]).sign(key.clone()).to_bytes()?;
+
+    let mut tx = frozen_transfer([3.into()]);
+
+    let signature = key.sign(&tx.signed_transaction_body_bytes(3.into())?);
+
+    tx.add_signature(key.public_key(), signature)?;
+
+    assert_eq!(tx.to_bytes()?, signed_bytes);
+
+    assert_matches!(
+        tx.signed_transaction_body_bytes(4.into()),
+        Err(crate::Error::NodeAccountUnknown(it)) if *it == AccountId::new(0, 0, 4)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn signed_transaction_body_bytes_per_node() -> crate::Result<()> {
+    let tx = frozen_transfer([3.into(), 4.into()]);
+
+    for node in [AccountId::from(3), AccountId::from(4)] {
+        let body =
+            services::TransactionBody::decode(&*tx.signed_transaction_body_bytes(node)?).unwrap();
+
+        assert_eq!(AccountId::from_protobuf(body.node_account_id.unwrap())?, node);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn transaction_hashes_match_bytes() -> crate::Result<()> {
+    use hedera_proto::{
+        sdk,
+        services,
+    };
+    use sha2::{
+        Digest,
+        Sha384,
+    };
+
+    let key = PrivateKey::generate_ed25519();
+
+    let mut tx = frozen_transfer([3.into(), 4.into()]);
+    tx.sign(key.clone());
+
+    // signatures added to a transaction parsed from bytes take a different path from freezing.
+    let mut from_bytes =
+        AnyTransaction::from_bytes(&frozen_transfer([3.into(), 4.into()]).to_bytes()?)?;
+    from_bytes.sign(key);
+
+    for tx in [&mut AnyTransaction::from(tx), &mut from_bytes] {
+        let hashes = tx.get_transaction_hash_per_node()?;
+
+        let list = sdk::TransactionList::decode(&*tx.to_bytes()?).unwrap();
+
+        assert_eq!(hashes.len(), list.transaction_list.len());
+
+        for transaction in &list.transaction_list {
+            let signed =
+                services::SignedTransaction::decode(&*transaction.signed_transaction_bytes)
+                    .unwrap();
+            let body = services::TransactionBody::decode(&*signed.body_bytes).unwrap();
+            let node = AccountId::from_protobuf(body.node_account_id.unwrap())?;
+
+            let expected: [u8; 48] = Sha384::digest(&transaction.signed_transaction_bytes).into();
+            assert_eq!(hashes[&node].0, expected);
+        }
+
+        // the single hash is the one for the first transaction in the list.
+        let expected: [u8; 48] =
+            Sha384::digest(&list.transaction_list[0].signed_transaction_bytes).into();
+        assert_eq!(tx.get_transaction_hash()?.0, expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn from_bytes_unsupported_transaction_kind() -> crate::Result<()> {
+    use hedera_proto::{
+        sdk,
+        services,
+    };
+
+    let mut list = sdk::TransactionList::decode(&*frozen_transfer([3.into()]).to_bytes()?).unwrap();
+
+    // swap the transfer for a kind of transaction that the SDK can't represent.
+    let transaction = &mut list.transaction_list[0];
+    let mut signed =
+        services::SignedTransaction::decode(&*transaction.signed_transaction_bytes).unwrap();
+    let mut body = services::TransactionBody::decode(&*signed.body_bytes).unwrap();
+    body.data = Some(services::transaction_body::Data::UncheckedSubmit(Default::default()));
+    signed.body_bytes = body.encode_to_vec();
+    transaction.signed_transaction_bytes = signed.encode_to_vec();
+
+    let error = assert_matches!(
+        AnyTransaction::from_bytes(&list.encode_to_vec()),
+        Err(error @ crate::Error::FromProtobuf(_)) => error
+    );
+
+    assert_eq!(
+        error.to_string(),
+        "failed to create a SDK type from a protobuf response: unsupported transaction kind `UncheckedSubmitTransaction`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn add_signature_errors() {
+    let key = PrivateKey::generate_ed25519();
+
+    let mut tx = TransferTransaction::new();
+    assert_matches!(
+        tx.add_signature(key.public_key(), Vec::new()),
+        Err(crate::Error::AddSignatureNotFrozen)
+    );
+
+    let mut tx = frozen_transfer([3.into(), 4.into()]);
+    assert_matches!(
+        tx.add_signature(key.public_key(), Vec::new()),
+        Err(crate::Error::AddSignatureMultipleRequests { requests: 2 })
+    );
+
+    // a signature by a different key doesn't verify against `key`.
+    let mut tx = frozen_transfer([3.into()]);
+    let other_signature = PrivateKey::generate_ed25519().sign(b"not the body bytes");
+    assert_matches!(
+        tx.add_signature(key.public_key(), other_signature),
+        Err(crate::Error::SignatureVerify(_))
+    );
+}
+
+#[tokio::test]
+async fn validate_signatures() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+
+    // nothing signs for `0.0.101`.
+    let mut tx = frozen_transfer([3.into()]);
+    assert_matches!(tx.validate_signatures(), Err(crate::Error::SignatureVerify(_)));
+
+    tx.sign(key.clone());
+    tx.validate_signatures()?;
+
+    // the operator signs for itself.
+    let client = Client::for_testnet();
+    client.set_operator(101.into(), key);
+
+    let mut tx = TransferTransaction::new();
+    tx.hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .freeze_with(&client)?;
+
+    tx.validate_signatures()?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn verify_signatures_before_submit() {
+    let service = MockUtilService::default();
+
+    let client = service.serve().await;
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+    client.set_verify_signatures_before_submit(true);
+
+    let result = crate::PrngTransaction::new()
+        .node_account_ids([3.into()])
+        .transaction_id(TransactionId::generate(5005.into()))
+        .execute(&client)
+        .await;
+
+    assert_matches!(result, Err(crate::Error::SignatureVerify(_)));
+    assert!(service.transaction_ids.lock().is_empty());
+}
+
+#[tokio::test]
+async fn verify_signatures_on_freeze() -> crate::Result<()> {
+    let client = Client::for_testnet();
+    client.set_operator(1001.into(), PrivateKey::generate_ed25519());
+    client.set_verify_signatures_before_submit(true);
+
+    let mut tx = crate::PrngTransaction::new();
+    tx.node_account_ids([3.into()]).transaction_id(TransactionId::generate(5005.into()));
+
+    assert_matches!(tx.freeze_with(&client), Err(crate::Error::SignatureVerify(_)));
+    assert!(!tx.is_frozen());
+
+    tx.sign(PrivateKey::generate_ed25519());
+    tx.freeze_with(&client)?;
+
+    // the operator signs for itself.
+    let mut tx = crate::PrngTransaction::new();
+    tx.node_account_ids([3.into()]).freeze_with(&client)?;
+
+    Ok(())
+}
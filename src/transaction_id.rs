@@ -36,6 +36,7 @@ use time::{
     OffsetDateTime,
 };
 
+use crate::client::TransactionIdGenerator;
 use crate::ledger_id::RefLedgerId;
 use crate::{
     AccountId,
@@ -72,6 +73,9 @@ pub struct TransactionId {
 
 impl TransactionId {
     /// Generates a new transaction ID for the given account ID.
+    ///
+    /// The valid start is backdated by a random 5 to 8 seconds, to account for clock drift
+    /// between the client and the network, and to avoid collisions between IDs generated at the same time.
     #[must_use]
     pub fn generate(account_id: AccountId) -> Self {
         let valid_start = OffsetDateTime::now_utc()
@@ -80,6 +84,49 @@ impl TransactionId {
         Self { account_id, valid_start, scheduled: false, nonce: None }
     }
 
+    /// Returns `self` with [`scheduled`](Self::scheduled) set to `scheduled`.
+    #[must_use]
+    pub const fn with_scheduled(mut self, scheduled: bool) -> Self {
+        self.scheduled = scheduled;
+        self
+    }
+
+    /// Returns the ID of the transaction scheduled by the `ScheduleCreateTransaction` with this ID.
+    ///
+    /// When a schedule executes, its inner transaction shares the ID of the `ScheduleCreateTransaction`,
+    /// with [`scheduled`](Self::scheduled) set, so this can be used to query the record of the execution.
+    #[must_use]
+    pub const fn into_scheduled(self) -> Self {
+        self.with_scheduled(true)
+    }
+
+    /// Returns the ID of the `ScheduleCreateTransaction` that scheduled the transaction with this ID.
+    ///
+    /// This is the inverse of [`into_scheduled`](Self::into_scheduled).
+    #[must_use]
+    pub const fn into_unscheduled(self) -> Self {
+        self.with_scheduled(false)
+    }
+
+    /// Returns `self` with [`nonce`](Self::nonce) set to `nonce`.
+    #[must_use]
+    pub const fn with_nonce(mut self, nonce: Option<i32>) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Generates a new transaction ID for the given account ID, with `generator` if present.
+    #[must_use]
+    pub(crate) fn generate_with(
+        account_id: AccountId,
+        generator: Option<&TransactionIdGenerator>,
+    ) -> Self {
+        match generator {
+            Some(generator) => generator.generate(account_id),
+            None => Self::generate(account_id),
+        }
+    }
+
     /// Create a new `TransactionId` from protobuf-encoded `bytes`.
     ///
     /// # Errors
@@ -212,7 +259,10 @@ mod tests {
 
     use assert_matches::assert_matches;
     use expect_test::expect;
-    use time::OffsetDateTime;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
 
     use crate::protobuf::{
         FromProtobuf,
@@ -375,4 +425,66 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    fn parse_scheduled_nonce_round_trip() {
+        let transaction_id =
+            TransactionId::from_str("0.0.1001@1699999999.000000123?scheduled/4").unwrap();
+
+        assert_eq!(
+            transaction_id,
+            TransactionId {
+                account_id: AccountId::new(0, 0, 1001),
+                valid_start: OffsetDateTime::from_unix_timestamp_nanos(1699999999000000123)
+                    .unwrap(),
+                nonce: Some(4),
+                scheduled: true
+            }
+        );
+
+        assert_eq!(TransactionId::from_str(&transaction_id.to_string()).unwrap(), transaction_id);
+    }
+
+    #[test]
+    fn builders() {
+        let transaction_id = TransactionId::from_str("0.0.1001@1699999999.000000123").unwrap();
+
+        let transaction_id = transaction_id.with_scheduled(true).with_nonce(Some(4));
+
+        assert_eq!(
+            transaction_id,
+            TransactionId::from_str("0.0.1001@1699999999.000000123?scheduled/4").unwrap()
+        );
+
+        assert_eq!(
+            transaction_id.with_scheduled(false).with_nonce(None).to_string(),
+            "0.0.1001@1699999999.123"
+        );
+    }
+
+    #[test]
+    fn into_scheduled() {
+        let transaction_id = TransactionId::from_str("0.0.1001@1699999999.000000123/4").unwrap();
+        let scheduled = transaction_id.into_scheduled();
+
+        assert_eq!(scheduled, TransactionId { scheduled: true, ..transaction_id });
+        assert_eq!(scheduled.to_string(), "0.0.1001@1699999999.123?scheduled/4");
+        assert_eq!(TransactionId::from_str(&scheduled.to_string()).unwrap(), scheduled);
+
+        assert_eq!(scheduled.into_unscheduled(), transaction_id);
+    }
+
+    #[test]
+    fn generate_backoff_window() {
+        for _ in 0..100 {
+            let before = OffsetDateTime::now_utc();
+            let transaction_id = TransactionId::generate(AccountId::new(0, 0, 1001));
+            let after = OffsetDateTime::now_utc();
+
+            assert!(transaction_id.valid_start > before - Duration::seconds(8));
+            assert!(transaction_id.valid_start <= after - Duration::seconds(5));
+            assert_eq!(transaction_id.nonce, None);
+            assert!(!transaction_id.scheduled);
+        }
+    }
 }
@@ -29,6 +29,7 @@ use crate::{
     AccountId,
     ContractId,
     Error,
+    ExchangeRates,
     FileId,
     FromProtobuf,
     ScheduleId,
@@ -60,8 +61,9 @@ pub struct TransactionReceipt {
     /// In the receipt for a `ContractCreateTransaction`, the id of the newly created contract.
     pub contract_id: Option<ContractId>,
 
-    // The exchange rates in effect when the transaction reached consensus.
-    // TODO: pub exchange_rate: ExchangeRate,
+    /// The exchange rates in effect when the transaction reached consensus.
+    pub exchange_rates: Option<ExchangeRates>,
+
     /// In the receipt for a `TopicCreateTransaction`, the id of the newly created topic.
     pub topic_id: Option<TopicId>,
 
@@ -88,6 +90,9 @@ pub struct TransactionReceipt {
     pub total_supply: u64,
 
     /// In the receipt for a `ScheduleCreateTransaction`, the id of the newly created schedule.
+    ///
+    /// If the status is [`Status::IdenticalScheduleAlreadyCreated`],
+    /// this is instead the id of the existing, identical, schedule.
     pub schedule_id: Option<ScheduleId>,
 
     /// In the receipt of a `ScheduleCreateTransaction` or `ScheduleSignTransaction` that resolves
@@ -142,13 +147,31 @@ impl TransactionReceipt {
         }
     }
 
+    /// Returns the [`schedule_id`](Self::schedule_id) of a `ScheduleCreateTransaction`'s receipt,
+    /// whether the schedule was newly created or an identical schedule already existed.
+    ///
+    /// # Errors
+    /// - [`Error::ReceiptStatus`] if the status is neither [`Status::Success`]
+    ///   nor [`Status::IdenticalScheduleAlreadyCreated`].
+    /// - [`Error::FromProtobuf`] if the receipt doesn't contain a schedule id.
+    pub fn schedule_id_or_existing(&self) -> crate::Result<ScheduleId> {
+        if !matches!(self.status, Status::Success | Status::IdenticalScheduleAlreadyCreated) {
+            return Err(Error::ReceiptStatus {
+                status: self.status,
+                transaction_id: self.transaction_id.map(Box::new),
+            });
+        }
+
+        self.schedule_id.ok_or_else(|| Error::from_protobuf("receipt is missing `schedule_id`"))
+    }
+
     fn from_protobuf(
         receipt: services::TransactionReceipt,
         duplicates: Vec<Self>,
         children: Vec<Self>,
         transaction_id: Option<&TransactionId>,
     ) -> crate::Result<Self> {
-        let status = Status::try_from(receipt.status).unwrap_or_default();
+        let status = Status::from(receipt.status);
 
         let account_id = Option::from_protobuf(receipt.account_id)?;
         let file_id = Option::from_protobuf(receipt.file_id)?;
@@ -156,6 +179,7 @@ impl TransactionReceipt {
         let topic_id = Option::from_protobuf(receipt.topic_id)?;
         let token_id = Option::from_protobuf(receipt.token_id)?;
         let schedule_id = Option::from_protobuf(receipt.schedule_id)?;
+        let exchange_rates = Option::from_protobuf(receipt.exchange_rate)?;
 
         let scheduled_transaction_id = Option::from_protobuf(receipt.scheduled_transaction_id)?;
 
@@ -174,6 +198,7 @@ impl TransactionReceipt {
             account_id,
             file_id,
             contract_id,
+            exchange_rates,
             topic_id,
             token_id,
             schedule_id,
@@ -223,11 +248,11 @@ impl ToProtobuf for TransactionReceipt {
 
     fn to_protobuf(&self) -> Self::Protobuf {
         services::TransactionReceipt {
-            status: self.status as i32,
+            status: self.status.into(),
             account_id: self.account_id.to_protobuf(),
             file_id: self.file_id.to_protobuf(),
             contract_id: self.contract_id.to_protobuf(),
-            exchange_rate: None,
+            exchange_rate: self.exchange_rates.to_protobuf(),
             topic_id: self.topic_id.to_protobuf(),
             topic_sequence_number: self.topic_sequence_number,
             topic_running_hash: self.topic_running_hash.clone().unwrap_or_default(),
@@ -244,13 +269,17 @@ impl ToProtobuf for TransactionReceipt {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
+    use hedera_proto::services;
+    use prost::Message;
 
     use crate::protobuf::ToProtobuf;
     use crate::transaction::test_helpers::TEST_TX_ID;
     use crate::{
         AccountId,
         ContractId,
+        Error,
         FileId,
         ScheduleId,
         Status,
@@ -267,6 +296,7 @@ mod tests {
             account_id: Some(AccountId::new(1, 2, 3)),
             file_id: Some(FileId::new(4, 5, 6)),
             contract_id: Some(ContractId::new(3, 2, 1)),
+            exchange_rates: None,
             topic_id: Some(TopicId::new(9, 8, 7)),
             topic_sequence_number: 3,
             topic_running_hash: Some(b"how now brown cow".to_vec()),
@@ -401,4 +431,104 @@ mod tests {
 
         assert_eq!(a.to_protobuf(), b.to_protobuf());
     }
+
+    #[test]
+    fn schedule_id_or_existing() {
+        let existing = ScheduleId::new(0, 0, 5005);
+
+        let response = services::response::Response::TransactionGetReceipt(
+            services::TransactionGetReceiptResponse {
+                header: None,
+                receipt: Some(services::TransactionReceipt {
+                    status: services::ResponseCodeEnum::IdenticalScheduleAlreadyCreated as i32,
+                    schedule_id: Some(existing.to_protobuf()),
+                    scheduled_transaction_id: Some(TEST_TX_ID.to_protobuf()),
+                    ..Default::default()
+                }),
+                duplicate_transaction_receipts: Vec::new(),
+                child_transaction_receipts: Vec::new(),
+            },
+        );
+
+        let receipt =
+            TransactionReceipt::from_response_protobuf(response, Some(&TEST_TX_ID)).unwrap();
+
+        assert_eq!(receipt.status, Status::IdenticalScheduleAlreadyCreated);
+        assert_eq!(receipt.schedule_id, Some(existing));
+        assert_eq!(receipt.schedule_id_or_existing().unwrap(), existing);
+    }
+
+    #[test]
+    fn token_mint_receipt() {
+        let receipt = TransactionReceipt::from_bytes(
+            &services::TransactionReceipt {
+                status: services::ResponseCodeEnum::Success as i32,
+                token_id: Some(TokenId::new(0, 0, 5005).to_protobuf()),
+                new_total_supply: 3,
+                serial_numbers: Vec::from([1, 2, 3]),
+                node_id: 0,
+                ..Default::default()
+            }
+            .encode_to_vec(),
+        )
+        .unwrap();
+
+        assert_eq!(receipt.status, Status::Success);
+        assert_eq!(receipt.token_id, Some(TokenId::new(0, 0, 5005)));
+        assert_eq!(receipt.total_supply, 3);
+        assert_eq!(receipt.serials, [1, 2, 3]);
+
+        assert_eq!(receipt.account_id, None);
+        assert_eq!(receipt.schedule_id, None);
+        assert_eq!(receipt.topic_running_hash, None);
+    }
+
+    #[test]
+    fn exchange_rates() {
+        let rate = |cents, seconds| services::ExchangeRate {
+            hbar_equiv: 30_000,
+            cent_equiv: cents,
+            expiration_time: Some(services::TimestampSeconds { seconds }),
+        };
+
+        let receipt = TransactionReceipt::from_bytes(
+            &services::TransactionReceipt {
+                status: services::ResponseCodeEnum::Success as i32,
+                exchange_rate: Some(services::ExchangeRateSet {
+                    current_rate: Some(rate(580_150, 1_645_714_800)),
+                    next_rate: Some(rate(587_660, 1_645_718_400)),
+                }),
+                ..Default::default()
+            }
+            .encode_to_vec(),
+        )
+        .unwrap();
+
+        let exchange_rates = receipt.exchange_rates.as_ref().unwrap();
+
+        assert_eq!(exchange_rates.current_rate.hbars, 30_000);
+        assert_eq!(exchange_rates.current_rate.cents, 580_150);
+        assert_eq!(exchange_rates.next_rate.cents, 587_660);
+        assert_eq!(exchange_rates.next_rate.expiration_time.unix_timestamp(), 1_645_718_400);
+
+        assert_eq!(
+            receipt.to_protobuf().exchange_rate.unwrap().current_rate,
+            Some(rate(580_150, 1_645_714_800))
+        );
+    }
+
+    #[test]
+    fn schedule_id_or_existing_failed() {
+        let receipt = make_receipt();
+
+        assert_matches!(
+            receipt.schedule_id_or_existing(),
+            Err(Error::ReceiptStatus { status: Status::ScheduleAlreadyDeleted, .. })
+        );
+
+        let receipt =
+            TransactionReceipt { status: Status::Success, schedule_id: None, ..make_receipt() };
+
+        assert_matches!(receipt.schedule_id_or_existing(), Err(Error::FromProtobuf(_)));
+    }
 }
@@ -166,13 +166,13 @@ impl QueryExecute for TransactionReceiptQueryData {
                 return false;
             };
 
-            match r.receipt.as_ref().and_then(|it| Some(Status::try_from(it.status))) {
-                Some(receipt_status) => receipt_status,
+            match r.receipt.as_ref() {
+                Some(receipt) => Status::from(receipt.status),
                 None => return false,
             }
         };
 
-        matches!(receipt_status, Ok(Status::Unknown))
+        matches!(receipt_status, Status::Unknown)
     }
 
     fn make_response(&self, response: Response) -> crate::Result<Self::Response> {
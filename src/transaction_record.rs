@@ -123,8 +123,10 @@ pub struct TransactionRecord {
 
     /// A list of pending token airdrops.
     pub pending_airdrop_records: Vec<PendingAirdropRecord>,
+
+    /// All staking rewards paid as a result of this transaction.
+    pub paid_staking_rewards: Vec<Transfer>,
 }
-// TODO: paid_staking_rewards
 
 impl TransactionRecord {
     /// Create a new `TransactionRecord` from protobuf-encoded `bytes`.
@@ -216,6 +218,8 @@ impl TransactionRecord {
 
         let pending_airdrop_records = Vec::from_protobuf(record.new_pending_airdrops)?;
 
+        let paid_staking_rewards = Vec::from_protobuf(record.paid_staking_rewards)?;
+
         Ok(Self {
             receipt,
             transaction_hash: record.transaction_hash,
@@ -239,6 +243,7 @@ impl TransactionRecord {
             prng_bytes,
             prng_number,
             pending_airdrop_records,
+            paid_staking_rewards,
         })
     }
 }
@@ -316,8 +321,7 @@ impl ToProtobuf for TransactionRecord {
             parent_consensus_timestamp: self.parent_consensus_timestamp.to_protobuf(),
             alias: self.alias_key.as_ref().map(ToProtobuf::to_bytes).unwrap_or_default(),
             ethereum_hash: self.ethereum_hash.clone(),
-            // TODO:
-            paid_staking_rewards: Vec::new(),
+            paid_staking_rewards: self.paid_staking_rewards.to_protobuf(),
             evm_address: self
                 .evm_address
                 .as_ref()
@@ -377,6 +381,7 @@ mod tests {
                 contract_function_parameters_bytes: Vec::new(),
                 sender_account_id: Some(AccountId::new(1, 2, 3)),
                 logs: Vec::new(),
+                created_contract_ids: Vec::new(),
                 contract_nonces: Vec::new(),
                 signer_nonce: None,
             }),
@@ -435,9 +440,97 @@ mod tests {
                 ),
                 pending_airdrop_value: Some(2),
             }],
+            paid_staking_rewards: Vec::new(),
         }
     }
 
+    #[test]
+    fn from_get_record_response() {
+        use hedera_proto::services;
+
+        use crate::{
+            FromProtobuf,
+            Status,
+        };
+
+        let record = |memo: &str| services::TransactionRecord {
+            receipt: Some(services::TransactionReceipt {
+                status: services::ResponseCodeEnum::Success as i32,
+                ..Default::default()
+            }),
+            transaction_hash: b"hash".to_vec(),
+            consensus_timestamp: Some(VALID_START.to_protobuf()),
+            transaction_id: Some(TEST_TX_ID.to_protobuf()),
+            memo: memo.to_owned(),
+            transaction_fee: 3000,
+            ..Default::default()
+        };
+
+        let response = services::response::Response::TransactionGetRecord(
+            services::TransactionGetRecordResponse {
+                header: None,
+                transaction_record: Some(services::TransactionRecord {
+                    transfer_list: Some(services::TransferList {
+                        account_amounts: Vec::from([
+                            Transfer {
+                                account_id: AccountId::new(0, 0, 5006),
+                                amount: Hbar::new(-1),
+                            }
+                            .to_protobuf(),
+                            Transfer { account_id: AccountId::new(0, 0, 3), amount: Hbar::new(1) }
+                                .to_protobuf(),
+                        ]),
+                    }),
+                    paid_staking_rewards: Vec::from([Transfer {
+                        account_id: AccountId::new(0, 0, 5006),
+                        amount: Hbar::from_tinybars(50),
+                    }
+                    .to_protobuf()]),
+                    body: Some(services::transaction_record::Body::ContractCallResult(
+                        services::ContractFunctionResult {
+                            contract_id: Some(ContractId::new(0, 0, 1001).to_protobuf()),
+                            contract_call_result: Vec::from([0x01, 0x02]),
+                            gas_used: 21000,
+                            ..Default::default()
+                        },
+                    )),
+                    ..record("parent")
+                }),
+                duplicate_transaction_records: Vec::from([record("duplicate")]),
+                child_transaction_records: Vec::from([record("child")]),
+            },
+        );
+
+        let record = TransactionRecord::from_protobuf(response).unwrap();
+
+        assert_eq!(record.receipt.status, Status::Success);
+        assert_eq!(record.transaction_id, TEST_TX_ID);
+        assert_eq!(record.consensus_timestamp, VALID_START);
+        assert_eq!(record.transaction_memo, "parent");
+        assert_eq!(record.transaction_fee, Hbar::from_tinybars(3000));
+        assert_eq!(
+            record.transfers,
+            [
+                Transfer { account_id: AccountId::new(0, 0, 5006), amount: Hbar::new(-1) },
+                Transfer { account_id: AccountId::new(0, 0, 3), amount: Hbar::new(1) },
+            ]
+        );
+        assert_eq!(
+            record.paid_staking_rewards,
+            [Transfer { account_id: AccountId::new(0, 0, 5006), amount: Hbar::from_tinybars(50) }]
+        );
+
+        let result = record.contract_function_result.unwrap();
+        assert_eq!(result.contract_id, ContractId::new(0, 0, 1001));
+        assert_eq!(result.bytes, [0x01, 0x02]);
+        assert_eq!(result.gas_used, 21000);
+
+        assert_eq!(record.duplicates.len(), 1);
+        assert_eq!(record.duplicates[0].transaction_memo, "duplicate");
+        assert_eq!(record.children.len(), 1);
+        assert_eq!(record.children[0].transaction_memo, "child");
+    }
+
     #[test]
     fn serialize() {
         expect_file!["./snapshots/transaction_record/serialize.txt"]
@@ -99,7 +99,7 @@ impl TransactionRecordQuery {
         self
     }
 
-    /// Whether records of processing duplicate transactions should be returned.
+    /// Whether the record status should be validated.
     #[must_use]
     pub fn get_validate_status(&self) -> bool {
         self.data.validate_status
@@ -110,6 +110,24 @@ impl TransactionRecordQuery {
         self.data.validate_status = validate;
         self
     }
+
+    /// Whether the status of the record's receipt should be validated.
+    ///
+    /// This is the same setting as [`get_validate_status`](Self::get_validate_status).
+    #[must_use]
+    pub fn get_validate_receipt_status(&self) -> bool {
+        self.data.validate_status
+    }
+
+    /// Whether the status of the record's receipt should be validated.
+    ///
+    /// If `true`, executing the query fails with [`Error::ReceiptStatus`]
+    /// unless the receipt's status is [`Status::Success`].
+    ///
+    /// This is the same setting as [`validate_status`](Self::validate_status).
+    pub fn validate_receipt_status(&mut self, validate: bool) -> &mut Self {
+        self.validate_status(validate)
+    }
 }
 
 impl ToQueryProtobuf for TransactionRecordQueryData {
@@ -148,6 +166,22 @@ impl QueryExecute for TransactionRecordQueryData {
         matches!(status, Status::ReceiptNotFound | Status::RecordNotFound)
     }
 
+    fn should_retry(&self, response: &services::Response) -> bool {
+        // the record isn't available until the transaction reaches consensus,
+        // until then its receipt has the status `UNKNOWN`.
+        let Some(services::response::Response::TransactionGetRecord(r)) = &response.response else {
+            return false;
+        };
+
+        let receipt_status = r
+            .transaction_record
+            .as_ref()
+            .and_then(|it| it.receipt.as_ref())
+            .map(|it| Status::from(it.status));
+
+        matches!(receipt_status, Some(Status::Unknown))
+    }
+
     fn make_response(&self, response: Response) -> crate::Result<Self::Response> {
         let record = TransactionRecord::from_protobuf(response)?;
 
@@ -171,10 +205,17 @@ impl ValidateChecksums for TransactionRecordQueryData {
 #[cfg(test)]
 mod tests {
     use expect_test::expect;
+    use hedera_proto::services;
 
-    use crate::query::ToQueryProtobuf;
+    use crate::query::{
+        QueryExecute,
+        ToQueryProtobuf,
+    };
     use crate::transaction::test_helpers::TEST_TX_ID;
-    use crate::TransactionRecordQuery;
+    use crate::{
+        Status,
+        TransactionRecordQuery,
+    };
 
     #[test]
     fn serialize() {
@@ -261,4 +302,39 @@ mod tests {
 
         assert_eq!(query.get_validate_status(), true);
     }
+
+    #[test]
+    fn get_set_validate_receipt_status() {
+        let mut query = TransactionRecordQuery::new();
+        query.validate_receipt_status(true);
+
+        assert_eq!(query.get_validate_receipt_status(), true);
+        assert_eq!(query.get_validate_status(), true);
+    }
+
+    #[test]
+    fn should_retry_until_record_available() {
+        fn response(status: Status) -> services::Response {
+            services::Response {
+                response: Some(services::response::Response::TransactionGetRecord(
+                    services::TransactionGetRecordResponse {
+                        transaction_record: Some(services::TransactionRecord {
+                            receipt: Some(services::TransactionReceipt {
+                                status: status.into(),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )),
+            }
+        }
+
+        let query = TransactionRecordQuery::new();
+
+        assert!(query.data.should_retry(&response(Status::Unknown)));
+        assert!(!query.data.should_retry(&response(Status::Success)));
+        assert!(!query.data.should_retry(&response(Status::InvalidSignature)));
+    }
 }
@@ -21,6 +21,7 @@
 use crate::{
     AccountId,
     Client,
+    ScheduleId,
     TransactionHash,
     TransactionId,
     TransactionReceipt,
@@ -53,11 +54,29 @@ pub struct TransactionResponse {
     /// This can be used to lookup the transaction in an explorer.
     pub transaction_hash: TransactionHash,
 
+    // the ID of the transaction scheduled by a `ScheduleCreateTransaction`.
+    pub(crate) scheduled_transaction_id: Option<TransactionId>,
+
     /// Whether the receipt/record status should be validated.
     pub validate_status: bool,
 }
 
 impl TransactionResponse {
+    /// Returns the SHA-384 hash of the submitted transaction's bytes.
+    #[must_use]
+    pub fn transaction_hash(&self) -> &[u8] {
+        &self.transaction_hash.0
+    }
+
+    /// Returns the ID of the transaction scheduled by a `ScheduleCreateTransaction`.
+    ///
+    /// This is `None` for every other kind of transaction, including `ScheduleSignTransaction`
+    /// (the receipt's [`scheduled_transaction_id`](TransactionReceipt::scheduled_transaction_id) has it).
+    #[must_use]
+    pub fn scheduled_transaction_id(&self) -> Option<TransactionId> {
+        self.scheduled_transaction_id
+    }
+
     /// Whether the receipt/record status should be validated.
     pub fn validate_status(&mut self, validate: bool) -> &mut Self {
         self.validate_status = validate;
@@ -88,7 +107,7 @@ impl TransactionResponse {
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
     ///
     /// fixme: is that it? Surely there are more situations.
@@ -100,7 +119,7 @@ impl TransactionResponse {
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
     pub async fn get_receipt_with_timeout(
         &self,
@@ -110,11 +129,24 @@ impl TransactionResponse {
         self.get_receipt_query().execute_with_timeout(client, timeout).await
     }
 
+    /// Get the id of the schedule created by this `ScheduleCreateTransaction`,
+    /// or the id of the existing schedule if an identical one was already created.
+    /// Will wait for consensus.
+    ///
+    /// # Errors
+    /// - [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) if the receipt's status is neither
+    ///   `Success` nor `IdenticalScheduleAlreadyCreated`, regardless of [`validate_status`](Self::validate_status).
+    pub async fn get_schedule_id_or_existing(&self, client: &Client) -> crate::Result<ScheduleId> {
+        let receipt = self.get_receipt_query().validate_status(false).execute(client).await?;
+
+        receipt.schedule_id_or_existing()
+    }
+
     /// Get the record for this transaction.
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
     pub async fn get_record(&self, client: &Client) -> crate::Result<TransactionRecord> {
         self.get_record_query().execute(client).await
@@ -124,7 +156,7 @@ impl TransactionResponse {
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
     pub async fn get_record_with_timeout(
         &self,
@@ -12,7 +12,7 @@ use crate::{
 /// A transfer of [`Hbar`] that occured within a [`Transaction`](crate::Transaction)
 ///
 /// Returned as part of a [`TransactionRecord`](crate::TransactionRecord)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Transfer {
     /// The account ID that this transfer is to/from.
     pub account_id: AccountId,
@@ -30,6 +30,7 @@ use crate::protobuf::FromProtobuf;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    Service,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
@@ -104,6 +105,10 @@ impl TransferTransaction {
     }
 
     /// Add a non-approved hbar transfer to the transaction.
+    ///
+    /// `account_id` may be an alias, such as one created from an [`EvmAddress`](crate::EvmAddress).
+    /// Transferring hbar to an alias that doesn't have an account yet will auto-create the account,
+    /// the fee for which is charged to the payer of this transaction.
     pub fn hbar_transfer(&mut self, account_id: AccountId, amount: Hbar) -> &mut Self {
         self._hbar_transfer(account_id, amount, false)
     }
@@ -302,6 +307,10 @@ impl TransactionExecute for TransferTransactionData {
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
         Box::pin(async { CryptoServiceClient::new(channel).crypto_transfer(request).await })
     }
+
+    fn service(&self) -> Service {
+        Service::Crypto
+    }
 }
 
 impl TransactionData for TransferTransactionData {}
@@ -398,7 +407,7 @@ impl ToTransactionDataProtobuf for TransferTransactionData {
         &self,
         chunk_info: &ChunkInfo,
     ) -> services::transaction_body::Data {
-        let _ = chunk_info.assert_single_transaction();
+        let _ = chunk_info.assert_single_transaction::<Self>();
 
         services::transaction_body::Data::CryptoTransfer(self.to_protobuf())
     }
@@ -445,15 +454,21 @@ impl ToProtobuf for TransferTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
+    use hedera_proto::services;
+    use hex_literal::hex;
 
+    use crate::protobuf::ToProtobuf;
     use crate::transaction::test_helpers::{
         check_body,
         transaction_body,
+        unused_private_key,
     };
     use crate::{
         AccountId,
         AnyTransaction,
+        EvmAddress,
         Hbar,
         TokenId,
         TransferTransaction,
@@ -861,4 +876,125 @@ mod tests {
         tx.token_transfer_with_decimals(TOKEN, AccountId::new(0, 0, 7), -100, 5);
         assert_eq!(tx.get_token_decimals().get(&TOKEN), Some(&5));
     }
+
+    #[test]
+    fn serialize_public_key_alias() {
+        let alias = unused_private_key().public_key();
+        let account_id = alias.to_account_id(0, 0);
+
+        assert_eq!(account_id.to_string(), format!("0.0.{alias}"));
+
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.hbar_transfer(account_id, Hbar::from_tinybars(100))
+            .hbar_transfer(AccountId::new(0, 0, 5006), Hbar::from_tinybars(-100))
+            .freeze()
+            .unwrap();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(transaction_body(tx2), transaction_body(tx.clone()));
+
+        let body = assert_matches!(
+            check_body(transaction_body(tx)),
+            services::transaction_body::Data::CryptoTransfer(it) => it
+        );
+
+        let credit = body
+            .transfers
+            .unwrap()
+            .account_amounts
+            .into_iter()
+            .find(|it| it.amount == 100)
+            .unwrap();
+
+        assert_eq!(
+            credit.account_id.unwrap().account,
+            Some(services::account_id::Account::Alias(ToProtobuf::to_bytes(&alias)))
+        );
+    }
+
+    #[test]
+    fn serialize_evm_address_alias() {
+        let evm_address = EvmAddress::from(hex!("5c562e90feaf0eebd33ea75d21024f249d451417"));
+
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.hbar_transfer(evm_address.into(), Hbar::from_tinybars(100))
+            .hbar_transfer(AccountId::new(0, 0, 5006), Hbar::from_tinybars(-100))
+            .freeze()
+            .unwrap();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(transaction_body(tx2), transaction_body(tx.clone()));
+
+        let tx = check_body(transaction_body(tx));
+
+        expect![[r#"
+            CryptoTransfer(
+                CryptoTransferTransactionBody {
+                    transfers: Some(
+                        TransferList {
+                            account_amounts: [
+                                AccountAmount {
+                                    account_id: Some(
+                                        AccountId {
+                                            shard_num: 0,
+                                            realm_num: 0,
+                                            account: Some(
+                                                Alias(
+                                                    [
+                                                        92,
+                                                        86,
+                                                        46,
+                                                        144,
+                                                        254,
+                                                        175,
+                                                        14,
+                                                        235,
+                                                        211,
+                                                        62,
+                                                        167,
+                                                        93,
+                                                        33,
+                                                        2,
+                                                        79,
+                                                        36,
+                                                        157,
+                                                        69,
+                                                        20,
+                                                        23,
+                                                    ],
+                                                ),
+                                            ),
+                                        },
+                                    ),
+                                    amount: 100,
+                                    is_approval: false,
+                                },
+                                AccountAmount {
+                                    account_id: Some(
+                                        AccountId {
+                                            shard_num: 0,
+                                            realm_num: 0,
+                                            account: Some(
+                                                AccountNum(
+                                                    5006,
+                                                ),
+                                            ),
+                                        },
+                                    ),
+                                    amount: -100,
+                                    is_approval: false,
+                                },
+                            ],
+                        },
+                    ),
+                    token_transfers: [],
+                },
+            )
+        "#]]
+        .assert_debug_eq(&tx);
+    }
 }
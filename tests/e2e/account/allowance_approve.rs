@@ -33,7 +33,7 @@ async fn spend() -> anyhow::Result<()> {
         .await?;
 
     let transfer_record = TransferTransaction::new()
-        .hbar_transfer(client.get_operator_account_id().unwrap(), Hbar::new(5))
+        .hbar_transfer(client.operator_account_id().unwrap(), Hbar::new(5))
         .approved_hbar_transfer(bob.id, Hbar::new(-5))
         .transaction_id(TransactionId::generate(alice.id))
         .freeze_with(&client)?
@@ -46,7 +46,7 @@ async fn spend() -> anyhow::Result<()> {
     assert!(transfer_record
         .transfers
         .iter()
-        .any(|it| it.account_id == client.get_operator_account_id().unwrap()
+        .any(|it| it.account_id == client.operator_account_id().unwrap()
             && it.amount == Hbar::new(5)));
 
     let _ = tokio::try_join!(alice.delete(&client), bob.delete(&client))?;
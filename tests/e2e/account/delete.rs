@@ -32,7 +32,7 @@ async fn create_then_delete() -> anyhow::Result<()> {
     let account_id = receipt.account_id.unwrap();
 
     AccountDeleteTransaction::new()
-        .transfer_account_id(client.get_operator_account_id().unwrap())
+        .transfer_account_id(client.operator_account_id().unwrap())
         .account_id(account_id)
         .sign(key)
         .execute(&client)
@@ -57,7 +57,7 @@ async fn missing_account_id_fails() {
     };
 
     let res = AccountDeleteTransaction::new()
-        .transfer_account_id(client.get_operator_account_id().unwrap())
+        .transfer_account_id(client.operator_account_id().unwrap())
         .execute(&client)
         .await;
 
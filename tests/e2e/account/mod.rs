@@ -38,7 +38,7 @@ impl Account {
     pub async fn delete(self, client: &hedera::Client) -> hedera::Result<()> {
         hedera::AccountDeleteTransaction::new()
             .account_id(self.id)
-            .transfer_account_id(client.get_operator_account_id().unwrap())
+            .transfer_account_id(client.operator_account_id().unwrap())
             .freeze_with(client)?
             .sign(self.key)
             .execute(client)
@@ -111,8 +111,8 @@ async fn cannot_update_max_token_association_to_lower_value_fails() -> anyhow::R
         .name("ffff")
         .symbol("F")
         .initial_supply(100_000)
-        .treasury_account_id(client.get_operator_account_id().unwrap())
-        .admin_key(client.get_operator_public_key().unwrap())
+        .treasury_account_id(client.operator_account_id().unwrap())
+        .admin_key(client.operator_public_key().unwrap())
         .execute(&client)
         .await?
         .get_receipt(&client)
@@ -122,7 +122,7 @@ async fn cannot_update_max_token_association_to_lower_value_fails() -> anyhow::R
 
     // Associate token with account
     _ = TransferTransaction::new()
-        .token_transfer(token_id, client.get_operator_account_id().unwrap(), -10)
+        .token_transfer(token_id, client.operator_account_id().unwrap(), -10)
         .token_transfer(token_id, account_id, 10)
         .execute(&client)
         .await?
@@ -65,10 +65,8 @@ async fn missing_contract_id_fails() -> anyhow::Result<()> {
         .execute(&client)
         .await;
 
-    assert_matches!(
-        res,
-        Err(hedera::Error::TransactionPreCheckStatus { status: Status::InvalidContractId, .. })
-    );
+    // caught locally, without a round trip to the network.
+    assert_matches!(res, Err(hedera::Error::FreezeUnsetField { field: "contract_id" }));
 
     Ok(())
 }
@@ -136,10 +134,8 @@ async fn missing_gas_fails() -> anyhow::Result<()> {
         .execute(&client)
         .await;
 
-    assert_matches!(
-        res,
-        Err(hedera::Error::TransactionPreCheckStatus { status: Status::InsufficientGas, .. })
-    );
+    // caught locally, without a round trip to the network.
+    assert_matches!(res, Err(hedera::Error::InvalidTransaction(_)));
 
     ContractDeleteTransaction::new()
         .transfer_account_id(op.account_id)
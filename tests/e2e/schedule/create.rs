@@ -39,7 +39,7 @@ async fn create_account() -> anyhow::Result<()> {
     transaction.key(key.public_key());
 
     let schedule_id = ScheduleCreateTransaction::new()
-        .scheduled_transaction(transaction)
+        .scheduled_transaction(transaction)?
         .admin_key(op.private_key.public_key())
         .payer_account_id(op.account_id)
         .execute(&client)
@@ -74,7 +74,7 @@ async fn create_account_schedule() -> anyhow::Result<()> {
     transaction.key(key.public_key());
 
     let schedule_id = transaction
-        .schedule()
+        .schedule()?
         .admin_key(op.private_key.public_key())
         .payer_account_id(op.account_id)
         .execute(&client)
@@ -127,7 +127,7 @@ async fn transfer() -> anyhow::Result<()> {
     transfer.hbar_transfer(account_id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
 
     // Schedule the transactoin
-    let mut scheduled = transfer.schedule();
+    let mut scheduled = transfer.schedule()?;
 
     let receipt = scheduled.execute(&client).await?.get_receipt(&client).await?;
 
@@ -190,7 +190,7 @@ async fn double_schedule_fails() -> anyhow::Result<()> {
     transfer.hbar_transfer(op.account_id, Hbar::new(-1)).hbar_transfer(account.id, Hbar::new(1));
 
     let schedule_id_1 = transfer
-        .schedule()
+        .schedule()?
         .execute(&client)
         .await?
         .get_receipt(&client)
@@ -206,7 +206,7 @@ async fn double_schedule_fails() -> anyhow::Result<()> {
 
     transfer.hbar_transfer(op.account_id, Hbar::new(-1)).hbar_transfer(account.id, Hbar::new(1));
 
-    let res = transfer.schedule().execute(&client).await?.get_receipt(&client).await;
+    let res = transfer.schedule()?.execute(&client).await?.get_receipt(&client).await;
 
     assert_matches!(
         res,
@@ -249,7 +249,7 @@ async fn topic_message() -> anyhow::Result<()> {
 
     // create schedule
     let schedule_id = transaction
-        .schedule()
+        .schedule()?
         .admin_key(op.private_key.public_key())
         .payer_account_id(op.account_id)
         .schedule_memo(format!(
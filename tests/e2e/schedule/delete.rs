@@ -29,7 +29,7 @@ async fn basic() -> anyhow::Result<()> {
         let mut tx = TransferTransaction::new();
         tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
 
-        tx.schedule()
+        tx.schedule()?
             .admin_key(op.private_key.public_key())
             .execute(&client)
             .await?
@@ -66,7 +66,7 @@ async fn missing_admin_key_fails() -> anyhow::Result<()> {
         let mut tx = TransferTransaction::new();
         tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
 
-        tx.schedule().execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
+        tx.schedule()?.execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
     };
 
     let res = ScheduleDeleteTransaction::new()
@@ -101,7 +101,7 @@ async fn double_delete_fails() -> anyhow::Result<()> {
         let mut tx = TransferTransaction::new();
         tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
 
-        tx.schedule()
+        tx.schedule()?
             .admin_key(op.private_key.public_key())
             .execute(&client)
             .await?
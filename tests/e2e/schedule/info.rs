@@ -34,7 +34,7 @@ async fn basic() -> anyhow::Result<()> {
     transaction.key(key.public_key());
 
     let schedule_id = ScheduleCreateTransaction::new()
-        .scheduled_transaction(transaction)
+        .scheduled_transaction(transaction)?
         .admin_key(op.private_key.public_key())
         .payer_account_id(op.account_id)
         .execute(&client)
@@ -69,7 +69,7 @@ async fn query() -> anyhow::Result<()> {
         let mut tx = TransferTransaction::new();
         tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
 
-        tx.schedule().execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
+        tx.schedule()?.execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
     };
 
     let info = ScheduleInfoQuery::new().schedule_id(schedule_id).execute(&client).await?;
@@ -125,7 +125,7 @@ async fn query_cost() -> anyhow::Result<()> {
         let mut tx = TransferTransaction::new();
         tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
 
-        tx.schedule().execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
+        tx.schedule()?.execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
     };
 
     let mut query = ScheduleInfoQuery::new();
@@ -158,7 +158,7 @@ async fn query_cost_big_max() -> anyhow::Result<()> {
         let mut tx = TransferTransaction::new();
         tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
 
-        tx.schedule().execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
+        tx.schedule()?.execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
     };
 
     let mut query = ScheduleInfoQuery::new();
@@ -191,7 +191,7 @@ async fn query_cost_small_max_fails() -> anyhow::Result<()> {
         let mut tx = TransferTransaction::new();
         tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
 
-        tx.schedule().execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
+        tx.schedule()?.execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
     };
 
     let mut query = ScheduleInfoQuery::new();
@@ -236,7 +236,7 @@ async fn query_cost_insufficient_tx_fee_fails() -> anyhow::Result<()> {
         let mut tx = TransferTransaction::new();
         tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
 
-        tx.schedule().execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
+        tx.schedule()?.execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
     };
 
     let mut query = ScheduleInfoQuery::new();
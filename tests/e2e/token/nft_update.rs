@@ -47,9 +47,9 @@ async fn update_nft_metadata() -> anyhow::Result<()> {
         .symbol("F")
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
         .token_type(TokenType::NonFungibleUnique)
-        .treasury_account_id(client.get_operator_account_id().unwrap())
-        .admin_key(client.get_operator_public_key().unwrap())
-        .supply_key(client.get_operator_public_key().unwrap())
+        .treasury_account_id(client.operator_account_id().unwrap())
+        .admin_key(client.operator_public_key().unwrap())
+        .supply_key(client.operator_public_key().unwrap())
         .metadata_key(metadata_key.public_key())
         .execute(&client)
         .await?
@@ -107,8 +107,8 @@ async fn cannot_update_without_signed_metadata_key_error() -> anyhow::Result<()>
         .symbol("F")
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
         .token_type(TokenType::NonFungibleUnique)
-        .treasury_account_id(client.get_operator_account_id().unwrap())
-        .admin_key(client.get_operator_public_key().unwrap())
+        .treasury_account_id(client.operator_account_id().unwrap())
+        .admin_key(client.operator_public_key().unwrap())
         .supply_key(supply_key.public_key())
         .metadata_key(metadata_key.public_key())
         .execute(&client)
@@ -172,8 +172,8 @@ async fn cannot_update_without_set_metadata_key_error() -> anyhow::Result<()> {
         .symbol("F")
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
         .token_type(TokenType::NonFungibleUnique)
-        .treasury_account_id(client.get_operator_account_id().unwrap())
-        .admin_key(client.get_operator_public_key().unwrap())
+        .treasury_account_id(client.operator_account_id().unwrap())
+        .admin_key(client.operator_public_key().unwrap())
         .supply_key(supply_key.public_key())
         .execute(&client)
         .await?
@@ -345,8 +345,8 @@ async fn transfer_to_account_with_unlimited_associations() -> anyhow::Result<()>
         .name("ffff")
         .symbol("F")
         .initial_supply(100_000)
-        .treasury_account_id(client.get_operator_account_id().unwrap())
-        .admin_key(client.get_operator_public_key().unwrap())
+        .treasury_account_id(client.operator_account_id().unwrap())
+        .admin_key(client.operator_public_key().unwrap())
         .execute(&client)
         .await?
         .get_receipt(&client)
@@ -384,7 +384,7 @@ async fn transfer_to_account_with_unlimited_associations() -> anyhow::Result<()>
         .await?;
 
     _ = TransferTransaction::new()
-        .token_transfer(token_id, client.get_operator_account_id().unwrap(), -10)
+        .token_transfer(token_id, client.operator_account_id().unwrap(), -10)
         .token_transfer(token_id, sender_id, 10)
         .execute(&client)
         .await?
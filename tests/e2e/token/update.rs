@@ -129,9 +129,9 @@ async fn update_immutable_token_metadata() -> anyhow::Result<()> {
         .decimals(3)
         .initial_supply(100000)
         .metadata(initial_metadata.clone())
-        .treasury_account_id(client.get_operator_account_id().unwrap())
+        .treasury_account_id(client.operator_account_id().unwrap())
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
-        .admin_key(client.get_operator_public_key().unwrap())
+        .admin_key(client.operator_public_key().unwrap())
         .metadata_key(metadata_key.public_key())
         .execute(&client)
         .await?
@@ -720,7 +720,7 @@ async fn update_admin_key_to_usuable_key_fail() -> anyhow::Result<()> {
         .name("Test NFT")
         .symbol("TNFT")
         .token_type(TokenType::NonFungibleUnique)
-        .treasury_account_id(client.get_operator_account_id().unwrap())
+        .treasury_account_id(client.operator_account_id().unwrap())
         .admin_key(admin_key.public_key())
         .supply_key(supply_key.public_key())
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
@@ -1683,7 +1683,7 @@ async fn create_token_with_keys(client: &Client, keys: &Keys) -> anyhow::Result<
             .symbol("TNFT")
             .token_type(TokenType::NonFungibleUnique)
             .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
-            .treasury_account_id(client.get_operator_account_id().unwrap())
+            .treasury_account_id(client.operator_account_id().unwrap())
             .freeze_key(keys.freeze_key.public_key())
             .supply_key(keys.supply_key.public_key())
             .wipe_key(keys.wipe_key.public_key())
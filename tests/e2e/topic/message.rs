@@ -32,7 +32,7 @@ async fn basic() -> anyhow::Result<()> {
     assert_eq!(info.topic_id, topic.id);
     assert_eq!(info.topic_memo, "[e2e::TopicCreateTransaction]");
     assert_eq!(info.sequence_number, 0);
-    assert_eq!(info.admin_key, Some(Key::from(client.get_operator_public_key().unwrap())));
+    assert_eq!(info.admin_key, Some(Key::from(client.operator_public_key().unwrap())));
 
     tokio::time::sleep(std::time::Duration::from_millis(3000)).await;
 
@@ -22,7 +22,7 @@ struct Topic {
 impl Topic {
     async fn create(client: &Client) -> anyhow::Result<Self> {
         let id = TopicCreateTransaction::new()
-            .admin_key(client.get_operator_public_key().unwrap())
+            .admin_key(client.operator_public_key().unwrap())
             .topic_memo("[e2e::TopicCreateTransaction]")
             .execute(client)
             .await?